@@ -1,21 +1,55 @@
 import { BrowserRouter, Navigate, Route, Routes } from 'react-router-dom';
+import ChainPage from './src/pages/ChainPage';
 import FormPage from './src/pages/FormPage';
+import HestonPage from './src/pages/HestonPage';
+import HistoricalVolPage from './src/pages/HistoricalVolPage';
 import ResultPage from './src/pages/ResultPage';
+import ScenarioPage from './src/pages/ScenarioPage';
+import StrategyPage from './src/pages/StrategyPage';
+import VolSurfacePage from './src/pages/VolSurfacePage';
+import YieldCurvePage from './src/pages/YieldCurvePage';
+import { MercadoAtivoProvider } from './src/hooks/useMercadoAtivo';
 import { styles } from './src/styles';
 
 export default function App() {
   return (
     <div style={styles.page}>
       <BrowserRouter>
-        <Routes>
-          <Route path="/" element={<FormPage />} />
-          <Route path="/resultado" element={<ResultPage variant="classico" />} />
-          <Route
-            path="/resultado-modificado"
-            element={<ResultPage variant="modificado" />}
-          />
-          <Route path="*" element={<Navigate to="/" replace />} />
-        </Routes>
+        <MercadoAtivoProvider>
+          <Routes>
+            <Route path="/" element={<FormPage />} />
+            <Route path="/resultado" element={<ResultPage variant="classico" />} />
+            <Route
+              path="/resultado-modificado"
+              element={<ResultPage variant="modificado" />}
+            />
+            <Route
+              path="/resultado-americano"
+              element={<ResultPage variant="americano" />}
+            />
+            <Route
+              path="/resultado-black76"
+              element={<ResultPage variant="black76" />}
+            />
+            <Route path="/resultado-fx" element={<ResultPage variant="fx" />} />
+            <Route
+              path="/resultado-exotico"
+              element={<ResultPage variant="exotico" />}
+            />
+            <Route
+              path="/resultado-saltos"
+              element={<ResultPage variant="saltos" />}
+            />
+            <Route path="/cadeia" element={<ChainPage />} />
+            <Route path="/estrategia" element={<StrategyPage />} />
+            <Route path="/cenarios" element={<ScenarioPage />} />
+            <Route path="/superficie" element={<VolSurfacePage />} />
+            <Route path="/vol-historica" element={<HistoricalVolPage />} />
+            <Route path="/heston" element={<HestonPage />} />
+            <Route path="/curva-juros" element={<YieldCurvePage />} />
+            <Route path="*" element={<Navigate to="/" replace />} />
+          </Routes>
+        </MercadoAtivoProvider>
       </BrowserRouter>
     </div>
   );
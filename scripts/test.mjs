@@ -0,0 +1,41 @@
+// Empacota cada tests/*.test.ts com o esbuild (ja instalado pelo Vite) e roda
+// o resultado com o runner nativo do Node (node --test).
+import { spawnSync } from 'node:child_process';
+import { mkdtempSync, readdirSync, rmSync } from 'node:fs';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import { build } from 'esbuild';
+
+const pastaTestes = new URL('../tests/', import.meta.url);
+const filtro = process.argv[2] ?? '';
+const arquivos = readdirSync(pastaTestes)
+  .filter((nome) => nome.endsWith('.test.ts') && nome.includes(filtro))
+  .sort();
+
+if (arquivos.length === 0) {
+  console.error('Nenhum teste encontrado.');
+  process.exit(1);
+}
+
+const saida = mkdtempSync(join(tmpdir(), 'calc-testes-'));
+try {
+  await build({
+    entryPoints: arquivos.map((nome) => new URL(nome, pastaTestes).pathname),
+    outdir: saida,
+    outExtension: { '.js': '.mjs' },
+    bundle: true,
+    platform: 'node',
+    format: 'esm',
+    logLevel: 'warning',
+    // Como no build do Vite: provedores so de desenvolvimento ficam de fora.
+    define: { 'import.meta.env.DEV': 'false' },
+  });
+  const { status } = spawnSync(
+    process.execPath,
+    ['--test', ...arquivos.map((nome) => join(saida, nome.replace(/\.ts$/, '.mjs')))],
+    { stdio: 'inherit' },
+  );
+  process.exitCode = status ?? 1;
+} finally {
+  rmSync(saida, { recursive: true, force: true });
+}
@@ -0,0 +1,88 @@
+import type React from 'react';
+import { useState } from 'react';
+import { styles } from '../styles';
+
+export function Input({
+  label,
+  hint,
+  ...props
+}: {
+  label: string;
+  hint?: string;
+  value: string;
+  inputMode?: 'decimal' | 'text';
+  placeholder?: string;
+  onChange: (event: React.ChangeEvent<HTMLInputElement>) => void;
+}) {
+  return (
+    <label style={styles.inputGroup}>
+      <span style={styles.labelRow}>
+        <span style={styles.label}>{label}</span>
+        {hint ? <InfoTip text={hint} /> : null}
+      </span>
+      <input style={styles.input} {...props} />
+    </label>
+  );
+}
+
+export function Select({
+  label,
+  hint,
+  options,
+  ...props
+}: {
+  label: string;
+  hint?: string;
+  value: string;
+  options: { value: string; label: string }[];
+  onChange: (event: React.ChangeEvent<HTMLSelectElement>) => void;
+}) {
+  return (
+    <label style={styles.inputGroup}>
+      <span style={styles.labelRow}>
+        <span style={styles.label}>{label}</span>
+        {hint ? <InfoTip text={hint} /> : null}
+      </span>
+      <select style={styles.input} {...props}>
+        {options.map((option) => (
+          <option key={option.value} value={option.value}>
+            {option.label}
+          </option>
+        ))}
+      </select>
+    </label>
+  );
+}
+
+export function InfoTip({ text }: { text: string }) {
+  const [isPinned, setIsPinned] = useState(false);
+  const [isHovered, setIsHovered] = useState(false);
+  const isOpen = isPinned || isHovered;
+
+  return (
+    <span
+      style={styles.hintWrapper}
+      onMouseEnter={() => setIsHovered(true)}
+      onMouseLeave={() => setIsHovered(false)}
+    >
+      <button
+        type="button"
+        style={styles.hintIcon}
+        aria-label={`Ajuda: ${text}`}
+        onClick={(event) => {
+          event.preventDefault();
+          event.stopPropagation();
+          setIsPinned((prev) => !prev);
+        }}
+        onBlur={() => setIsPinned(false)}
+      >
+        ?
+      </button>
+      {isOpen ? (
+        <span style={styles.hintBubble} role="tooltip">
+          {text}
+        </span>
+      ) : null}
+    </span>
+  );
+}
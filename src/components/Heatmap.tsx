@@ -0,0 +1,91 @@
+import { styles } from '../styles';
+
+type Rgb = [number, number, number];
+
+const COR_NEUTRA: Rgb = [30, 41, 59];
+const COR_POSITIVA: Rgb = [22, 163, 74];
+const COR_NEGATIVA: Rgb = [220, 38, 38];
+
+function misturar(de: Rgb, para: Rgb, t: number): string {
+  const [r, g, b] = de.map((c, i) => Math.round(c + (para[i] - c) * t));
+  return `rgb(${r}, ${g}, ${b})`;
+}
+
+/**
+ * Escala divergente centrada em zero: verde para positivo, vermelho para negativo.
+ */
+function corCelula(valor: number, maximoAbsoluto: number): string {
+  if (!(maximoAbsoluto > 0)) {
+    return misturar(COR_NEUTRA, COR_NEUTRA, 0);
+  }
+  const t = Math.min(Math.abs(valor) / maximoAbsoluto, 1);
+  return misturar(COR_NEUTRA, valor >= 0 ? COR_POSITIVA : COR_NEGATIVA, t);
+}
+
+/**
+ * Mapa de calor em tabela: linhas x colunas com a cor proporcional ao valor.
+ */
+export default function Heatmap({
+  titulo,
+  rotuloCanto,
+  colunas,
+  linhas,
+  valores,
+  destaque,
+  casas = 2,
+}: {
+  titulo: string;
+  rotuloCanto: string;
+  colunas: string[];
+  linhas: string[];
+  valores: number[][];
+  /** Celula do cenario base [linha, coluna], destacada com borda. */
+  destaque?: [number, number];
+  casas?: number;
+}) {
+  const maximoAbsoluto = Math.max(
+    ...valores.flatMap((linha) => linha.map((v) => Math.abs(v))),
+  );
+
+  return (
+    <section style={styles.section}>
+      <p style={styles.sectionTitle}>{titulo}</p>
+      <div style={styles.tableWrapper}>
+        <table style={styles.table}>
+          <thead>
+            <tr>
+              <th style={styles.tableHeader}>{rotuloCanto}</th>
+              {colunas.map((coluna) => (
+                <th key={coluna} style={styles.tableHeader}>
+                  {coluna}
+                </th>
+              ))}
+            </tr>
+          </thead>
+          <tbody>
+            {valores.map((linha, i) => (
+              <tr key={linhas[i]}>
+                <th style={styles.tableHeader}>{linhas[i]}</th>
+                {linha.map((valor, j) => (
+                  <td
+                    key={colunas[j]}
+                    style={{
+                      ...styles.tableCell,
+                      backgroundColor: corCelula(valor, maximoAbsoluto),
+                      outline:
+                        destaque && destaque[0] === i && destaque[1] === j
+                          ? '2px solid #38bdf8'
+                          : undefined,
+                    }}
+                  >
+                    {valor.toFixed(casas)}
+                  </td>
+                ))}
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      </div>
+    </section>
+  );
+}
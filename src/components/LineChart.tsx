@@ -0,0 +1,175 @@
+import { styles } from '../styles';
+import type { PontoCurva } from '../utils/payoff';
+
+export type SerieGrafico = {
+  nome: string;
+  cor: string;
+  pontos: PontoCurva[];
+  tracejado?: boolean;
+};
+
+export type MarcadorGrafico = {
+  x: number;
+  rotulo: string;
+  cor: string;
+};
+
+const LARGURA = 680;
+const ALTURA = 280;
+const MARGEM = { topo: 16, direita: 16, base: 32, esquerda: 56 };
+
+/**
+ * Marcas "redondas" (1, 2, 2.5, 5 x 10^n) cobrindo o intervalo.
+ */
+function gerarMarcas(min: number, max: number, alvo = 5): number[] {
+  const amplitude = max - min || Math.abs(max) || 1;
+  const bruto = amplitude / alvo;
+  const potencia = 10 ** Math.floor(Math.log10(bruto));
+  const passo =
+    [1, 2, 2.5, 5, 10].map((m) => m * potencia).find((p) => p >= bruto) ??
+    10 * potencia;
+  const marcas: number[] = [];
+  for (let v = Math.ceil(min / passo) * passo; v <= max + passo * 1e-9; v += passo) {
+    marcas.push(Math.round(v / passo) * passo);
+  }
+  return marcas;
+}
+
+function formatarMarca(valor: number): string {
+  return Math.abs(valor) >= 100 ? valor.toFixed(0) : String(Number(valor.toFixed(2)));
+}
+
+/**
+ * Grafico de linhas em SVG puro, com eixos, marcadores verticais e legenda.
+ */
+export default function LineChart({
+  titulo,
+  series,
+  marcadores = [],
+  rotuloX,
+}: {
+  titulo: string;
+  series: SerieGrafico[];
+  marcadores?: MarcadorGrafico[];
+  rotuloX?: string;
+}) {
+  const pontos = series.flatMap((serie) => serie.pontos);
+  if (pontos.length === 0) {
+    return null;
+  }
+
+  const xs = pontos.map((p) => p.x);
+  const ys = pontos.map((p) => p.y);
+  const xMin = Math.min(...xs);
+  const xMax = Math.max(...xs);
+  const yMin = Math.min(0, ...ys);
+  const yMax = Math.max(...ys) > yMin ? Math.max(...ys) : yMin + 1;
+
+  const areaX = LARGURA - MARGEM.esquerda - MARGEM.direita;
+  const areaY = ALTURA - MARGEM.topo - MARGEM.base;
+  const escalaX = (x: number) =>
+    MARGEM.esquerda + ((x - xMin) / (xMax - xMin || 1)) * areaX;
+  const escalaY = (y: number) =>
+    MARGEM.topo + (1 - (y - yMin) / (yMax - yMin)) * areaY;
+
+  const caminho = (serie: SerieGrafico) =>
+    serie.pontos
+      .map((p, i) => `${i === 0 ? 'M' : 'L'}${escalaX(p.x).toFixed(1)},${escalaY(p.y).toFixed(1)}`)
+      .join(' ');
+
+  return (
+    <section style={styles.section}>
+      <p style={styles.sectionTitle}>{titulo}</p>
+      <svg
+        viewBox={`0 0 ${LARGURA} ${ALTURA}`}
+        style={{ width: '100%', height: 'auto' }}
+        role="img"
+        aria-label={titulo}
+      >
+        {gerarMarcas(yMin, yMax).map((marca) => (
+          <g key={`y-${marca}`}>
+            <line
+              x1={MARGEM.esquerda}
+              x2={LARGURA - MARGEM.direita}
+              y1={escalaY(marca)}
+              y2={escalaY(marca)}
+              stroke={marca === 0 ? '#64748b' : '#1e293b'}
+            />
+            <text
+              x={MARGEM.esquerda - 6}
+              y={escalaY(marca) + 4}
+              fill="#94a3b8"
+              fontSize="11"
+              textAnchor="end"
+            >
+              {formatarMarca(marca)}
+            </text>
+          </g>
+        ))}
+        {gerarMarcas(xMin, xMax, 8).map((marca) => (
+          <text
+            key={`x-${marca}`}
+            x={escalaX(marca)}
+            y={ALTURA - MARGEM.base + 16}
+            fill="#94a3b8"
+            fontSize="11"
+            textAnchor="middle"
+          >
+            {formatarMarca(marca)}
+          </text>
+        ))}
+        {rotuloX ? (
+          <text
+            x={LARGURA - MARGEM.direita}
+            y={ALTURA - 4}
+            fill="#94a3b8"
+            fontSize="11"
+            textAnchor="end"
+          >
+            {rotuloX}
+          </text>
+        ) : null}
+        {marcadores
+          .filter((m) => m.x >= xMin && m.x <= xMax)
+          .map((m, i) => (
+            <g key={`m-${m.rotulo}-${i}`}>
+              <line
+                x1={escalaX(m.x)}
+                x2={escalaX(m.x)}
+                y1={MARGEM.topo}
+                y2={ALTURA - MARGEM.base}
+                stroke={m.cor}
+                strokeDasharray="3 3"
+              />
+              <text
+                x={escalaX(m.x) + 4}
+                y={MARGEM.topo + 12 + (i % 3) * 13}
+                fill={m.cor}
+                fontSize="11"
+              >
+                {m.rotulo}
+              </text>
+            </g>
+          ))}
+        {series.map((serie) => (
+          <path
+            key={serie.nome}
+            d={caminho(serie)}
+            fill="none"
+            stroke={serie.cor}
+            strokeWidth={2}
+            strokeDasharray={serie.tracejado ? '6 4' : undefined}
+          />
+        ))}
+      </svg>
+      <div style={{ ...styles.modeRow, marginTop: '6px' }}>
+        {series.map((serie) => (
+          <span key={serie.nome} style={{ ...styles.sectionText, color: serie.cor }}>
+            {serie.tracejado ? '- - ' : '— '}
+            {serie.nome}
+          </span>
+        ))}
+      </div>
+    </section>
+  );
+}
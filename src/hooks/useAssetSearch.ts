@@ -1,5 +1,6 @@
 import { useCallback, useEffect, useRef, useState } from 'react';
-import { getAssetYahoo, searchAssetsYahoo } from '../services/yahooApi';
+import { normalizeText, normalizeTicker } from '../services/githubMarketData';
+import { type MarketDataProvider, getProvider } from '../services/marketDataProvider';
 import { AssetDetails, AssetResult } from '../types';
 
 type CacheEntry<T> = {
@@ -7,14 +8,31 @@ type CacheEntry<T> = {
   expiresAt: number;
 };
 
-const cacheAutocomplete: Map<string, CacheEntry<AssetResult[]>> = new Map();
-const cacheLookup: Map<string, CacheEntry<AssetDetails>> = new Map();
+// Cache por instancia de provedor: recarregar um arquivo descarta o cache antigo.
+type Cache<T> = WeakMap<MarketDataProvider, Map<string, CacheEntry<T>>>;
+
+const cacheAutocomplete: Cache<AssetResult[]> = new WeakMap();
+const cacheLookup: Cache<AssetDetails> = new WeakMap();
+
+function cacheFor<T>(cache: Cache<T>, provider: MarketDataProvider) {
+  let entries = cache.get(provider);
+  if (!entries) {
+    entries = new Map();
+    cache.set(provider, entries);
+  }
+  return entries;
+}
 
 const AUTOCOMPLETE_TTL_MS = 60 * 1000;
 const LOOKUP_TTL_MS = 5 * 60 * 1000;
 const DEBOUNCE_MS = 350;
+const MIN_CHARS = 2;
 
-export function useAssetSearch() {
+/**
+ * Busca com debounce, cancelamento e cache sobre o provedor registrado com o
+ * id `source`.
+ */
+export function useAssetSearch(source = 'github') {
   const [query, setQuery] = useState('');
   const [results, setResults] = useState<AssetResult[]>([]);
   const [selectedAsset, setSelectedAsset] = useState<AssetDetails | null>(null);
@@ -26,39 +44,34 @@ export function useAssetSearch() {
   const autocompleteController = useRef<AbortController | null>(null);
   const lookupController = useRef<AbortController | null>(null);
   const debounceTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
+  // Callbacks estaveis leem a fonte atual por ref.
+  const sourceRef = useRef(source);
+  sourceRef.current = source;
+
+  const cancelPending = () => {
+    if (debounceTimer.current) {
+      clearTimeout(debounceTimer.current);
+      debounceTimer.current = null;
+    }
+    if (autocompleteController.current) {
+      autocompleteController.current.abort();
+    }
+  };
 
-  const normalizeQuery = (text: string) =>
-    text
-      .toLowerCase()
-      .trim()
-      .replace(/\s+/g, ' ');
-
-  const onChangeQuery = useCallback(
-    (text: string) => {
-      setQuery(text);
-      setErrorAutocomplete(null);
-      if (debounceTimer.current) {
-        clearTimeout(debounceTimer.current);
-      }
-      const normalized = normalizeQuery(text);
-      if (normalized.length < 2) {
-        setResults([]);
-        return;
-      }
-
-      debounceTimer.current = setTimeout(() => {
-        void runAutocomplete(normalized);
-      }, DEBOUNCE_MS);
-    },
-    [],
-  );
-
+  // Devolve null quando a busca foi abortada ou falhou.
   const runAutocomplete = useCallback(
-    async (normalizedQuery: string) => {
-      const cached = cacheAutocomplete.get(normalizedQuery);
+    async (normalizedQuery: string): Promise<AssetResult[] | null> => {
+      const provider = getProvider(sourceRef.current);
+      if (!provider) {
+        setErrorAutocomplete('Fonte de dados indisponivel.');
+        return null;
+      }
+      const cache = cacheFor(cacheAutocomplete, provider);
+      const cached = cache.get(normalizedQuery);
       if (cached && cached.expiresAt > Date.now()) {
         setResults(cached.value);
-        return;
+        setErrorAutocomplete(cached.value.length === 0 ? 'Nenhum resultado.' : null);
+        return cached.value;
       }
 
       if (autocompleteController.current) {
@@ -70,33 +83,63 @@ export function useAssetSearch() {
       setLoadingAutocomplete(true);
       setErrorAutocomplete(null);
       try {
-        const res = await searchAssetsYahoo(
-          normalizedQuery,
-          controller.signal,
-        );
+        const res = await provider.search(normalizedQuery, controller.signal);
         setResults(res);
-        cacheAutocomplete.set(normalizedQuery, {
+        cache.set(normalizedQuery, {
           value: res,
           expiresAt: Date.now() + AUTOCOMPLETE_TTL_MS,
         });
         if (res.length === 0) {
           setErrorAutocomplete('Nenhum resultado.');
         }
+        return res;
       } catch (err) {
-        if ((err as Error).name === 'AbortError') return;
+        if ((err as Error).name === 'AbortError') return null;
         console.error('autocomplete error', normalizedQuery, err);
         setErrorAutocomplete('Erro ao buscar ativos.');
+        return null;
       } finally {
-        setLoadingAutocomplete(false);
+        if (autocompleteController.current === controller) {
+          setLoadingAutocomplete(false);
+        }
       }
     },
     [],
   );
 
+  const onChangeQuery = useCallback(
+    (text: string) => {
+      setQuery(text);
+      setErrorAutocomplete(null);
+      cancelPending();
+      const normalized = normalizeText(text);
+      if (normalized.length < MIN_CHARS) {
+        setLoadingAutocomplete(false);
+        setResults([]);
+        return;
+      }
+
+      debounceTimer.current = setTimeout(() => {
+        void runAutocomplete(normalized);
+      }, DEBOUNCE_MS);
+    },
+    [runAutocomplete],
+  );
+
   const onSelectResult = useCallback(
     async (item: AssetResult) => {
+      cancelPending();
+      setQuery(item.symbol);
+      setResults([]);
+      setErrorAutocomplete(null);
       setErrorLookup(null);
-      const cached = cacheLookup.get(item.symbol);
+      const provider = getProvider(sourceRef.current);
+      if (!provider) {
+        setErrorLookup('Fonte de dados indisponivel.');
+        return;
+      }
+      const cache = cacheFor(cacheLookup, provider);
+      const cached = cache.get(item.symbol);
       if (cached && cached.expiresAt > Date.now()) {
         setSelectedAsset(cached.value);
         return;
@@ -110,9 +153,9 @@ export function useAssetSearch() {
 
       setLoadingLookup(true);
       try {
-        const detail = await getAssetYahoo(item.symbol, controller.signal);
+        const detail = await provider.quote(item.symbol, controller.signal);
         setSelectedAsset(detail);
-        cacheLookup.set(item.symbol, {
+        cache.set(item.symbol, {
           value: detail,
           expiresAt: Date.now() + LOOKUP_TTL_MS,
         });
@@ -121,23 +164,59 @@ export function useAssetSearch() {
         console.error('lookup error', item.symbol, err);
         setErrorLookup('Erro ao carregar ativo.');
       } finally {
-        setLoadingLookup(false);
+        if (lookupController.current === controller) {
+          setLoadingLookup(false);
+        }
       }
     },
     [],
   );
 
+  /**
+   * Busca imediata (botao "Pesquisar"): seleciona direto o ticker exato ou o
+   * unico resultado.
+   */
+  const searchNow = useCallback(async () => {
+    cancelPending();
+    const normalized = normalizeText(query);
+    if (!normalized) {
+      setResults([]);
+      setErrorAutocomplete('Informe um ticker ou nome.');
+      return;
+    }
+    const res = await runAutocomplete(normalized);
+    if (!res || res.length === 0) return;
+    const wanted = normalizeTicker(normalized);
+    const preferred =
+      res.find((item) => item.symbol.toUpperCase() === wanted) ??
+      res.find((item) => item.symbol.toUpperCase() === normalized.toUpperCase()) ??
+      (res.length === 1 ? res[0] : null);
+    if (preferred) {
+      await onSelectResult(preferred);
+    }
+  }, [query, runAutocomplete, onSelectResult]);
+
+  // Trocar (ou recarregar) a fonte invalida resultados e selecao anteriores.
+  const currentProvider = getProvider(source);
+  useEffect(() => {
+    cancelPending();
+    if (lookupController.current) {
+      lookupController.current.abort();
+    }
+    setResults([]);
+    setSelectedAsset(null);
+    setLoadingAutocomplete(false);
+    setLoadingLookup(false);
+    setErrorAutocomplete(null);
+    setErrorLookup(null);
+  }, [currentProvider]);
+
   useEffect(
     () => () => {
-      if (autocompleteController.current) {
-        autocompleteController.current.abort();
-      }
+      cancelPending();
       if (lookupController.current) {
         lookupController.current.abort();
       }
-      if (debounceTimer.current) {
-        clearTimeout(debounceTimer.current);
-      }
     },
     [],
   );
@@ -152,5 +231,6 @@ export function useAssetSearch() {
     errorLookup,
     onChangeQuery,
     onSelectResult,
+    searchNow,
   };
 }
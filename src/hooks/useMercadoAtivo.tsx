@@ -0,0 +1,39 @@
+import type React from 'react';
+import { createContext, useContext, useMemo, useState } from 'react';
+import type { SuperficieVolatilidade } from '../utils/volSurface';
+import type { CurvaJuros } from '../utils/yieldCurve';
+
+type MercadoAtivo = {
+  /** Curva usada pelo formulario para preencher r no prazo da opcao. */
+  curva: CurvaJuros | null;
+  definirCurva: (curva: CurvaJuros | null) => void;
+  /** Superficie usada pelo formulario para preencher sigma. */
+  superficie: SuperficieVolatilidade | null;
+  definirSuperficie: (superficie: SuperficieVolatilidade | null) => void;
+};
+
+const MercadoAtivoContext = createContext<MercadoAtivo | null>(null);
+
+/**
+ * Guarda os dados de mercado escolhidos nas paginas auxiliares enquanto o app
+ * estiver aberto, para o formulario principal consultar.
+ */
+export function MercadoAtivoProvider({ children }: { children: React.ReactNode }) {
+  const [curva, definirCurva] = useState<CurvaJuros | null>(null);
+  const [superficie, definirSuperficie] = useState<SuperficieVolatilidade | null>(null);
+  const valor = useMemo(
+    () => ({ curva, definirCurva, superficie, definirSuperficie }),
+    [curva, superficie],
+  );
+  return (
+    <MercadoAtivoContext.Provider value={valor}>{children}</MercadoAtivoContext.Provider>
+  );
+}
+
+export function useMercadoAtivo(): MercadoAtivo {
+  const contexto = useContext(MercadoAtivoContext);
+  if (!contexto) {
+    throw new Error('useMercadoAtivo precisa estar dentro de MercadoAtivoProvider.');
+  }
+  return contexto;
+}
@@ -0,0 +1,346 @@
+import type React from 'react';
+import { useState } from 'react';
+import { useLocation, useNavigate } from 'react-router-dom';
+import { styles } from '../styles';
+import { Input, Select } from '../components/Fields';
+import { listarCalendarios } from '../utils/holidayCalendar';
+import { type FormState, resolverCalendario } from '../utils/scenario';
+import {
+  type VencimentoCadeia,
+  calcularCadeia,
+  gerarFaixaStrikes,
+  parseListaStrikes,
+  parseListaVencimentos,
+} from '../utils/optionChain';
+import { formatDateInput, parseDate } from '../utils/dateHelpers';
+
+type ModoStrikes = 'faixa' | 'lista';
+
+type CadeiaState = {
+  S: string;
+  r: string;
+  sigma: string;
+  q: string;
+  taxaAluguel: string;
+  dataAtual: string;
+  vencimentos: string;
+  strikeInicial: string;
+  strikeFinal: string;
+  passoStrike: string;
+  listaStrikes: string;
+  calendario: string;
+  feriadosExtras: string;
+};
+
+function arredondar(valor: number, casas = 2): string {
+  const fator = 10 ** casas;
+  return String(Math.round(valor * fator) / fator);
+}
+
+// Parte do formulario principal quando vem de la; faixa de +-20% em torno do spot.
+function estadoInicial(form?: FormState): CadeiaState {
+  const S = Number(form?.S ?? '100') || 100;
+  const vencimento = new Date();
+  vencimento.setDate(vencimento.getDate() + 90);
+  return {
+    S: form?.S ?? '100',
+    r: form?.r ?? '0.05',
+    sigma: form?.sigma ?? '0.2',
+    q: form?.q ?? '0',
+    taxaAluguel: form?.taxaAluguel ?? '0',
+    dataAtual: form?.dataAtual ?? formatDateInput(new Date()),
+    vencimentos: form?.dataVencimento ?? formatDateInput(vencimento),
+    strikeInicial: arredondar(S * 0.8),
+    strikeFinal: arredondar(S * 1.2),
+    passoStrike: arredondar(Math.max(S * 0.05, 0.01)),
+    listaStrikes: '',
+    calendario: form?.calendario ?? 'b3',
+    feriadosExtras: form?.feriadosExtras ?? '',
+  };
+}
+
+export default function ChainPage() {
+  const location = useLocation();
+  const navigate = useNavigate();
+  const origem = (location.state as { form?: FormState } | null)?.form;
+  const [form, setForm] = useState<CadeiaState>(() => estadoInicial(origem));
+  const [modoStrikes, setModoStrikes] = useState<ModoStrikes>('faixa');
+  const [cadeia, setCadeia] = useState<VencimentoCadeia[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleChange = (key: keyof CadeiaState, value: string) => {
+    setForm((prev) => ({ ...prev, [key]: value }));
+  };
+
+  const calcular = (event: React.FormEvent) => {
+    event.preventDefault();
+    setError(null);
+    setCadeia(null);
+
+    const S = Number(form.S);
+    const r = Number(form.r);
+    const sigma = Number(form.sigma);
+    const q = Number(form.q || '0');
+    const taxaAluguel = Number(form.taxaAluguel || '0');
+    if ([S, r, sigma, q, taxaAluguel].some((n) => Number.isNaN(n)) || S <= 0) {
+      setError('Preencha valores numericos validos.');
+      return;
+    }
+    const dataAtual = parseDate(form.dataAtual);
+    if (!dataAtual) {
+      setError('Data atual deve estar no formato DD/MM/AAAA.');
+      return;
+    }
+
+    const strikes =
+      modoStrikes === 'faixa'
+        ? gerarFaixaStrikes(
+          Number(form.strikeInicial),
+          Number(form.strikeFinal),
+          Number(form.passoStrike),
+        )
+        : parseListaStrikes(form.listaStrikes);
+    if (!strikes.ok) {
+      setError(strikes.error);
+      return;
+    }
+    const vencimentos = parseListaVencimentos(form.vencimentos);
+    if (!vencimentos.ok) {
+      setError(vencimentos.error);
+      return;
+    }
+    const calendario = resolverCalendario(form);
+    if (!calendario.ok) {
+      setError(calendario.error);
+      return;
+    }
+
+    const calculada = calcularCadeia(
+      S,
+      strikes.strikes,
+      r,
+      sigma,
+      dataAtual,
+      vencimentos.vencimentos,
+      q,
+      taxaAluguel,
+      calendario.calendario,
+    );
+    if (!calculada.ok) {
+      setError(calculada.error);
+      return;
+    }
+    setCadeia(calculada.cadeia);
+  };
+
+  return (
+    <main style={{ ...styles.container, maxWidth: '1100px' }}>
+      <div>
+        <h1 style={styles.title}>Cadeia de opcoes</h1>
+        <p style={styles.subtitle}>
+          Black-Scholes classico para cada strike e vencimento. A linha destacada e
+          o strike mais proximo do forward.
+        </p>
+      </div>
+
+      <form style={styles.form} onSubmit={calcular}>
+        <Input
+          label="S - Preco do ativo"
+          value={form.S}
+          onChange={(e) => handleChange('S', e.target.value)}
+          inputMode="decimal"
+        />
+        <Input
+          label="r - Taxa livre de risco (anual)"
+          value={form.r}
+          onChange={(e) => handleChange('r', e.target.value)}
+          inputMode="decimal"
+        />
+        <Input
+          label="sigma - Volatilidade anual"
+          hint="A mesma volatilidade e usada em toda a cadeia."
+          value={form.sigma}
+          onChange={(e) => handleChange('sigma', e.target.value)}
+          inputMode="decimal"
+        />
+        <Input
+          label="q - Dividend yield (anual)"
+          value={form.q}
+          onChange={(e) => handleChange('q', e.target.value)}
+          inputMode="decimal"
+          placeholder="0"
+        />
+        <Input
+          label="Taxa de aluguel (anual, opcional)"
+          value={form.taxaAluguel}
+          onChange={(e) => handleChange('taxaAluguel', e.target.value)}
+          inputMode="decimal"
+          placeholder="0"
+        />
+        <Input
+          label="Data atual (DD/MM/AAAA)"
+          value={form.dataAtual}
+          onChange={(e) => handleChange('dataAtual', e.target.value)}
+          inputMode="text"
+        />
+        <label style={styles.inputGroup}>
+          <span style={styles.label}>Vencimentos (DD/MM/AAAA, um por linha)</span>
+          <textarea
+            style={{ ...styles.input, minHeight: '72px', resize: 'vertical' }}
+            value={form.vencimentos}
+            onChange={(e) => handleChange('vencimentos', e.target.value)}
+            placeholder={'20/03/2026\n17/04/2026\n15/05/2026'}
+          />
+        </label>
+
+        <div style={styles.modeRow}>
+          <button
+            style={
+              modoStrikes === 'faixa' ? styles.modeButtonActive : styles.modeButton
+            }
+            type="button"
+            onClick={() => setModoStrikes('faixa')}
+          >
+            Faixa de strikes
+          </button>
+          <button
+            style={
+              modoStrikes === 'lista' ? styles.modeButtonActive : styles.modeButton
+            }
+            type="button"
+            onClick={() => setModoStrikes('lista')}
+          >
+            Lista de strikes
+          </button>
+        </div>
+        {modoStrikes === 'faixa' ? (
+          <div style={styles.searchRow}>
+            <Input
+              label="Strike inicial"
+              value={form.strikeInicial}
+              onChange={(e) => handleChange('strikeInicial', e.target.value)}
+              inputMode="decimal"
+            />
+            <Input
+              label="Strike final"
+              value={form.strikeFinal}
+              onChange={(e) => handleChange('strikeFinal', e.target.value)}
+              inputMode="decimal"
+            />
+            <Input
+              label="Passo"
+              value={form.passoStrike}
+              onChange={(e) => handleChange('passoStrike', e.target.value)}
+              inputMode="decimal"
+            />
+          </div>
+        ) : (
+          <Input
+            label="Strikes"
+            hint="Separados por espaco ou ';' (ex.: 30 32,5 35)."
+            value={form.listaStrikes}
+            onChange={(e) => handleChange('listaStrikes', e.target.value)}
+            inputMode="text"
+            placeholder="30 32,5 35 37,5 40"
+          />
+        )}
+        <Select
+          label="Calendario de feriados"
+          value={form.calendario}
+          onChange={(e) => handleChange('calendario', e.target.value)}
+          options={listarCalendarios().map((cal) => ({
+            value: cal.id,
+            label: cal.nome,
+          }))}
+        />
+
+        {error ? <p style={styles.error}>{error}</p> : null}
+
+        <div style={{ display: 'flex', gap: '12px', flexWrap: 'wrap' }}>
+          <button style={{ ...styles.button, ...styles.actionButton }} type="submit">
+            Calcular cadeia
+          </button>
+          <button
+            style={{ ...styles.secondaryButton, ...styles.actionButton }}
+            type="button"
+            onClick={() =>
+              navigate('/', { state: origem ? { form: origem } : null })
+            }
+          >
+            Voltar
+          </button>
+        </div>
+      </form>
+
+      {cadeia?.map((vencimento) => (
+        <TabelaVencimento
+          key={vencimento.dataVencimento.getTime()}
+          vencimento={vencimento}
+        />
+      ))}
+    </main>
+  );
+}
+
+const COLUNAS = [
+  'Call',
+  'Delta C',
+  'Theta C',
+  'Strike',
+  'K/S',
+  'ln(K/F)/sd',
+  'Put',
+  'Delta P',
+  'Theta P',
+  'Gama',
+  'Vega',
+];
+
+function TabelaVencimento({ vencimento }: { vencimento: VencimentoCadeia }) {
+  return (
+    <section style={styles.section}>
+      <p style={styles.sectionTitle}>
+        Vencimento {formatDateInput(vencimento.dataVencimento)} -{' '}
+        {vencimento.diasUteis} dias uteis | T = {vencimento.T.toFixed(4)} | F ={' '}
+        {vencimento.forward.toFixed(4)}
+      </p>
+      <div style={styles.tableWrapper}>
+        <table style={styles.table}>
+          <thead>
+            <tr>
+              {COLUNAS.map((coluna) => (
+                <th key={coluna} style={styles.tableHeader}>
+                  {coluna}
+                </th>
+              ))}
+            </tr>
+          </thead>
+          <tbody>
+            {vencimento.linhas.map((linha) => (
+              <tr key={linha.K} style={linha.atm ? styles.tableRowAtm : undefined}>
+                <td style={styles.tableCell}>{linha.call.toFixed(4)}</td>
+                <td style={styles.tableCell}>{linha.gregas.deltaCall.toFixed(4)}</td>
+                <td style={styles.tableCell}>{linha.gregas.thetaCall.toFixed(4)}</td>
+                <td style={{ ...styles.tableCell, fontWeight: 700 }}>
+                  {linha.K}
+                  {linha.atm ? ' (ATM)' : ''}
+                </td>
+                <td style={styles.tableCell}>
+                  {(linha.moneyness * 100).toFixed(1)}%
+                </td>
+                <td style={styles.tableCell}>
+                  {linha.moneynessPadronizada.toFixed(2)}
+                </td>
+                <td style={styles.tableCell}>{linha.put.toFixed(4)}</td>
+                <td style={styles.tableCell}>{linha.gregas.deltaPut.toFixed(4)}</td>
+                <td style={styles.tableCell}>{linha.gregas.thetaPut.toFixed(4)}</td>
+                <td style={styles.tableCell}>{linha.gregas.gama.toFixed(4)}</td>
+                <td style={styles.tableCell}>{linha.gregas.vega.toFixed(4)}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      </div>
+    </section>
+  );
+}
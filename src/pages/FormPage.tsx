@@ -1,353 +1,178 @@
 import type React from 'react';
-import { useEffect, useRef, useState } from 'react';
-import { useNavigate } from 'react-router-dom';
+import { useEffect, useState } from 'react';
+import { useLocation, useNavigate } from 'react-router-dom';
+import { listarCalendarios } from '../utils/holidayCalendar';
+import { volatilidadeDaSuperficie } from '../utils/volSurface';
+import { taxaParaVencimento } from '../utils/yieldCurve';
+import { PRODUTOS_EXOTICOS, TIPOS_BARREIRA } from '../utils/exoticOptions';
+import { MODELOS_SALTOS } from '../utils/jumpDiffusion';
 import {
-  blackScholesCall,
-  blackScholesPut,
-  blackScholesCallModified,
-  blackScholesPutModified,
-  calcularDiasUteis,
-  calcularTempoEmAnos,
-  calculaGregas,
-} from '../utils/blackScholes';
+  type FormState,
+  type Modo,
+  type Variant,
+  ROTAS_RESULTADO,
+  calcularCenario,
+  cenarioParaQuery,
+} from '../utils/scenario';
 import { styles } from '../styles';
-import { parseDate, formatDateInput } from '../utils/dateHelpers';
-import { type SearchItem } from '../api/search';
+import { InfoTip, Input, Select } from '../components/Fields';
+import { formatDateInput, parseDate } from '../utils/dateHelpers';
+import { useAssetSearch } from '../hooks/useAssetSearch';
+import { useMercadoAtivo } from '../hooks/useMercadoAtivo';
+import {
+  createFileProvider,
+  listProviders,
+  registerProvider,
+} from '../services/marketDataProvider';
 
-type FormState = {
-  S: string;
-  K: string;
-  r: string;
-  sigma: string;
-  p: string;
-  dataAtual: string;
-  dataVencimento: string;
-};
+type Subjacente = 'spot' | 'futuro' | 'cambio';
 
 const today = new Date();
 const defaultVencimento = new Date();
 defaultVencimento.setDate(today.getDate() + 90);
 
-const MARKET_DATA_URL =
-  'https://raw.githubusercontent.com/iagomarcolino/Consultprice/main/data/marketdata.json';
-const AUTOCOMPLETE_MIN_CHARS = 2;
-const AUTOCOMPLETE_DEBOUNCE_MS = 350;
-const MARKET_CACHE_TTL_MS = 5 * 60 * 1000;
-
-function normalizeTicker(raw: string): string {
-  const ticker = raw.trim().toUpperCase();
-  if (!ticker) return '';
-  return ticker.includes('.') ? ticker : `${ticker}.SA`;
-}
-
-function normalizeText(raw: string): string {
-  return raw
-    .toLowerCase()
-    .trim()
-    .replace(/\s+/g, ' ');
-}
-
-type MarketDataItem = {
-  symbol?: string;
-  name?: string;
-  price?: number;
-  vol_annual?: number;
-};
-
-const marketDataCache: {
-  data: MarketDataItem[] | null;
-  fetchedAt: number;
-} = {
-  data: null,
-  fetchedAt: 0,
-};
-
-async function loadMarketData(
-  signal?: AbortSignal,
-): Promise<MarketDataItem[]> {
-  if (
-    marketDataCache.data &&
-    Date.now() - marketDataCache.fetchedAt < MARKET_CACHE_TTL_MS
-  ) {
-    return marketDataCache.data;
-  }
-
-  const resp = await fetch(`${MARKET_DATA_URL}?t=${Date.now()}`, {
-    cache: 'no-store',
-    signal,
-  });
-  if (!resp.ok) {
-    throw new Error('HTTP error');
-  }
-
-  const json = await resp.json();
-  const data = Array.isArray(json?.data) ? json.data : [];
-  marketDataCache.data = data;
-  marketDataCache.fetchedAt = Date.now();
-  return data;
-}
-
-function getMatches(
-  data: MarketDataItem[],
-  rawTerm: string,
-): MarketDataItem[] {
-  const term = rawTerm.trim();
-  if (!term) return [];
-
-  const exactSymbol = normalizeTicker(term);
-  const partialSymbol = term.toUpperCase();
-  const normalizedName = normalizeText(term);
-
-  return data.filter((item) => {
-    const symbol =
-      typeof item?.symbol === 'string' ? item.symbol.toUpperCase() : '';
-    const name = typeof item?.name === 'string' ? item.name : '';
-    const symbolExactMatch = symbol !== '' && symbol === exactSymbol;
-    const symbolPartialMatch =
-      partialSymbol.length >= 2 && symbol.includes(partialSymbol);
-    const nameMatch =
-      normalizedName.length >= 2 &&
-      normalizeText(name).includes(normalizedName);
-    return symbolExactMatch || symbolPartialMatch || nameMatch;
-  });
-}
-
-function toSearchItem(
-  item: MarketDataItem,
-  fallbackSymbol: string,
-): SearchItem {
-  const symbol = item.symbol ?? fallbackSymbol;
-  return {
-    symbol,
-    name: item.name ?? symbol,
-    exchange: 'Dados GitHub',
-    price: item.price,
-    volAnnual: item.vol_annual,
-  };
-}
-
 const initialState: FormState = {
   S: '100',
   K: '105',
   r: '0.05',
   sigma: '0.2',
+  q: '0',
+  taxaAluguel: '0',
+  rf: '0.045',
+  nocional: '100000',
   p: '1.0',
+  premio: '',
+  tipoOpcao: 'call',
+  calendario: 'b3',
+  feriadosExtras: '',
+  dividendos: '',
+  metodoAmericano: 'bjerksundStensland',
+  passosBinomial: '200',
+  produto: 'vanilla',
+  tipoBarreira: 'downOut',
+  barreira: '',
+  rebate: '0',
+  monitoramento: '1',
+  pagamento: '1',
+  modeloSaltos: 'merton',
+  lambdaSaltos: '1',
+  mediaSalto: '-0.05',
+  volSalto: '0.1',
+  probSaltoAlta: '0.4',
   dataAtual: formatDateInput(today),
   dataVencimento: formatDateInput(defaultVencimento),
 };
 
 export default function FormPage() {
-  const [form, setForm] = useState<FormState>(initialState);
+  const location = useLocation();
+  // Paginas auxiliares (cadeia, superficie...) devolvem o formulario ao voltar.
+  const [form, setForm] = useState<FormState>(
+    () => (location.state as { form?: FormState } | null)?.form ?? initialState,
+  );
+  const [modo, setModo] = useState<Modo>('preco');
+  const [subjacente, setSubjacente] = useState<Subjacente>('spot');
   const [error, setError] = useState<string | null>(null);
-  const [searchTerm, setSearchTerm] = useState('');
-  const [searchResults, setSearchResults] = useState<SearchItem[]>([]);
-  const [pinnedResults, setPinnedResults] = useState<SearchItem[] | null>(null);
-  const [selectedResultIndex, setSelectedResultIndex] = useState('');
-  const [searchMessage, setSearchMessage] = useState<string | null>(null);
-  const [searchLoading, setSearchLoading] = useState(false);
+  const [fonteAtivos, setFonteAtivos] = useState('github');
+  const [arquivoMercado, setArquivoMercado] = useState<string | null>(null);
+  const busca = useAssetSearch(fonteAtivos);
+  const { curva: curvaAtiva, superficie: superficieAtiva } = useMercadoAtivo();
   const navigate = useNavigate();
-  const autocompleteController = useRef<AbortController | null>(null);
-  const debounceTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
-  const displayedResults = pinnedResults ?? searchResults;
+  const { selectedAsset } = busca;
 
-  const applySearchItemToForm = (item: SearchItem) => {
+  // Aplica ao formulario os dados do ativo escolhido na busca.
+  useEffect(() => {
+    if (!selectedAsset) return;
     setForm((prev) => ({
       ...prev,
-      ...(item.price !== undefined ? { S: String(item.price) } : {}),
-      ...(item.volAnnual !== undefined
-        ? { sigma: String(item.volAnnual) }
+      ...(selectedAsset.price !== undefined ? { S: String(selectedAsset.price) } : {}),
+      ...(selectedAsset.volAnnual !== undefined
+        ? { sigma: String(selectedAsset.volAnnual) }
+        : {}),
+      ...(selectedAsset.dividendYield !== undefined
+        ? { q: String(selectedAsset.dividendYield) }
         : {}),
     }));
-  };
+  }, [selectedAsset]);
 
-  const handleSearch = async () => {
-    const rawTerm = searchTerm.trim();
-    const normalizedSymbol = normalizeTicker(rawTerm);
-    if (!rawTerm) {
-      setSearchMessage('Informe um ticker ou nome.');
-      setSearchResults([]);
-      setPinnedResults(null);
-      setSelectedResultIndex('');
-      return;
-    }
-    setSearchLoading(true);
-    setSearchMessage(null);
-    setSearchResults([]);
-    setPinnedResults(null);
-    setSelectedResultIndex('');
-    try {
-      const data = await loadMarketData();
-      const matches = getMatches(data, rawTerm);
-
-      if (matches.length === 0) {
-        setSearchMessage('Ativo nao encontrado.');
-        return;
-      }
+  const handleChange = (key: keyof FormState, value: string) => {
+    setForm((prev) => ({ ...prev, [key]: value }));
+  };
 
-      const exactSymbolMatch = matches.find((item) => {
-        if (typeof item?.symbol !== 'string') return false;
-        return item.symbol.toUpperCase() === normalizedSymbol;
-      });
-      const preferredMatch =
-        exactSymbolMatch ?? (matches.length === 1 ? matches[0] : null);
+  const handleSubmit = (event: React.FormEvent) => {
+    event.preventDefault();
+  };
 
-      if (preferredMatch) {
-        applySearchItemToForm(
-          toSearchItem(preferredMatch, normalizedSymbol),
-        );
-      }
+  const loadFeriadosFile = (event: React.ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    if (!file) return;
+    file
+      .text()
+      .then((text) => handleChange('feriadosExtras', text))
+      .catch(() => setError('Erro ao ler arquivo de feriados.'));
+    event.target.value = '';
+  };
 
-      setSearchResults(
-        matches.map((item) => toSearchItem(item, normalizedSymbol)),
-      );
-    } catch (err) {
-      setSearchMessage('Erro ao consultar dados.');
-    } finally {
-      setSearchLoading(false);
-    }
+  // Arquivo local (JSON/CSV) vira o provedor "arquivo" e passa a ser a fonte ativa.
+  const loadMercadoFile = (event: React.ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    if (!file) return;
+    file
+      .text()
+      .then((text) => {
+        const carregado = createFileProvider(text, file.name);
+        if (!carregado.ok) {
+          setError(carregado.error);
+          return;
+        }
+        registerProvider(carregado.provider);
+        setArquivoMercado(file.name);
+        setFonteAtivos(carregado.provider.id);
+      })
+      .catch(() => setError('Erro ao ler arquivo de dados de mercado.'));
+    event.target.value = '';
   };
 
-  useEffect(() => {
-    const term = searchTerm.trim();
-    if (debounceTimer.current) {
-      clearTimeout(debounceTimer.current);
+  const usarSigmaDaSuperficie = () => {
+    setError(null);
+    const K = Number(form.K);
+    const dataVencimento = parseDate(form.dataVencimento);
+    if (!superficieAtiva || !(K > 0) || !dataVencimento) {
+      setError('Informe K e vencimento validos para consultar a superficie.');
+      return;
     }
-
-    if (!term || term.length < AUTOCOMPLETE_MIN_CHARS) {
-      if (autocompleteController.current) {
-        autocompleteController.current.abort();
-      }
-      setSearchLoading(false);
-      setSearchResults([]);
-      setSelectedResultIndex('');
-      setSearchMessage(null);
+    const sigma = volatilidadeDaSuperficie(superficieAtiva, K, dataVencimento);
+    if (sigma === null || !Number.isFinite(sigma)) {
+      setError('Superficie sem dados para este strike/vencimento.');
       return;
     }
-
-    debounceTimer.current = setTimeout(() => {
-      if (autocompleteController.current) {
-        autocompleteController.current.abort();
-      }
-      const controller = new AbortController();
-      autocompleteController.current = controller;
-
-      setSearchLoading(true);
-      setSearchMessage(null);
-      loadMarketData(controller.signal)
-        .then((data) => {
-          const matches = getMatches(data, term);
-          setSearchResults(
-            matches.map((item) => toSearchItem(item, normalizeTicker(term))),
-          );
-          if (matches.length === 0) {
-            setSearchMessage('Nenhum resultado.');
-          }
-        })
-        .catch((err) => {
-          if ((err as Error).name === 'AbortError') return;
-          setSearchMessage('Erro ao consultar dados.');
-        })
-        .finally(() => {
-          setSearchLoading(false);
-        });
-    }, AUTOCOMPLETE_DEBOUNCE_MS);
-  }, [searchTerm]);
-
-  useEffect(
-    () => () => {
-      if (autocompleteController.current) {
-        autocompleteController.current.abort();
-      }
-      if (debounceTimer.current) {
-        clearTimeout(debounceTimer.current);
-      }
-    },
-    [],
-  );
-
-  const handleChange = (key: keyof FormState, value: string) => {
-    setForm((prev) => ({ ...prev, [key]: value }));
-  };
-
-  const handleSubmit = (event: React.FormEvent) => {
-    event.preventDefault();
+    handleChange('sigma', sigma.toFixed(6));
   };
 
-  const calculateClassic = (event: React.FormEvent) => {
-    event.preventDefault();
+  // Taxa zero da curva ativa no prazo em dias uteis da opcao, convertida para continua.
+  const usarTaxaDaCurva = () => {
     setError(null);
-    const parsed = parseInputs(form, { requireP: false });
-    if (!parsed.ok) {
-      setError(parsed.error);
+    const dataAtual = parseDate(form.dataAtual);
+    const dataVencimento = parseDate(form.dataVencimento);
+    if (!curvaAtiva || !dataAtual || !dataVencimento) {
+      setError('Informe datas validas para consultar a curva de juros.');
       return;
     }
-
-    const { S, K, r, sigma, dataAtual, dataVencimento } = parsed;
-    const diasUteis = calcularDiasUteis(dataAtual, dataVencimento);
-    const T = calcularTempoEmAnos(dataAtual, dataVencimento);
-    const call = blackScholesCall(S, K, r, sigma, dataAtual, dataVencimento);
-    const put = blackScholesPut(S, K, r, sigma, dataAtual, dataVencimento);
-    const gregas = calculaGregas(S, K, r, sigma, dataAtual, dataVencimento);
-
-    navigate('/resultado', {
-      state: {
-        variant: 'classico' as const,
-        result: {
-          call,
-          put,
-          T,
-          diasUteis,
-          ...gregas,
-          inputs: form,
-        },
-      },
-    });
+    const taxa = taxaParaVencimento(curvaAtiva, dataAtual, dataVencimento);
+    handleChange('r', taxa.taxaContinua.toFixed(6));
   };
 
-  const calculateModified = (event: React.FormEvent) => {
-    event.preventDefault();
+  const calcular = (variant: Variant) => {
     setError(null);
-    const parsed = parseInputs(form, { requireP: true });
-    if (!parsed.ok) {
-      setError(parsed.error);
+    const calculado = calcularCenario(variant, form, modo);
+    if (!calculado.ok) {
+      setError(calculado.error);
       return;
     }
 
-    const { S, K, r, sigma, p, dataAtual, dataVencimento } = parsed;
-    const diasUteis = calcularDiasUteis(dataAtual, dataVencimento);
-    const T = calcularTempoEmAnos(dataAtual, dataVencimento);
-    const call = blackScholesCallModified(
-      S,
-      K,
-      r,
-      sigma,
-      p,
-      dataAtual,
-      dataVencimento,
-    );
-    const put = blackScholesPutModified(
-      S,
-      K,
-      r,
-      sigma,
-      p,
-      dataAtual,
-      dataVencimento,
+    navigate(
+      `${ROTAS_RESULTADO[variant]}?${cenarioParaQuery(variant, form, modo)}`,
+      { state: calculado.state },
     );
-
-    navigate('/resultado-modificado', {
-      state: {
-        variant: 'modificado' as const,
-        p,
-        result: {
-          call,
-          put,
-          T,
-          diasUteis,
-          inputs: form,
-        },
-      },
-    });
   };
 
   return (
@@ -360,72 +185,153 @@ export default function FormPage() {
       </div>
 
       <div style={styles.searchRow}>
+        <select
+          style={{ ...styles.input, marginTop: 0, flex: '0 0 auto' }}
+          value={fonteAtivos}
+          onChange={(e) => setFonteAtivos(e.target.value)}
+          aria-label="Fonte de dados"
+        >
+          {listProviders().map((provider) => (
+            <option key={provider.id} value={provider.id}>
+              {provider.label}
+            </option>
+          ))}
+        </select>
         <input
           style={{ ...styles.input, ...styles.searchInput, marginTop: 0 }}
-          value={searchTerm}
-          onChange={(e) => {
-            setPinnedResults(null);
-            setSelectedResultIndex('');
-            setSearchTerm(e.target.value);
-          }}
+          value={busca.query}
+          onChange={(e) => busca.onChangeQuery(e.target.value)}
           placeholder="Buscar ativo (ticker ou nome, ex: PETR4.SA, Ambev)"
         />
         <button
           style={{ ...styles.secondaryButton, marginTop: 0 }}
           type="button"
-          onClick={handleSearch}
-          disabled={searchLoading}
+          onClick={() => void busca.searchNow()}
+          disabled={busca.loadingAutocomplete || busca.loadingLookup}
         >
-          {searchLoading ? 'Buscando...' : 'Pesquisar'}
+          {busca.loadingAutocomplete || busca.loadingLookup
+            ? 'Buscando...'
+            : 'Pesquisar'}
         </button>
       </div>
+      <label style={styles.inputGroup}>
+        <span style={styles.label}>
+          Dados de mercado de arquivo (JSON com ativos/curva ou CSV OHLC)
+          {arquivoMercado ? ` - carregado: ${arquivoMercado}` : ''}
+        </span>
+        <input
+          style={styles.sectionText}
+          type="file"
+          accept=".json,.csv,.txt"
+          onChange={loadMercadoFile}
+        />
+      </label>
 
-      {searchMessage ? <p style={styles.error}>{searchMessage}</p> : null}
-      {displayedResults.length > 0 ? (
+      {busca.errorAutocomplete ? (
+        <p style={styles.error}>{busca.errorAutocomplete}</p>
+      ) : null}
+      {busca.errorLookup ? <p style={styles.error}>{busca.errorLookup}</p> : null}
+      {busca.results.length > 0 ? (
         <select
           style={{ ...styles.input, ...styles.searchInput, marginTop: 0 }}
-          size={Math.min(6, displayedResults.length + 1)}
-          value={selectedResultIndex}
+          size={Math.min(6, busca.results.length + 1)}
+          value=""
           onChange={(e) => {
-            const value = e.target.value;
-            setSelectedResultIndex(value);
-            if (value === '') return;
-            const index = Number(value);
-            if (!Number.isFinite(index)) return;
-            const item = displayedResults[index];
-            if (!item) return;
-
-            // Aplica os dados ao formulário
-            applySearchItemToForm(item);
-
-            // Escreve o ticker do ativo na caixa de busca
-            setSearchTerm(item.symbol);
-
-            // Limpa os resultados para fechar a aba de sugestões
-            setSearchResults([]);
-            setPinnedResults(null);
-            setSelectedResultIndex('');
+            const item = busca.results[Number(e.target.value)];
+            if (item) {
+              void busca.onSelectResult(item);
+            }
           }}
         >
           <option value="" disabled>
             Selecione um ativo
           </option>
-          {displayedResults.map((item, index) => (
+          {busca.results.map((item, index) => (
             <option
               key={`${item.symbol}-${item.exchange}`}
               value={String(index)}
             >
               {item.symbol} - {item.name}
-              {item.price !== undefined ? ` (R$ ${item.price})` : ''}
+              {item.exchange ? ` (${item.exchange})` : ''}
             </option>
           ))}
         </select>
       ) : null}
+      {busca.selectedAsset ? (
+        <p style={styles.sectionText}>
+          {busca.selectedAsset.symbol} - {busca.selectedAsset.name}
+          {busca.selectedAsset.price !== undefined
+            ? ` | ${busca.selectedAsset.currency} ${busca.selectedAsset.price}`
+            : ''}
+          {busca.selectedAsset.timestampISO
+            ? ` | ${new Date(busca.selectedAsset.timestampISO).toLocaleString('pt-BR')}`
+            : ''}
+        </p>
+      ) : null}
 
       <form style={styles.form} onSubmit={handleSubmit}>
+        <div style={styles.modeRow}>
+          <button
+            style={modo === 'preco' ? styles.modeButtonActive : styles.modeButton}
+            type="button"
+            onClick={() => setModo('preco')}
+          >
+            Calcular preco
+          </button>
+          <button
+            style={
+              modo === 'volImplicita' ? styles.modeButtonActive : styles.modeButton
+            }
+            type="button"
+            onClick={() => setModo('volImplicita')}
+          >
+            Volatilidade implicita
+          </button>
+        </div>
+        <div style={styles.modeRow}>
+          <button
+            style={
+              subjacente === 'spot' ? styles.modeButtonActive : styles.modeButton
+            }
+            type="button"
+            onClick={() => setSubjacente('spot')}
+          >
+            Ativo a vista
+          </button>
+          <button
+            style={
+              subjacente === 'futuro' ? styles.modeButtonActive : styles.modeButton
+            }
+            type="button"
+            onClick={() => setSubjacente('futuro')}
+          >
+            Futuro (Black-76)
+          </button>
+          <button
+            style={
+              subjacente === 'cambio' ? styles.modeButtonActive : styles.modeButton
+            }
+            type="button"
+            onClick={() => setSubjacente('cambio')}
+          >
+            Cambio (Garman-Kohlhagen)
+          </button>
+        </div>
         <Input
-          label="S - Preco do ativo"
-          hint="Preco atual do ativo subjacente (spot)."
+          label={
+            subjacente === 'futuro'
+              ? 'F - Preco do futuro'
+              : subjacente === 'cambio'
+                ? 'S - Taxa de cambio (BRL por unidade estrangeira)'
+                : 'S - Preco do ativo'
+          }
+          hint={
+            subjacente === 'futuro'
+              ? 'Preco do contrato futuro (ex.: DOL, IND, DI1) com o mesmo vencimento da opcao.'
+              : subjacente === 'cambio'
+                ? 'Cotacao a vista da moeda estrangeira em reais (ex.: USD/BRL 5.40).'
+                : 'Preco atual do ativo subjacente (spot).'
+          }
           value={form.S}
           onChange={(e) => handleChange('S', e.target.value)}
           inputMode="decimal"
@@ -438,27 +344,155 @@ export default function FormPage() {
           inputMode="decimal"
         />
         <Input
-          label="r - Taxa livre de risco (anual)"
-          hint="Taxa livre de risco anual em decimal Selic(0.05 = 5%)."
+          label={
+            subjacente === 'cambio'
+              ? 'r - Taxa domestica (anual)'
+              : 'r - Taxa livre de risco (anual)'
+          }
+          hint="Taxa anual continua em decimal (0.05 = 5%); veja a curva DI."
           value={form.r}
           onChange={(e) => handleChange('r', e.target.value)}
           inputMode="decimal"
         />
-        <Input
-          label="sigma - Volatilidade anual"
-          hint="Volatilidade anual do ativo em decimal (0.2 = 20%)."
-          value={form.sigma}
-          onChange={(e) => handleChange('sigma', e.target.value)}
-          inputMode="decimal"
-        />
-        <Input
-          label="p - Parametro (modelo modificado)"
-          hint="Parametro do Black-Scholes modificado; use apenas no modificado (sugestao: 0.5 a 1.5)."
-          value={form.p}
-          onChange={(e) => handleChange('p', e.target.value)}
-          inputMode="decimal"
-          placeholder="1.0"
-        />
+        <div style={styles.searchRow}>
+          {curvaAtiva ? (
+            <button
+              style={{ ...styles.secondaryButton, marginTop: 0 }}
+              type="button"
+              onClick={usarTaxaDaCurva}
+            >
+              Usar r da curva (prazo da opcao)
+            </button>
+          ) : null}
+          <button
+            style={{ ...styles.secondaryButton, marginTop: 0 }}
+            type="button"
+            onClick={() => navigate('/curva-juros', { state: { form } })}
+          >
+            Curva de juros
+          </button>
+        </div>
+        {subjacente === 'cambio' ? (
+          <>
+            <Input
+              label="rf - Taxa estrangeira (anual)"
+              hint="Taxa livre de risco da moeda estrangeira em decimal (SOFR ou cupom cambial)."
+              value={form.rf}
+              onChange={(e) => handleChange('rf', e.target.value)}
+              inputMode="decimal"
+            />
+            <Input
+              label="Nocional (moeda estrangeira)"
+              hint="Quantidade de moeda estrangeira do contrato, usada no premio total em BRL."
+              value={form.nocional}
+              onChange={(e) => handleChange('nocional', e.target.value)}
+              inputMode="decimal"
+            />
+          </>
+        ) : null}
+        {modo === 'preco' ? (
+          <>
+            <Input
+              label="sigma - Volatilidade anual"
+              hint="Volatilidade anual do ativo em decimal (0.2 = 20%)."
+              value={form.sigma}
+              onChange={(e) => handleChange('sigma', e.target.value)}
+              inputMode="decimal"
+            />
+            <div style={styles.searchRow}>
+              {superficieAtiva ? (
+                <button
+                  style={{ ...styles.secondaryButton, marginTop: 0 }}
+                  type="button"
+                  onClick={usarSigmaDaSuperficie}
+                >
+                  Usar sigma da superficie (K e vencimento)
+                </button>
+              ) : null}
+              <button
+                style={{ ...styles.secondaryButton, marginTop: 0 }}
+                type="button"
+                onClick={() => navigate('/superficie', { state: { form } })}
+              >
+                Superficie de volatilidade
+              </button>
+              <button
+                style={{ ...styles.secondaryButton, marginTop: 0 }}
+                type="button"
+                onClick={() => navigate('/vol-historica', { state: { form } })}
+              >
+                Volatilidade historica
+              </button>
+              <button
+                style={{ ...styles.secondaryButton, marginTop: 0 }}
+                type="button"
+                onClick={() => navigate('/heston', { state: { form } })}
+              >
+                Heston (vol estocastica)
+              </button>
+            </div>
+          </>
+        ) : (
+          <>
+            <Select
+              label="Tipo da opcao"
+              hint="Tipo da opcao cujo premio foi observado no mercado."
+              value={form.tipoOpcao}
+              onChange={(e) => handleChange('tipoOpcao', e.target.value)}
+              options={[
+                { value: 'call', label: 'Call (compra)' },
+                { value: 'put', label: 'Put (venda)' },
+              ]}
+            />
+            <Input
+              label="Premio observado"
+              hint="Preco de mercado da opcao; sigma sera o valor que reproduz esse premio."
+              value={form.premio}
+              onChange={(e) => handleChange('premio', e.target.value)}
+              inputMode="decimal"
+            />
+          </>
+        )}
+        {subjacente === 'spot' ? (
+          <>
+            <Input
+              label="q - Dividend yield (anual)"
+              hint="Rendimento continuo de dividendos/JCP em decimal (0.08 = 8%). Use 0 para ativos sem dividendos."
+              value={form.q}
+              onChange={(e) => handleChange('q', e.target.value)}
+              inputMode="decimal"
+              placeholder="0"
+            />
+            <Input
+              label="Taxa de aluguel (anual, opcional)"
+              hint="Custo de aluguel (borrow/lease) do ativo em decimal; soma-se a q no ajuste do forward."
+              value={form.taxaAluguel}
+              onChange={(e) => handleChange('taxaAluguel', e.target.value)}
+              inputMode="decimal"
+              placeholder="0"
+            />
+            <label style={styles.inputGroup}>
+              <span style={styles.labelRow}>
+                <span style={styles.label}>Dividendos discretos (opcional)</span>
+                <InfoTip text="Uma linha por pagamento: data ex;valor por acao (DD/MM/AAAA;0.85). O VP dos pagamentos ate o vencimento e descontado de S." />
+              </span>
+              <textarea
+                style={{ ...styles.input, minHeight: '72px', resize: 'vertical' }}
+                value={form.dividendos}
+                onChange={(e) => handleChange('dividendos', e.target.value)}
+                placeholder="15/05/2026;0.85"
+              />
+            </label>
+            <Input
+              label="p - Parametro (modelo modificado)"
+              hint="Parametro do Black-Scholes modificado; use apenas no modificado (sugestao: 0.5 a 1.5)."
+              value={form.p}
+              onChange={(e) => handleChange('p', e.target.value)}
+              inputMode="decimal"
+              placeholder="1.0"
+            />
+          </>
+        ) : null}
         <Input
           label="Data atual (DD/MM/AAAA)"
           hint="Data base para calcular o tempo ate o vencimento."
@@ -475,129 +509,253 @@ export default function FormPage() {
           inputMode="text"
           placeholder="12/03/2026"
         />
+        <Select
+          label="Calendario de feriados"
+          hint="Feriados em dia de semana nao contam como dia util no calculo de T."
+          value={form.calendario}
+          onChange={(e) => handleChange('calendario', e.target.value)}
+          options={listarCalendarios().map((cal) => ({
+            value: cal.id,
+            label: cal.nome,
+          }))}
+        />
+        <label style={styles.inputGroup}>
+          <span style={styles.labelRow}>
+            <span style={styles.label}>Feriados adicionais (opcional)</span>
+            <InfoTip text="Uma data por linha (DD/MM/AAAA;Nome), somada ao calendario escolhido. Aceita arquivo .txt/.csv." />
+          </span>
+          <textarea
+            style={{ ...styles.input, minHeight: '72px', resize: 'vertical' }}
+            value={form.feriadosExtras}
+            onChange={(e) => handleChange('feriadosExtras', e.target.value)}
+            placeholder="25/01/2026;Aniversario de Sao Paulo"
+          />
+          <input
+            style={styles.sectionText}
+            type="file"
+            accept=".txt,.csv"
+            onChange={loadFeriadosFile}
+          />
+        </label>
+
+        {subjacente === 'spot' ? (
+          <Select
+            label="Produto"
+            hint="Vanilla usa os modelos classico, modificado e americano; exoticos usam formulas fechadas proprias."
+            value={form.produto}
+            onChange={(e) => handleChange('produto', e.target.value)}
+            options={[
+              { value: 'vanilla', label: 'Vanilla (call/put europeia ou americana)' },
+              ...Object.entries(PRODUTOS_EXOTICOS).map(([value, label]) => ({
+                value,
+                label,
+              })),
+            ]}
+          />
+        ) : null}
+        {subjacente === 'spot' && form.produto === 'digitalDinheiro' ? (
+          <Input
+            label="Pagamento da digital"
+            hint="Valor pago no vencimento se a opcao terminar dentro do dinheiro."
+            value={form.pagamento}
+            onChange={(e) => handleChange('pagamento', e.target.value)}
+            inputMode="decimal"
+            placeholder="1"
+          />
+        ) : null}
+        {subjacente === 'spot' && form.produto === 'barreira' ? (
+          <>
+            <Select
+              label="Tipo de barreira"
+              hint="Knock-out deixa de existir ao tocar a barreira; knock-in so passa a existir ao toca-la."
+              value={form.tipoBarreira}
+              onChange={(e) => handleChange('tipoBarreira', e.target.value)}
+              options={Object.entries(TIPOS_BARREIRA).map(([value, label]) => ({
+                value,
+                label,
+              }))}
+            />
+            <Input
+              label="H - Barreira"
+              hint="Nivel do ativo que ativa (in) ou extingue (out) a opcao."
+              value={form.barreira}
+              onChange={(e) => handleChange('barreira', e.target.value)}
+              inputMode="decimal"
+            />
+            <Input
+              label="Rebate"
+              hint="Pago no toque (knock-out) ou no vencimento se a barreira nunca for tocada (knock-in)."
+              value={form.rebate}
+              onChange={(e) => handleChange('rebate', e.target.value)}
+              inputMode="decimal"
+              placeholder="0"
+            />
+            <Input
+              label="Monitoramento (dias uteis entre observacoes)"
+              hint="1 = fechamento diario, 5 = semanal; 0 = monitoramento continuo. Datas discretas usam a correcao de Broadie-Glasserman-Kou."
+              value={form.monitoramento}
+              onChange={(e) => handleChange('monitoramento', e.target.value)}
+              inputMode="decimal"
+              placeholder="1"
+            />
+          </>
+        ) : null}
+        {subjacente === 'spot' && form.produto === 'vanilla' ? (
+          <>
+            <Select
+              label="Metodo americano"
+              hint="Usado em 'Calcular americano': aproximacao fechada de Bjerksund-Stensland (2002) ou arvore binomial."
+              value={form.metodoAmericano}
+              onChange={(e) => handleChange('metodoAmericano', e.target.value)}
+              options={[
+                { value: 'bjerksundStensland', label: 'Bjerksund-Stensland 2002' },
+                { value: 'crr', label: 'Binomial Cox-Ross-Rubinstein' },
+                { value: 'leisenReimer', label: 'Binomial Leisen-Reimer' },
+              ]}
+            />
+            {form.metodoAmericano !== 'bjerksundStensland' ? (
+              <Input
+                label="Passos da arvore binomial"
+                hint="Mais passos aumentam a precisao e o tempo de calculo (Leisen-Reimer usa numero impar)."
+                value={form.passosBinomial}
+                onChange={(e) => handleChange('passosBinomial', e.target.value)}
+                inputMode="decimal"
+                placeholder="200"
+              />
+            ) : null}
+            <Select
+              label="Modelo de saltos"
+              hint="Usado em 'Calcular com saltos': Merton (saltos lognormais, serie de Black-Scholes) ou Kou (caudas exponenciais assimetricas)."
+              value={form.modeloSaltos}
+              onChange={(e) => handleChange('modeloSaltos', e.target.value)}
+              options={Object.entries(MODELOS_SALTOS).map(([value, label]) => ({
+                value,
+                label,
+              }))}
+            />
+            <Input
+              label="Intensidade de saltos (por ano)"
+              hint="Numero esperado de saltos por ano; para um evento unico no prazo (ex.: balanco) use cerca de 1/T."
+              value={form.lambdaSaltos}
+              onChange={(e) => handleChange('lambdaSaltos', e.target.value)}
+              inputMode="decimal"
+              placeholder="1"
+            />
+            <Input
+              label="Media do salto (log-retorno)"
+              hint="Media do log-retorno de cada salto em decimal (-0.05 = queda media de ~5%)."
+              value={form.mediaSalto}
+              onChange={(e) => handleChange('mediaSalto', e.target.value)}
+              inputMode="decimal"
+              placeholder="-0.05"
+            />
+            <Input
+              label="Vol do salto"
+              hint="Desvio padrao do log-retorno de cada salto; sigma acima passa a ser so a vol da difusao."
+              value={form.volSalto}
+              onChange={(e) => handleChange('volSalto', e.target.value)}
+              inputMode="decimal"
+              placeholder="0.1"
+            />
+            {form.modeloSaltos === 'kou' ? (
+              <Input
+                label="Probabilidade de salto de alta"
+                hint="Chance de cada salto ser positivo no Kou; com a media e a vol define as duas caudas exponenciais."
+                value={form.probSaltoAlta}
+                onChange={(e) => handleChange('probSaltoAlta', e.target.value)}
+                inputMode="decimal"
+                placeholder="0.4"
+              />
+            ) : null}
+          </>
+        ) : null}
 
         {error ? <p style={styles.error}>{error}</p> : null}
 
         <div style={{ display: 'flex', gap: '12px', flexWrap: 'wrap' }}>
-          <button
-            style={{ ...styles.button, ...styles.actionButton }}
-            type="button"
-            onClick={calculateClassic}
-          >
-            Calcular classico
-          </button>
-          <button
-            style={{ ...styles.secondaryButton, ...styles.actionButton }}
-            type="button"
-            onClick={calculateModified}
-          >
-            Calcular modificado
-          </button>
+          {subjacente === 'futuro' ? (
+            <button
+              style={{ ...styles.button, ...styles.actionButton }}
+              type="button"
+              onClick={() => calcular('black76')}
+            >
+              Calcular Black-76
+            </button>
+          ) : subjacente === 'cambio' ? (
+            <button
+              style={{ ...styles.button, ...styles.actionButton }}
+              type="button"
+              onClick={() => calcular('fx')}
+            >
+              Calcular Garman-Kohlhagen
+            </button>
+          ) : (
+            <>
+              {form.produto === 'vanilla' ? (
+                <>
+                  <button
+                    style={{ ...styles.button, ...styles.actionButton }}
+                    type="button"
+                    onClick={() => calcular('classico')}
+                  >
+                    Calcular classico
+                  </button>
+                  <button
+                    style={{ ...styles.secondaryButton, ...styles.actionButton }}
+                    type="button"
+                    onClick={() => calcular('modificado')}
+                  >
+                    Calcular modificado
+                  </button>
+                  <button
+                    style={{ ...styles.secondaryButton, ...styles.actionButton }}
+                    type="button"
+                    onClick={() => calcular('americano')}
+                  >
+                    Calcular americano
+                  </button>
+                  <button
+                    style={{ ...styles.secondaryButton, ...styles.actionButton }}
+                    type="button"
+                    onClick={() => calcular('saltos')}
+                  >
+                    Calcular com saltos
+                  </button>
+                </>
+              ) : (
+                <button
+                  style={{ ...styles.button, ...styles.actionButton }}
+                  type="button"
+                  onClick={() => calcular('exotico')}
+                >
+                  Calcular exotica
+                </button>
+              )}
+              <button
+                style={{ ...styles.secondaryButton, ...styles.actionButton }}
+                type="button"
+                onClick={() => navigate('/cadeia', { state: { form } })}
+              >
+                Cadeia de opcoes
+              </button>
+              <button
+                style={{ ...styles.secondaryButton, ...styles.actionButton }}
+                type="button"
+                onClick={() => navigate('/estrategia', { state: { form } })}
+              >
+                Estrategias
+              </button>
+              <button
+                style={{ ...styles.secondaryButton, ...styles.actionButton }}
+                type="button"
+                onClick={() => navigate('/cenarios', { state: { form } })}
+              >
+                Cenarios de estresse
+              </button>
+            </>
+          )}
         </div>
       </form>
     </main>
   );
 }
-
-function Input({
-  label,
-  hint,
-  ...props
-}: {
-  label: string;
-  hint?: string;
-  value: string;
-  inputMode?: 'decimal' | 'text';
-  placeholder?: string;
-  onChange: (event: React.ChangeEvent<HTMLInputElement>) => void;
-}) {
-  return (
-    <label style={styles.inputGroup}>
-      <span style={styles.labelRow}>
-        <span style={styles.label}>{label}</span>
-        {hint ? <InfoTip text={hint} /> : null}
-      </span>
-      <input style={styles.input} {...props} />
-    </label>
-  );
-}
-
-function InfoTip({ text }: { text: string }) {
-  const [isPinned, setIsPinned] = useState(false);
-  const [isHovered, setIsHovered] = useState(false);
-  const isOpen = isPinned || isHovered;
-
-  return (
-    <span
-      style={styles.hintWrapper}
-      onMouseEnter={() => setIsHovered(true)}
-      onMouseLeave={() => setIsHovered(false)}
-    >
-      <button
-        type="button"
-        style={styles.hintIcon}
-        aria-label={`Ajuda: ${text}`}
-        onClick={(event) => {
-          event.preventDefault();
-          event.stopPropagation();
-          setIsPinned((prev) => !prev);
-        }}
-        onBlur={() => setIsPinned(false)}
-      >
-        ?
-      </button>
-      {isOpen ? (
-        <span style={styles.hintBubble} role="tooltip">
-          {text}
-        </span>
-      ) : null}
-    </span>
-  );
-}
-
-function parseInputs(
-  form: FormState,
-  { requireP }: { requireP: boolean },
-):
-  | {
-    ok: true;
-    S: number;
-    K: number;
-    r: number;
-    sigma: number;
-    p?: number;
-    dataAtual: Date;
-    dataVencimento: Date;
-  }
-  | { ok: false; error: string } {
-  const S = Number(form.S);
-  const K = Number(form.K);
-  const r = Number(form.r);
-  const sigma = Number(form.sigma);
-  const p = Number(form.p);
-  const dataAtual = parseDate(form.dataAtual);
-  const dataVencimento = parseDate(form.dataVencimento);
-
-  if ([S, K, r, sigma].some((n) => Number.isNaN(n))) {
-    return { ok: false, error: 'Preencha valores numericos validos.' };
-  }
-
-  if (requireP && (Number.isNaN(p) || p <= 0)) {
-    return { ok: false, error: 'Parametro p deve ser maior que zero.' };
-  }
-
-  if (!dataAtual || !dataVencimento) {
-    return { ok: false, error: 'Datas devem estar no formato DD/MM/AAAA.' };
-  }
-
-  return {
-    ok: true,
-    S,
-    K,
-    r,
-    sigma,
-    ...(requireP ? { p } : {}),
-    dataAtual,
-    dataVencimento,
-  };
-}
\ No newline at end of file
@@ -0,0 +1,666 @@
+import type React from 'react';
+import { useState } from 'react';
+import { useLocation, useNavigate } from 'react-router-dom';
+import { styles } from '../styles';
+import { Input, Select } from '../components/Fields';
+import LineChart from '../components/LineChart';
+import {
+  blackScholesCall,
+  blackScholesCallModified,
+  blackScholesPut,
+  blackScholesPutModified,
+} from '../utils/blackScholes';
+import {
+  type ParametrosHeston,
+  type ResultadoCalibracaoHeston,
+  calibrarHeston,
+  precoHeston,
+  satisfazFeller,
+  validarParametrosHeston,
+} from '../utils/heston';
+import { type CalendarioFeriados, listarCalendarios } from '../utils/holidayCalendar';
+import { type TipoOpcao, volatilidadeImplicita } from '../utils/impliedVolatility';
+import { type FormState, resolverCalendario } from '../utils/scenario';
+import { parseCotacoes } from '../utils/volSurface';
+import { formatDateInput, parseDate } from '../utils/dateHelpers';
+
+type HestonForm = {
+  S: string;
+  K: string;
+  r: string;
+  q: string;
+  taxaAluguel: string;
+  sigma: string;
+  p: string;
+  dataAtual: string;
+  dataVencimento: string;
+  calendario: string;
+  feriadosExtras: string;
+  v0: string;
+  kappa: string;
+  theta: string;
+  xi: string;
+  rho: string;
+  cotacoes: string;
+};
+
+type Mercado = {
+  S: number;
+  K: number;
+  r: number;
+  q: number;
+  taxaAluguel: number;
+  sigma: number;
+  p: number;
+  dataAtual: Date;
+  dataVencimento: Date;
+  calendario: CalendarioFeriados;
+};
+
+type Comparacao = {
+  mercado: Mercado;
+  parametros: ParametrosHeston;
+};
+
+type Erro = { ok: false; error: string };
+
+const CAMPOS_HESTON: { chave: keyof ParametrosHeston; rotulo: string; hint: string }[] = [
+  { chave: 'v0', rotulo: 'v0', hint: 'Variancia inicial (0.04 = vol de 20%).' },
+  {
+    chave: 'kappa',
+    rotulo: 'kappa',
+    hint: 'Velocidade de reversao da variancia a media.',
+  },
+  { chave: 'theta', rotulo: 'theta', hint: 'Variancia de longo prazo.' },
+  { chave: 'xi', rotulo: 'xi (vol da vol)', hint: 'Volatilidade da variancia.' },
+  {
+    chave: 'rho',
+    rotulo: 'rho',
+    hint: 'Correlacao entre ativo e variancia; negativa gera o skew de acoes.',
+  },
+];
+
+function estadoInicial(form?: FormState): HestonForm {
+  const sigma = form?.sigma ?? '0.2';
+  const variancia = Number(sigma) > 0 ? (Number(sigma) ** 2).toFixed(6) : '0.04';
+  return {
+    S: form?.S ?? '100',
+    K: form?.K ?? '100',
+    r: form?.r ?? '0.05',
+    q: form?.q ?? '0',
+    taxaAluguel: form?.taxaAluguel ?? '0',
+    sigma,
+    p: form?.p ?? '1',
+    dataAtual: form?.dataAtual ?? formatDateInput(new Date()),
+    dataVencimento: form?.dataVencimento ?? '',
+    calendario: form?.calendario ?? 'b3',
+    feriadosExtras: form?.feriadosExtras ?? '',
+    v0: variancia,
+    kappa: '2',
+    theta: variancia,
+    xi: '0.5',
+    rho: '-0.7',
+    cotacoes: '',
+  };
+}
+
+function lerEntrada(
+  form: HestonForm,
+): { ok: true; mercado: Mercado; parametros: ParametrosHeston } | Erro {
+  const [S, K, r, q, taxaAluguel, sigma, p] = [
+    form.S,
+    form.K,
+    form.r,
+    form.q || '0',
+    form.taxaAluguel || '0',
+    form.sigma,
+    form.p,
+  ].map(Number);
+  if ([S, K, r, q, taxaAluguel, sigma, p].some((n) => Number.isNaN(n))) {
+    return { ok: false, error: 'Preencha valores numericos validos.' };
+  }
+  if (S <= 0 || K <= 0 || sigma <= 0 || p <= 0) {
+    return { ok: false, error: 'S, K, sigma e p devem ser maiores que zero.' };
+  }
+  const dataAtual = parseDate(form.dataAtual);
+  const dataVencimento = parseDate(form.dataVencimento);
+  if (!dataAtual || !dataVencimento) {
+    return { ok: false, error: 'Datas devem estar no formato DD/MM/AAAA.' };
+  }
+  if (dataVencimento <= dataAtual) {
+    return { ok: false, error: 'Data de vencimento deve ser posterior a data atual.' };
+  }
+  const parametros: ParametrosHeston = {
+    v0: Number(form.v0),
+    kappa: Number(form.kappa),
+    theta: Number(form.theta),
+    xi: Number(form.xi),
+    rho: Number(form.rho),
+  };
+  const invalido = validarParametrosHeston(parametros);
+  if (invalido) {
+    return { ok: false, error: invalido };
+  }
+  const calendario = resolverCalendario(form);
+  if (!calendario.ok) {
+    return calendario;
+  }
+  return {
+    ok: true,
+    mercado: {
+      S,
+      K,
+      r,
+      q,
+      taxaAluguel,
+      sigma,
+      p,
+      dataAtual,
+      dataVencimento,
+      calendario: calendario.calendario,
+    },
+    parametros,
+  };
+}
+
+// Vol implicita do preco Heston pela opcao fora do dinheiro (mais estavel).
+function volHeston(
+  { S, r, q, taxaAluguel, dataAtual, dataVencimento, calendario }: Mercado,
+  parametros: ParametrosHeston,
+  K: number,
+): number | null {
+  const tipo: TipoOpcao = K >= S ? 'call' : 'put';
+  const premio = precoHeston(
+    tipo,
+    S,
+    K,
+    r,
+    parametros,
+    dataAtual,
+    dataVencimento,
+    q,
+    taxaAluguel,
+    calendario,
+  );
+  const vol = volatilidadeImplicita(
+    premio,
+    tipo,
+    S,
+    K,
+    r,
+    dataAtual,
+    dataVencimento,
+    q,
+    taxaAluguel,
+    {},
+    calendario,
+  );
+  return vol.ok ? vol.sigma : null;
+}
+
+function precosModelos(
+  tipo: TipoOpcao,
+  mercado: Mercado,
+  parametros: ParametrosHeston,
+  K: number,
+  dataVencimento: Date,
+): { heston: number; classico: number; modificado: number } {
+  const { S, r, q, taxaAluguel, sigma, p, dataAtual, calendario } = mercado;
+  const classico = tipo === 'call' ? blackScholesCall : blackScholesPut;
+  const modificado = tipo === 'call' ? blackScholesCallModified : blackScholesPutModified;
+  return {
+    heston: precoHeston(
+      tipo,
+      S,
+      K,
+      r,
+      parametros,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      calendario,
+    ),
+    classico: classico(
+      S,
+      K,
+      r,
+      sigma,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      calendario,
+    ),
+    modificado: modificado(
+      S,
+      K,
+      r,
+      sigma,
+      p,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      calendario,
+    ),
+  };
+}
+
+export default function HestonPage() {
+  const location = useLocation();
+  const navigate = useNavigate();
+  const origem = (location.state as { form?: FormState } | null)?.form;
+  const [form, setForm] = useState<HestonForm>(() => estadoInicial(origem));
+  const [comparacao, setComparacao] = useState<Comparacao | null>(null);
+  const [calibracao, setCalibracao] = useState<ResultadoCalibracaoHeston | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleChange = (key: keyof HestonForm, value: string) => {
+    setForm((prev) => ({ ...prev, [key]: value }));
+  };
+
+  const loadCotacoesFile = (event: React.ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    if (!file) return;
+    file
+      .text()
+      .then((text) => handleChange('cotacoes', text))
+      .catch(() => setError('Erro ao ler arquivo de cotacoes.'));
+    event.target.value = '';
+  };
+
+  const prepararEntrada = () => {
+    const entrada = lerEntrada(form);
+    if (!entrada.ok) {
+      setError(entrada.error);
+      return null;
+    }
+    return entrada;
+  };
+
+  const comparar = (event: React.FormEvent) => {
+    event.preventDefault();
+    setError(null);
+    const entrada = prepararEntrada();
+    if (!entrada) return;
+    setComparacao({ mercado: entrada.mercado, parametros: entrada.parametros });
+  };
+
+  const calibrar = () => {
+    setError(null);
+    const entrada = prepararEntrada();
+    if (!entrada) return;
+    const cotacoes = parseCotacoes(form.cotacoes);
+    if (!cotacoes.ok) {
+      setError(cotacoes.error);
+      return;
+    }
+    const { S, r, q, taxaAluguel, dataAtual, calendario } = entrada.mercado;
+    const resultado = calibrarHeston(
+      cotacoes.cotacoes,
+      { S, r, q, taxaAluguel, dataAtual, calendario },
+      entrada.parametros,
+    );
+    if (!resultado.ok) {
+      setError(resultado.error);
+      return;
+    }
+    const { parametros } = resultado;
+    setForm((prev) => ({
+      ...prev,
+      v0: parametros.v0.toFixed(6),
+      kappa: parametros.kappa.toFixed(6),
+      theta: parametros.theta.toFixed(6),
+      xi: parametros.xi.toFixed(6),
+      rho: parametros.rho.toFixed(6),
+    }));
+    setCalibracao(resultado);
+    setComparacao({ mercado: entrada.mercado, parametros });
+  };
+
+  // Volta ao formulario com sigma = vol implicita do Heston no K e vencimento.
+  const usarNoFormulario = () => {
+    if (!comparacao) return;
+    const { mercado, parametros } = comparacao;
+    if (!origem) {
+      navigate('/');
+      return;
+    }
+    const sigma = volHeston(mercado, parametros, mercado.K);
+    navigate('/', {
+      state: {
+        form: sigma !== null ? { ...origem, sigma: sigma.toFixed(6) } : origem,
+      },
+    });
+  };
+
+  return (
+    <main style={{ ...styles.container, maxWidth: '1100px' }}>
+      <div>
+        <h1 style={styles.title}>Heston (volatilidade estocastica)</h1>
+        <p style={styles.subtitle}>
+          Preco pela funcao caracteristica com quadratura de Gauss-Laguerre, calibracao
+          por minimos quadrados aos premios cotados e comparacao com o Black-Scholes
+          classico e o modelo modificado (p).
+        </p>
+      </div>
+
+      <form style={styles.form} onSubmit={comparar}>
+        <div style={styles.searchRow}>
+          <Input
+            label="S - Preco do ativo"
+            value={form.S}
+            onChange={(e) => handleChange('S', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="K - Strike"
+            value={form.K}
+            onChange={(e) => handleChange('K', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="r (anual)"
+            value={form.r}
+            onChange={(e) => handleChange('r', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="q (anual)"
+            value={form.q}
+            onChange={(e) => handleChange('q', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="Taxa de aluguel (anual)"
+            value={form.taxaAluguel}
+            onChange={(e) => handleChange('taxaAluguel', e.target.value)}
+            inputMode="decimal"
+          />
+        </div>
+        <div style={styles.searchRow}>
+          <Input
+            label="sigma (classico e modificado)"
+            value={form.sigma}
+            onChange={(e) => handleChange('sigma', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="p (modificado)"
+            value={form.p}
+            onChange={(e) => handleChange('p', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="Data atual (DD/MM/AAAA)"
+            value={form.dataAtual}
+            onChange={(e) => handleChange('dataAtual', e.target.value)}
+            inputMode="text"
+          />
+          <Input
+            label="Vencimento (DD/MM/AAAA)"
+            value={form.dataVencimento}
+            onChange={(e) => handleChange('dataVencimento', e.target.value)}
+            inputMode="text"
+          />
+        </div>
+        <Select
+          label="Calendario de feriados"
+          value={form.calendario}
+          onChange={(e) => handleChange('calendario', e.target.value)}
+          options={listarCalendarios().map((cal) => ({
+            value: cal.id,
+            label: cal.nome,
+          }))}
+        />
+        <div style={styles.searchRow}>
+          {CAMPOS_HESTON.map(({ chave, rotulo, hint }) => (
+            <Input
+              key={chave}
+              label={rotulo}
+              hint={hint}
+              value={form[chave]}
+              onChange={(e) => handleChange(chave, e.target.value)}
+              inputMode="decimal"
+            />
+          ))}
+        </div>
+        <label style={styles.inputGroup}>
+          <span style={styles.label}>
+            Cotacoes para calibrar (DD/MM/AAAA;strike;premio;call|put, uma por linha)
+          </span>
+          <textarea
+            style={{ ...styles.input, minHeight: '140px', resize: 'vertical' }}
+            value={form.cotacoes}
+            onChange={(e) => handleChange('cotacoes', e.target.value)}
+            placeholder={'20/03/2026;95;2.10;put\n20/03/2026;100;3.40;call\n20/03/2026;105;1.55;call'}
+          />
+          <input
+            style={styles.sectionText}
+            type="file"
+            accept=".txt,.csv"
+            onChange={loadCotacoesFile}
+          />
+        </label>
+
+        {error ? <p style={styles.error}>{error}</p> : null}
+
+        <div style={{ display: 'flex', gap: '12px', flexWrap: 'wrap' }}>
+          <button style={{ ...styles.button, ...styles.actionButton }} type="submit">
+            Comparar modelos
+          </button>
+          <button
+            style={{ ...styles.secondaryButton, ...styles.actionButton }}
+            type="button"
+            onClick={calibrar}
+          >
+            Calibrar Heston
+          </button>
+          {comparacao ? (
+            <button
+              style={{ ...styles.secondaryButton, ...styles.actionButton }}
+              type="button"
+              onClick={usarNoFormulario}
+            >
+              Usar no formulario
+            </button>
+          ) : null}
+          <button
+            style={{ ...styles.secondaryButton, ...styles.actionButton }}
+            type="button"
+            onClick={() =>
+              navigate('/', { state: origem ? { form: origem } : null })
+            }
+          >
+            Voltar
+          </button>
+        </div>
+      </form>
+
+      {calibracao && comparacao ? (
+        <CalibracaoSection calibracao={calibracao} mercado={comparacao.mercado} />
+      ) : null}
+      {comparacao ? <ComparacaoSection comparacao={comparacao} /> : null}
+    </main>
+  );
+}
+
+function CalibracaoSection({
+  calibracao,
+  mercado,
+}: {
+  calibracao: ResultadoCalibracaoHeston;
+  mercado: Mercado;
+}) {
+  const { parametros } = calibracao;
+  const feller = satisfazFeller(parametros);
+
+  return (
+    <section style={styles.section}>
+      <p style={styles.sectionTitle}>Calibracao</p>
+      <p style={styles.sectionText}>
+        RMSE dos premios {calibracao.rmse.toFixed(4)} em {calibracao.ajustes.length}{' '}
+        cotacoes ({calibracao.iteracoes} iteracoes).
+      </p>
+      <p style={styles.sectionText}>
+        Feller (2 kappa theta {'>='} xi^2):{' '}
+        {feller ? 'satisfeita' : 'violada, a variancia pode tocar zero'} (
+        {(2 * parametros.kappa * parametros.theta).toFixed(4)} x{' '}
+        {(parametros.xi * parametros.xi).toFixed(4)}).
+      </p>
+      <div style={styles.tableWrapper}>
+        <table style={styles.table}>
+          <thead>
+            <tr>
+              <th style={styles.tableHeader}>Vencimento</th>
+              <th style={styles.tableHeader}>Strike</th>
+              <th style={styles.tableHeader}>Tipo</th>
+              <th style={styles.tableHeader}>Mercado</th>
+              <th style={styles.tableHeader}>Heston</th>
+              <th style={styles.tableHeader}>Classico</th>
+              <th style={styles.tableHeader}>Modificado</th>
+            </tr>
+          </thead>
+          <tbody>
+            {calibracao.ajustes.map(({ cotacao, modelo }, index) => {
+              const precos = precosModelos(
+                cotacao.tipo,
+                mercado,
+                parametros,
+                cotacao.K,
+                cotacao.dataVencimento,
+              );
+              return (
+                <tr key={index}>
+                  <td style={styles.tableCell}>
+                    {formatDateInput(cotacao.dataVencimento)}
+                  </td>
+                  <td style={styles.tableCell}>{cotacao.K}</td>
+                  <td style={styles.tableCell}>{cotacao.tipo}</td>
+                  <td style={styles.tableCell}>{cotacao.premio.toFixed(4)}</td>
+                  <td style={styles.tableCell}>{modelo.toFixed(4)}</td>
+                  <td style={styles.tableCell}>{precos.classico.toFixed(4)}</td>
+                  <td style={styles.tableCell}>{precos.modificado.toFixed(4)}</td>
+                </tr>
+              );
+            })}
+          </tbody>
+        </table>
+      </div>
+    </section>
+  );
+}
+
+function ComparacaoSection({ comparacao }: { comparacao: Comparacao }) {
+  const { mercado, parametros } = comparacao;
+  const { S, K, sigma, p, dataVencimento } = mercado;
+  const call = precosModelos('call', mercado, parametros, K, dataVencimento);
+  const put = precosModelos('put', mercado, parametros, K, dataVencimento);
+  const volNoStrike = volHeston(mercado, parametros, K);
+
+  const grade = Array.from({ length: 41 }, (_, i) => S * (0.7 + (0.6 * i) / 40));
+  const smile = grade
+    .map((strike) => ({ x: strike, vol: volHeston(mercado, parametros, strike) }))
+    .filter((ponto): ponto is { x: number; vol: number } => ponto.vol !== null);
+  const linhasStrike = grade.filter((_, i) => i % 5 === 0);
+
+  return (
+    <>
+      <section style={styles.section}>
+        <p style={styles.sectionTitle}>
+          K {K} | vencimento {formatDateInput(dataVencimento)}
+        </p>
+        <div style={styles.tableWrapper}>
+          <table style={styles.table}>
+            <thead>
+              <tr>
+                <th style={styles.tableHeader}>Modelo</th>
+                <th style={styles.tableHeader}>Call</th>
+                <th style={styles.tableHeader}>Put</th>
+              </tr>
+            </thead>
+            <tbody>
+              <tr>
+                <td style={styles.tableCell}>Heston</td>
+                <td style={styles.tableCell}>{call.heston.toFixed(4)}</td>
+                <td style={styles.tableCell}>{put.heston.toFixed(4)}</td>
+              </tr>
+              <tr>
+                <td style={styles.tableCell}>Classico (sigma {sigma})</td>
+                <td style={styles.tableCell}>{call.classico.toFixed(4)}</td>
+                <td style={styles.tableCell}>{put.classico.toFixed(4)}</td>
+              </tr>
+              <tr>
+                <td style={styles.tableCell}>Modificado (p {p})</td>
+                <td style={styles.tableCell}>{call.modificado.toFixed(4)}</td>
+                <td style={styles.tableCell}>{put.modificado.toFixed(4)}</td>
+              </tr>
+            </tbody>
+          </table>
+        </div>
+        <p style={styles.sectionText}>
+          Vol implicita do Heston no strike:{' '}
+          {volNoStrike !== null ? `${(volNoStrike * 100).toFixed(2)}%` : '-'}
+        </p>
+      </section>
+
+      <LineChart
+        titulo="Vol implicita do Heston x sigma do classico (vol % x strike)"
+        rotuloX="K"
+        series={[
+          {
+            nome: 'Heston',
+            cor: '#38bdf8',
+            pontos: smile.map(({ x, vol }) => ({ x, y: vol * 100 })),
+          },
+          {
+            nome: 'Classico',
+            cor: '#f59e0b',
+            tracejado: true,
+            pontos: grade.map((x) => ({ x, y: sigma * 100 })),
+          },
+        ]}
+        marcadores={[
+          { x: S, rotulo: `S ${S}`, cor: '#facc15' },
+          { x: K, rotulo: `K ${K}`, cor: '#4ade80' },
+        ]}
+      />
+
+      <section style={styles.section}>
+        <p style={styles.sectionTitle}>Calls por strike</p>
+        <div style={styles.tableWrapper}>
+          <table style={styles.table}>
+            <thead>
+              <tr>
+                <th style={styles.tableHeader}>Strike</th>
+                <th style={styles.tableHeader}>Heston</th>
+                <th style={styles.tableHeader}>Classico</th>
+                <th style={styles.tableHeader}>Modificado</th>
+              </tr>
+            </thead>
+            <tbody>
+              {linhasStrike.map((strike) => {
+                const precos = precosModelos(
+                  'call',
+                  mercado,
+                  parametros,
+                  strike,
+                  dataVencimento,
+                );
+                return (
+                  <tr key={strike}>
+                    <td style={styles.tableCell}>{strike.toFixed(2)}</td>
+                    <td style={styles.tableCell}>{precos.heston.toFixed(4)}</td>
+                    <td style={styles.tableCell}>{precos.classico.toFixed(4)}</td>
+                    <td style={styles.tableCell}>{precos.modificado.toFixed(4)}</td>
+                  </tr>
+                );
+              })}
+            </tbody>
+          </table>
+        </div>
+      </section>
+    </>
+  );
+}
@@ -0,0 +1,242 @@
+import type React from 'react';
+import { useMemo, useState } from 'react';
+import { useLocation, useNavigate } from 'react-router-dom';
+import { styles } from '../styles';
+import { Input, Select } from '../components/Fields';
+import { type FormState } from '../utils/scenario';
+import {
+  type BarraOhlc,
+  type EstimadorVolatilidade,
+  ESTIMADORES_VOLATILIDADE,
+  LAMBDA_RISKMETRICS,
+  estimarVolatilidades,
+  parseSerieOhlc,
+} from '../utils/historicalVolatility';
+import { getProvider, listProviders } from '../services/marketDataProvider';
+import { formatDateInput } from '../utils/dateHelpers';
+
+const JANELAS = [
+  { value: '21', label: '21 dias uteis (1 mes)' },
+  { value: '63', label: '63 dias uteis (3 meses)' },
+  { value: '126', label: '126 dias uteis (6 meses)' },
+  { value: '252', label: '252 dias uteis (1 ano)' },
+  { value: 'tudo', label: 'Serie completa' },
+];
+
+export default function HistoricalVolPage() {
+  const location = useLocation();
+  const navigate = useNavigate();
+  const origem = (location.state as { form?: FormState } | null)?.form;
+  const [texto, setTexto] = useState('');
+  const [janela, setJanela] = useState('63');
+  const [lambda, setLambda] = useState(String(LAMBDA_RISKMETRICS));
+  const [barras, setBarras] = useState<BarraOhlc[] | null>(null);
+  const [aviso, setAviso] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [provedor, setProvedor] = useState('fixture');
+  const [ticker, setTicker] = useState('');
+  const [buscando, setBuscando] = useState(false);
+
+  const lambdaNumero = Number(lambda);
+  const lambdaValido = lambdaNumero > 0 && lambdaNumero < 1;
+  const estimativas = useMemo(
+    () =>
+      barras && lambdaValido
+        ? estimarVolatilidades(
+          barras,
+          janela === 'tudo' ? undefined : Number(janela),
+          lambdaNumero,
+        )
+        : null,
+    [barras, janela, lambdaNumero, lambdaValido],
+  );
+
+  const carregar = (conteudo: string) => {
+    setError(null);
+    setAviso(null);
+    setBarras(null);
+    const serie = parseSerieOhlc(conteudo);
+    if (!serie.ok) {
+      setError(serie.error);
+      return;
+    }
+    setBarras(serie.barras);
+    if (serie.ignoradas > 0) {
+      setAviso(`${serie.ignoradas} linha(s) sem data ou fechamento foram ignoradas.`);
+    }
+  };
+
+  const loadSerieFile = (event: React.ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    if (!file) return;
+    file
+      .text()
+      .then((conteudo) => {
+        setTexto(conteudo);
+        carregar(conteudo);
+      })
+      .catch(() => setError('Erro ao ler arquivo da serie.'));
+    event.target.value = '';
+  };
+
+  const importarDoProvedor = async () => {
+    const fonte = getProvider(provedor);
+    if (!fonte || !ticker.trim()) {
+      setError('Informe o ticker do ativo.');
+      return;
+    }
+    setError(null);
+    setAviso(null);
+    setBarras(null);
+    setBuscando(true);
+    try {
+      const historico = await fonte.history(ticker.trim().toUpperCase());
+      if (historico.length < 2) {
+        setError('Historico com menos de duas barras.');
+        return;
+      }
+      setTexto('');
+      setBarras(historico);
+    } catch (err) {
+      setError((err as Error).message || 'Erro ao carregar historico.');
+    } finally {
+      setBuscando(false);
+    }
+  };
+
+  // Volta ao formulario principal com o sigma escolhido.
+  const usarEstimativa = (sigma: number) => {
+    if (!origem) {
+      navigate('/');
+      return;
+    }
+    navigate('/', { state: { form: { ...origem, sigma: sigma.toFixed(6) } } });
+  };
+
+  return (
+    <main style={styles.container}>
+      <div>
+        <h1 style={styles.title}>Volatilidade historica</h1>
+        <p style={styles.subtitle}>
+          Estimadores anualizados em base 252 a partir de uma serie diaria OHLC.
+        </p>
+      </div>
+
+      <div style={styles.searchRow}>
+        <Select
+          label="Provedor de dados"
+          value={provedor}
+          onChange={(e) => setProvedor(e.target.value)}
+          options={listProviders().map((item) => ({
+            value: item.id,
+            label: item.label,
+          }))}
+        />
+        <Input
+          label="Ticker"
+          value={ticker}
+          onChange={(e) => setTicker(e.target.value)}
+          placeholder="PETR4.SA"
+        />
+        <button
+          style={{ ...styles.secondaryButton, marginTop: 0 }}
+          type="button"
+          onClick={() => void importarDoProvedor()}
+          disabled={buscando}
+        >
+          {buscando ? 'Buscando...' : 'Importar historico'}
+        </button>
+      </div>
+
+      <label style={styles.inputGroup}>
+        <span style={styles.label}>
+          Serie diaria (CSV com Date,Open,High,Low,Close ou JSON)
+        </span>
+        <textarea
+          style={{ ...styles.input, minHeight: '140px', resize: 'vertical' }}
+          value={texto}
+          onChange={(e) => setTexto(e.target.value)}
+          placeholder={'Date,Open,High,Low,Close\n2025-01-02,36.10,36.80,35.90,36.55'}
+        />
+        <input
+          style={styles.sectionText}
+          type="file"
+          accept=".csv,.txt,.json"
+          onChange={loadSerieFile}
+        />
+      </label>
+      <div style={styles.searchRow}>
+        <Select
+          label="Janela"
+          value={janela}
+          onChange={(e) => setJanela(e.target.value)}
+          options={JANELAS}
+        />
+        <Input
+          label="Lambda EWMA"
+          hint="Fator de decaimento do RiskMetrics (0.94 para dados diarios)."
+          value={lambda}
+          onChange={(e) => setLambda(e.target.value)}
+          inputMode="decimal"
+        />
+      </div>
+
+      {error ? <p style={styles.error}>{error}</p> : null}
+      {!lambdaValido ? (
+        <p style={styles.error}>Lambda deve estar entre 0 e 1.</p>
+      ) : null}
+      {aviso ? <p style={styles.sectionText}>{aviso}</p> : null}
+
+      <div style={{ display: 'flex', gap: '12px', flexWrap: 'wrap' }}>
+        <button
+          style={{ ...styles.button, ...styles.actionButton }}
+          type="button"
+          onClick={() => carregar(texto)}
+        >
+          Calcular volatilidades
+        </button>
+        <button
+          style={{ ...styles.secondaryButton, ...styles.actionButton }}
+          type="button"
+          onClick={() =>
+            navigate('/', { state: origem ? { form: origem } : null })
+          }
+        >
+          Voltar
+        </button>
+      </div>
+
+      {barras && estimativas ? (
+        <section style={styles.section}>
+          <p style={styles.sectionTitle}>
+            {barras.length} barras de {formatDateInput(barras[0].data)} a{' '}
+            {formatDateInput(barras[barras.length - 1].data)}
+          </p>
+          {(Object.keys(ESTIMADORES_VOLATILIDADE) as EstimadorVolatilidade[]).map(
+            (estimador) => {
+              const sigma = estimativas[estimador];
+              return (
+                <div key={estimador} style={{ ...styles.greekRow, alignItems: 'center' }}>
+                  <span style={styles.sectionText}>
+                    {ESTIMADORES_VOLATILIDADE[estimador]}
+                  </span>
+                  <span style={styles.greekValue}>
+                    {sigma === null ? 'requer OHLC' : `${(sigma * 100).toFixed(2)}%`}
+                  </span>
+                  <button
+                    style={{ ...styles.secondaryButton, marginTop: 0, padding: '6px 10px' }}
+                    type="button"
+                    disabled={sigma === null}
+                    onClick={() => sigma !== null && usarEstimativa(sigma)}
+                  >
+                    Usar como sigma
+                  </button>
+                </div>
+              );
+            },
+          )}
+        </section>
+      ) : null}
+    </main>
+  );
+}
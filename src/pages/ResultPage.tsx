@@ -1,45 +1,100 @@
 import { useLocation, useNavigate } from 'react-router-dom';
-import { useMemo } from 'react';
+import { useMemo, useState } from 'react';
 import { styles } from '../styles';
+import {
+  type Gregas,
+  type ResultState,
+  type Variant,
+  calcularCenario,
+  cenarioDeQuery,
+  parseOpcoesExotica,
+  resolverCalendario,
+} from '../utils/scenario';
+import { formatIsoDate, parseDate } from '../utils/dateHelpers';
+import {
+  curvaPayoff,
+  curvasValorHoje,
+  encontrarBreakevens,
+  gerarGradeSpot,
+} from '../utils/payoff';
+import LineChart, { type MarcadorGrafico } from '../components/LineChart';
+import { Input, Select } from '../components/Fields';
+import {
+  type ResultadoMonteCarlo,
+  type SequenciaMonteCarlo,
+  type VariavelControle,
+  MAX_PASSOS_MONTE_CARLO,
+  VARIAVEIS_CONTROLE,
+  precificarEuropeiaMonteCarlo,
+  simularMonteCarlo,
+} from '../utils/monteCarlo';
+import {
+  PRODUTOS_EXOTICOS,
+  TIPOS_BARREIRA,
+  observacoesExotica,
+  payoffExoticoCaminho,
+} from '../utils/exoticOptions';
+import { type ResultadoEdp, precificarEdp } from '../utils/finiteDifference';
+import { MODELOS_SALTOS } from '../utils/jumpDiffusion';
 
-type ResultState = {
-  result: {
-    call: number;
-    put: number;
-    T: number;
-    diasUteis: number;
-    deltaCall: number;
-    deltaPut: number;
-    vega: number;
-    gama: number;
-
-    inputs: {
-      S: string;
-      K: string;
-      r: string;
-      sigma: string;
-      dataAtual: string;
-      dataVencimento: string;
-    };
-  };
-  variant?: 'classico' | 'modificado';
-  p?: number;
+type Props = {
+  variant: Variant;
 };
 
-type Props = {
-  variant: 'classico' | 'modificado';
+const TITLES: Record<Variant, string> = {
+  classico: 'Black-Scholes Classico',
+  modificado: 'Black-Scholes Modificado',
+  americano: 'Opcao Americana',
+  black76: 'Black-76 (opcao sobre futuro)',
+  fx: 'Garman-Kohlhagen (cambio)',
+  exotico: 'Opcao exotica',
+  saltos: 'Difusao com saltos',
+};
+
+const METODOS_AMERICANOS = {
+  crr: 'Binomial Cox-Ross-Rubinstein',
+  leisenReimer: 'Binomial Leisen-Reimer',
+  bjerksundStensland: 'Bjerksund-Stensland 2002',
 };
 
 export default function ResultPage({ variant }: Props) {
   const location = useLocation();
   const navigate = useNavigate();
-  const state = location.state as ResultState | null;
+  const [linkCopiado, setLinkCopiado] = useState<boolean | null>(null);
+
+  // Sem state do router (refresh ou link compartilhado): recalcula pela query string.
+  const recalculado = useMemo(() => {
+    const routerState = location.state as ResultState | null;
+    if (routerState?.result) {
+      return { state: routerState, error: null };
+    }
+    const cenario = cenarioDeQuery(location.search);
+    if (!cenario) {
+      return { state: null, error: null };
+    }
+    const calculado = calcularCenario(variant, cenario.form, cenario.modo);
+    return calculado.ok
+      ? { state: calculado.state, error: null }
+      : { state: null, error: calculado.error };
+  }, [location.search, location.state, variant]);
+  const { state } = recalculado;
+
+  const copiarLink = () => {
+    navigator.clipboard
+      .writeText(window.location.href)
+      .then(() => setLinkCopiado(true))
+      .catch(() => setLinkCopiado(false));
+  };
 
   const content = useMemo(() => {
     if (!state?.result) {
       return (
         <>
-          <p style={styles.error}>Nenhum resultado. Volte e realize um calculo.</p>
+          <p style={styles.error}>
+            {recalculado.error
+              ? `Link invalido: ${recalculado.error}`
+              : 'Nenhum resultado. Volte e realize um calculo.'}
+          </p>
           <button style={styles.secondaryButton} onClick={() => navigate('/')}>
             Voltar
           </button>
@@ -51,18 +106,144 @@ export default function ResultPage({ variant }: Props) {
     return (
       <>
         <h1 style={styles.title}>
-          {variant === 'modificado'
-            ? 'Black-Scholes Modificado'
-            : 'Black-Scholes Classico'}
+          {result.exotica
+            ? PRODUTOS_EXOTICOS[result.exotica.produto]
+            : result.saltos
+              ? MODELOS_SALTOS[result.saltos.modelo]
+              : TITLES[variant]}
         </h1>
+        {result.volImplicita ? (
+          <>
+            <InfoRow
+              label={`Volatilidade implicita (${result.volImplicita.tipo})`}
+              value={result.volImplicita.sigma}
+              formatter={(v) => `${(v * 100).toFixed(4)}%`}
+            />
+            <InfoRow
+              label="Premio observado"
+              value={result.volImplicita.premio}
+            />
+            {result.volImplicita.naoUnica ? (
+              <p style={styles.sectionText}>
+                Solucao nao unica: um sigma maior tambem reproduz o premio; vale o
+                do trecho em que o preco cresce com sigma.
+              </p>
+            ) : null}
+          </>
+        ) : null}
         <InfoRow label="Preco teorico - Call (C)" value={result.call} />
         <InfoRow label="Preco teorico - Put (P)" value={result.put} />
+        {result.americana ? (
+          <section style={styles.section}>
+            <p style={styles.sectionTitle}>
+              Exercicio antecipado - {METODOS_AMERICANOS[result.americana.metodo]}
+              {result.americana.passos ? ` (${result.americana.passos} passos)` : ''}
+            </p>
+            <p style={styles.sectionText}>
+              Call europeia: {result.americana.callEuropeia.toFixed(4)} | premio
+              de exercicio: {result.americana.premioCall.toFixed(4)}
+            </p>
+            <p style={styles.sectionText}>
+              Put europeia: {result.americana.putEuropeia.toFixed(4)} | premio de
+              exercicio: {result.americana.premioPut.toFixed(4)}
+            </p>
+          </section>
+        ) : null}
+        {result.fx ? (
+          <section style={styles.section}>
+            <p style={styles.sectionTitle}>
+              Premio em cotacao de cambio (nocional {result.fx.nocional})
+            </p>
+            <p style={styles.sectionText}>
+              Call: {result.fx.call.pips.toFixed(1)} pips BRL |{' '}
+              {result.fx.call.percentualNocional.toFixed(4)}% do nocional | R${' '}
+              {result.fx.call.total.toFixed(2)}
+            </p>
+            <p style={styles.sectionText}>
+              Put: {result.fx.put.pips.toFixed(1)} pips BRL |{' '}
+              {result.fx.put.percentualNocional.toFixed(4)}% do nocional | R${' '}
+              {result.fx.put.total.toFixed(2)}
+            </p>
+          </section>
+        ) : null}
+        {result.exotica ? (
+          <section style={styles.section}>
+            <p style={styles.sectionTitle}>
+              {result.exotica.tipoBarreira
+                ? `${TIPOS_BARREIRA[result.exotica.tipoBarreira]} com barreira ${
+                  result.inputs.barreira
+                }`
+                : PRODUTOS_EXOTICOS[result.exotica.produto]}
+            </p>
+            {result.exotica.barreiraAjustada !== undefined &&
+            Number(result.inputs.monitoramento) > 0 ? (
+              <p style={styles.sectionText}>
+                Monitoramento a cada {result.inputs.monitoramento} dia(s) util(eis):
+                barreira continua equivalente{' '}
+                {result.exotica.barreiraAjustada.toFixed(4)}
+              </p>
+            ) : null}
+            <p style={styles.sectionText}>
+              Call vanilla: {result.exotica.callVanilla.toFixed(4)} | Put vanilla:{' '}
+              {result.exotica.putVanilla.toFixed(4)}
+            </p>
+          </section>
+        ) : null}
+        {result.saltos ? (
+          <section style={styles.section}>
+            <p style={styles.sectionTitle}>
+              Saltos: {result.saltos.parametros.lambda} por ano, media{' '}
+              {result.saltos.parametros.media} e vol {result.saltos.parametros.vol}
+            </p>
+            {result.saltos.eta1 !== undefined && result.saltos.eta2 !== undefined ? (
+              <p style={styles.sectionText}>
+                Prob. de alta: {result.saltos.parametros.probAlta} | eta1 (alta):{' '}
+                {result.saltos.eta1.toFixed(4)} | eta2 (baixa):{' '}
+                {result.saltos.eta2.toFixed(4)}
+              </p>
+            ) : null}
+            <p style={styles.sectionText}>
+              Call sem saltos: {result.saltos.callSemSaltos.toFixed(4)} | Put sem
+              saltos: {result.saltos.putSemSaltos.toFixed(4)}
+            </p>
+            {result.saltos.volEquivalente ? (
+              <p style={styles.sectionText}>
+                Vol Black-Scholes equivalente ({result.saltos.volEquivalente.tipo} fora
+                do dinheiro): {(result.saltos.volEquivalente.sigma * 100).toFixed(4)}%
+              </p>
+            ) : null}
+          </section>
+        ) : null}
         <InfoRow
           label="Tempo ate o vencimento (anos)"
           value={result.T}
           formatter={(v) => v.toFixed(6)}
         />
         <InfoRow label="Dias uteis considerados" value={result.diasUteis} />
+        {result.dividendos ? (
+          <>
+            <InfoRow
+              label="VP dos dividendos ate o vencimento"
+              value={result.dividendos.valorPresente}
+            />
+            <InfoRow
+              label="Spot ajustado (escrowed)"
+              value={result.dividendos.spotAjustado}
+            />
+          </>
+        ) : null}
+        {result.feriados && result.feriados.length > 0 ? (
+          <section style={styles.section}>
+            <p style={styles.sectionTitle}>
+              Feriados desconsiderados ({result.feriados.length})
+            </p>
+            {result.feriados.map((feriado) => (
+              <p key={feriado.data} style={styles.sectionText}>
+                {formatIsoDate(feriado.data)} - {feriado.nome}
+              </p>
+            ))}
+          </section>
+        ) : null}
         {variant === 'modificado' && state?.p !== undefined ? (
           <InfoRow
             label="Parametro p"
@@ -70,16 +251,35 @@ export default function ResultPage({ variant }: Props) {
             formatter={(v) => v.toFixed(4)}
           />
         ) : null}
+        <GregasSection gregas={result} />
+        <GraficosPayoff variant={variant} result={result} />
+        {variant === 'classico' || variant === 'exotico' ? (
+          <MonteCarloSection result={result} />
+        ) : null}
+        {variant === 'classico' ||
+        variant === 'americano' ||
+        result.exotica?.produto === 'barreira' ? (
+          <DiferencasFinitasSection variant={variant} result={result} />
+        ) : null}
         <section style={styles.section}>
           <p style={styles.sectionTitle}>Parametros usados</p>
-          <p style={styles.sectionText}>S: {result.inputs.S}</p>
+          <p style={styles.sectionText}>
+            {variant === 'black76' ? 'F' : 'S'}: {result.inputs.S}
+          </p>
           <p style={styles.sectionText}>K: {result.inputs.K}</p>
           <p style={styles.sectionText}>r: {result.inputs.r}</p>
+          {variant === 'fx' && result.inputs.rf !== undefined ? (
+            <p style={styles.sectionText}>rf: {result.inputs.rf}</p>
+          ) : null}
           <p style={styles.sectionText}>sigma: {result.inputs.sigma}</p>
-          <p style={styles.sectionText}>delta Call: {result.deltaCall}</p>
-          <p style={styles.sectionText}>delta Put: {result.deltaPut}</p>
-          <p style={styles.sectionText}>vega: {result.vega}</p>
-          <p style={styles.sectionText}>gama: {result.gama}</p>
+          {result.inputs.q !== undefined ? (
+            <p style={styles.sectionText}>q: {result.inputs.q}</p>
+          ) : null}
+          {result.inputs.taxaAluguel && Number(result.inputs.taxaAluguel) !== 0 ? (
+            <p style={styles.sectionText}>
+              Taxa de aluguel: {result.inputs.taxaAluguel}
+            </p>
+          ) : null}
           <p style={styles.sectionText}>
             Data atual: {result.inputs.dataAtual}
           </p>
@@ -87,12 +287,21 @@ export default function ResultPage({ variant }: Props) {
             Vencimento: {result.inputs.dataVencimento}
           </p>
         </section>
+        {location.search ? (
+          <button style={styles.secondaryButton} onClick={copiarLink}>
+            {linkCopiado === null
+              ? 'Copiar link'
+              : linkCopiado
+                ? 'Link copiado'
+                : 'Nao foi possivel copiar o link'}
+          </button>
+        ) : null}
         <button style={styles.secondaryButton} onClick={() => navigate('/')}>
           Voltar
         </button>
       </>
     );
-  }, [navigate, state, variant]);
+  }, [linkCopiado, location.search, navigate, recalculado.error, state, variant]);
 
   return <main style={styles.container}>{content}</main>;
 }
@@ -114,3 +323,547 @@ function InfoRow({
     </div>
   );
 }
+
+const GREGAS_LABELS: { key: keyof Gregas; label: string }[] = [
+  { key: 'deltaCall', label: 'Delta Call' },
+  { key: 'deltaPut', label: 'Delta Put' },
+  { key: 'gama', label: 'Gama' },
+  { key: 'vega', label: 'Vega (por 1% de vol)' },
+  { key: 'vegaCall', label: 'Vega Call (por 1% de vol)' },
+  { key: 'vegaPut', label: 'Vega Put (por 1% de vol)' },
+  { key: 'thetaCall', label: 'Theta Call (por dia util)' },
+  { key: 'thetaPut', label: 'Theta Put (por dia util)' },
+  { key: 'thetaCallCorrido', label: 'Theta Call (por dia corrido)' },
+  { key: 'thetaPutCorrido', label: 'Theta Put (por dia corrido)' },
+  { key: 'rhoCall', label: 'Rho Call (por 1% de taxa)' },
+  { key: 'rhoPut', label: 'Rho Put (por 1% de taxa)' },
+  { key: 'rhoEstrangeiroCall', label: 'Rho estrangeiro Call (por 1%)' },
+  { key: 'rhoEstrangeiroPut', label: 'Rho estrangeiro Put (por 1%)' },
+  { key: 'vanna', label: 'Vanna (por 1% de vol)' },
+  { key: 'volga', label: 'Volga / Vomma (por 1% de vol)' },
+  { key: 'charmCall', label: 'Charm Call (por dia util)' },
+  { key: 'charmPut', label: 'Charm Put (por dia util)' },
+  { key: 'speed', label: 'Speed' },
+  { key: 'sensibilidadePCall', label: 'Sensibilidade a p - Call' },
+  { key: 'sensibilidadePut', label: 'Sensibilidade a p - Put' },
+];
+
+function GregasSection({ gregas }: { gregas: Gregas }) {
+  const rows = GREGAS_LABELS.filter(({ key }) => gregas[key] !== undefined);
+  if (rows.length === 0) {
+    return null;
+  }
+
+  return (
+    <section style={styles.section}>
+      <p style={styles.sectionTitle}>Gregas</p>
+      {rows.map(({ key, label }) => (
+        <div key={key} style={styles.greekRow}>
+          <span style={styles.sectionText}>{label}</span>
+          <span style={styles.greekValue}>{gregas[key]?.toFixed(6)}</span>
+        </div>
+      ))}
+    </section>
+  );
+}
+
+const CORES = {
+  payoff: '#94a3b8',
+  valorHoje: '#38bdf8',
+  comparacao: '#f59e0b',
+  spot: '#facc15',
+  strike: '#e2e8f0',
+  breakeven: '#4ade80',
+};
+
+function GraficosPayoff({
+  variant,
+  result,
+}: {
+  variant: Variant;
+  result: ResultState['result'];
+}) {
+  const [sobrepor, setSobrepor] = useState(false);
+  // Saltos precificam cada spot por integracao numerica: a curva de valor
+  // hoje so e calculada quando pedida, para nao travar a renderizacao.
+  const sobDemanda = variant === 'saltos';
+  const [mostrarHoje, setMostrarHoje] = useState(!sobDemanda);
+  const { inputs } = result;
+  const S = Number(inputs.S);
+  const K = Number(inputs.K);
+  const podeComparar =
+    (variant === 'classico' || variant === 'modificado') && Number(inputs.p) > 0;
+  const outraVariante = variant === 'classico' ? 'modificado' : 'classico';
+
+  const curvas = useMemo(() => {
+    if (!(S > 0) || !(K > 0)) {
+      return null;
+    }
+    const form = {
+      ...inputs,
+      q: inputs.q ?? '0',
+      taxaAluguel: inputs.taxaAluguel ?? '0',
+    };
+    const spots = gerarGradeSpot(S, K);
+    return {
+      spots,
+      hoje: mostrarHoje ? curvasValorHoje(variant, form, spots) : null,
+      comparacao:
+        sobrepor && podeComparar
+          ? curvasValorHoje(outraVariante, form, spots)
+          : null,
+    };
+  }, [K, S, inputs, mostrarHoje, outraVariante, podeComparar, sobrepor, variant]);
+
+  if (!curvas) {
+    return null;
+  }
+
+  const rotuloSpot = variant === 'black76' ? 'F' : 'S';
+  // Exoticos dependem da trajetoria: so o valor hoje tem curva por spot.
+  const mostraPayoff = variant !== 'exotico';
+  const graficos = [
+    { tipo: 'call' as const, titulo: 'Call', premio: result.call },
+    { tipo: 'put' as const, titulo: 'Put', premio: result.put },
+  ];
+
+  return (
+    <>
+      {podeComparar ? (
+        <label style={{ ...styles.sectionText, display: 'flex', gap: '8px' }}>
+          <input
+            type="checkbox"
+            checked={sobrepor}
+            onChange={(e) => setSobrepor(e.target.checked)}
+          />
+          Sobrepor curva do modelo {TITLES[outraVariante]}
+        </label>
+      ) : null}
+      {!mostrarHoje ? (
+        <button
+          style={styles.secondaryButton}
+          type="button"
+          onClick={() => setMostrarHoje(true)}
+        >
+          Calcular curva de valor hoje
+        </button>
+      ) : null}
+      {graficos.map(({ tipo, titulo, premio }) => {
+        const payoff = mostraPayoff ? curvaPayoff(tipo, K, curvas.spots) : [];
+        // Breakeven no vencimento: payoff - premio pago = 0.
+        const breakevens = encontrarBreakevens(
+          payoff.map((p) => ({ x: p.x, y: p.y - premio })),
+        );
+        const marcadores: MarcadorGrafico[] = [
+          { x: S, rotulo: `${rotuloSpot} ${S}`, cor: CORES.spot },
+          { x: K, rotulo: `K ${K}`, cor: CORES.strike },
+          ...breakevens.map((x) => ({
+            x,
+            rotulo: `BE ${x.toFixed(2)}`,
+            cor: CORES.breakeven,
+          })),
+        ];
+        return (
+          <LineChart
+            key={tipo}
+            titulo={
+              mostraPayoff
+                ? `${titulo}: payoff no vencimento e valor hoje`
+                : `${titulo}: valor hoje`
+            }
+            rotuloX={rotuloSpot}
+            marcadores={marcadores}
+            series={[
+              ...(mostraPayoff
+                ? [{ nome: 'Payoff no vencimento', cor: CORES.payoff, pontos: payoff }]
+                : []),
+              ...(curvas.hoje
+                ? [
+                  {
+                    nome: `Valor hoje (${TITLES[variant]})`,
+                    cor: CORES.valorHoje,
+                    pontos: curvas.hoje[tipo],
+                  },
+                ]
+                : []),
+              ...(curvas.comparacao
+                ? [
+                  {
+                    nome: `Valor hoje (${TITLES[outraVariante]})`,
+                    cor: CORES.comparacao,
+                    pontos: curvas.comparacao[tipo],
+                    tracejado: true,
+                  },
+                ]
+                : []),
+            ]}
+          />
+        );
+      })}
+    </>
+  );
+}
+
+type MonteCarloEstado =
+  | { ok: true; call: ResultadoMonteCarlo; put: ResultadoMonteCarlo }
+  | { ok: false; error: string };
+
+/**
+ * Conferencia do preco fechado por Monte Carlo, com os mesmos insumos
+ * (spot ja ajustado pelos dividendos discretos, quando houver). Exoticos
+ * simulam a trajetoria nas datas de observacao do produto.
+ */
+function MonteCarloSection({ result }: { result: ResultState['result'] }) {
+  const [caminhos, setCaminhos] = useState('100000');
+  const [semente, setSemente] = useState('42');
+  const [sequencia, setSequencia] = useState<SequenciaMonteCarlo>('pseudo');
+  const [antitetico, setAntitetico] = useState(true);
+  const [controle, setControle] = useState(true);
+  const [variavelControle, setVariavelControle] = useState<VariavelControle>('call');
+  const [simulacao, setSimulacao] = useState<MonteCarloEstado | null>(null);
+
+  const controleNaCall = !!result.exotica || variavelControle !== 'call';
+
+  const simular = () => {
+    const { inputs } = result;
+    const dataAtual = parseDate(inputs.dataAtual);
+    const dataVencimento = parseDate(inputs.dataVencimento);
+    if (!dataAtual || !dataVencimento) {
+      setSimulacao({ ok: false, error: 'Datas invalidas.' });
+      return;
+    }
+    const calendario = resolverCalendario(inputs);
+    if (!calendario.ok) {
+      setSimulacao(calendario);
+      return;
+    }
+    const S = result.dividendos?.spotAjustado ?? Number(inputs.S);
+    const config = {
+      caminhos: Number(caminhos),
+      semente: Number(semente),
+      sequencia,
+      antitetico,
+      controle,
+      variavelControle,
+    };
+    const argumentos = [
+      S,
+      Number(inputs.K),
+      Number(inputs.r),
+      Number(inputs.sigma),
+      dataAtual,
+      dataVencimento,
+      Number(inputs.q || '0'),
+      Number(inputs.taxaAluguel || '0'),
+    ] as const;
+    let call: ReturnType<typeof simularMonteCarlo>;
+    let put: ReturnType<typeof simularMonteCarlo>;
+    if (result.exotica) {
+      const exotica = parseOpcoesExotica({
+        ...inputs,
+        q: inputs.q ?? '0',
+        taxaAluguel: inputs.taxaAluguel ?? '0',
+      });
+      if (!exotica.ok) {
+        setSimulacao(exotica);
+        return;
+      }
+      const { produto, opcoes } = exotica;
+      const [, K, r, sigma, , , q, taxaAluguel] = argumentos;
+      const mercado = {
+        S,
+        r,
+        sigma,
+        q,
+        taxaAluguel,
+        dataAtual,
+        dataVencimento,
+        calendario: calendario.calendario,
+      };
+      const passos = Math.min(
+        observacoesExotica(produto, result.diasUteis, opcoes),
+        MAX_PASSOS_MONTE_CARLO,
+      );
+      call = simularMonteCarlo(
+        mercado,
+        payoffExoticoCaminho(produto, 'call', K, opcoes),
+        { ...config, passos },
+        K,
+      );
+      put = simularMonteCarlo(
+        mercado,
+        payoffExoticoCaminho(produto, 'put', K, opcoes),
+        { ...config, passos },
+        K,
+      );
+    } else {
+      // A call de mesmo strike como controle seria o proprio alvo (EP nulo).
+      call = precificarEuropeiaMonteCarlo(
+        'call',
+        ...argumentos,
+        controleNaCall ? config : { ...config, controle: false },
+        calendario.calendario,
+      );
+      put = precificarEuropeiaMonteCarlo(
+        'put',
+        ...argumentos,
+        config,
+        calendario.calendario,
+      );
+    }
+    if (!call.ok) {
+      setSimulacao(call);
+      return;
+    }
+    if (!put.ok) {
+      setSimulacao(put);
+      return;
+    }
+    setSimulacao({ ok: true, call, put });
+  };
+
+  return (
+    <section style={styles.section}>
+      <p style={styles.sectionTitle}>Conferencia por Monte Carlo</p>
+      <div style={styles.searchRow}>
+        <Input
+          label="Caminhos"
+          value={caminhos}
+          onChange={(e) => setCaminhos(e.target.value)}
+          inputMode="decimal"
+        />
+        <Input
+          label="Semente"
+          value={semente}
+          onChange={(e) => setSemente(e.target.value)}
+          inputMode="decimal"
+        />
+        <Select
+          label="Sequencia"
+          value={sequencia}
+          onChange={(e) => setSequencia(e.target.value as SequenciaMonteCarlo)}
+          options={[
+            { value: 'pseudo', label: 'Pseudoaleatoria' },
+            { value: 'sobol', label: 'Sobol (QMC aleatorizado)' },
+          ]}
+        />
+      </div>
+      <label style={{ ...styles.sectionText, display: 'flex', gap: '8px' }}>
+        <input
+          type="checkbox"
+          checked={antitetico}
+          onChange={(e) => setAntitetico(e.target.checked)}
+        />
+        Variaveis antiteticas
+      </label>
+      <label style={{ ...styles.sectionText, display: 'flex', gap: '8px' }}>
+        <input
+          type="checkbox"
+          checked={controle}
+          onChange={(e) => setControle(e.target.checked)}
+        />
+        Variavel de controle
+      </label>
+      {controle ? (
+        <Select
+          label="Controle"
+          value={variavelControle}
+          onChange={(e) => setVariavelControle(e.target.value as VariavelControle)}
+          options={Object.entries(VARIAVEIS_CONTROLE).map(([value, label]) => ({
+            value,
+            label,
+          }))}
+        />
+      ) : null}
+      {controle && !controleNaCall ? (
+        <p style={styles.sectionText}>
+          A call vanilla e simulada sem controle: a call de mesmo strike e o proprio
+          alvo da conferencia.
+        </p>
+      ) : null}
+      <button style={styles.secondaryButton} type="button" onClick={simular}>
+        Simular
+      </button>
+      {simulacao && !simulacao.ok ? (
+        <p style={styles.error}>{simulacao.error}</p>
+      ) : null}
+      {simulacao?.ok
+        ? [
+          { nome: 'Call', mc: simulacao.call, fechado: result.call },
+          { nome: 'Put', mc: simulacao.put, fechado: result.put },
+        ].map(({ nome, mc, fechado }) => (
+          <p key={nome} style={styles.sectionText}>
+            {nome}: {mc.preco.toFixed(4)} (EP {mc.erroPadrao.toFixed(4)}, IC{' '}
+            {(mc.confianca * 100).toFixed(0)}% [{mc.intervalo[0].toFixed(4)};{' '}
+            {mc.intervalo[1].toFixed(4)}]) | fechado {fechado.toFixed(4)} | desvio{' '}
+            {mc.erroPadrao > 0
+              ? `${((mc.preco - fechado) / mc.erroPadrao).toFixed(2)} EP`
+              : '-'}
+          </p>
+        ))
+        : null}
+    </section>
+  );
+}
+
+type EdpEstado =
+  | { ok: true; call: ResultadoEdp; put: ResultadoEdp }
+  | { ok: false; error: string };
+
+/**
+ * Conferencia pela EDP de Black-Scholes (Crank-Nicolson): europeias contra a
+ * formula fechada, americanas por PSOR e barreiras na propria grade.
+ */
+function DiferencasFinitasSection({
+  variant,
+  result,
+}: {
+  variant: Variant;
+  result: ResultState['result'];
+}) {
+  const [pontos, setPontos] = useState('400');
+  const [passos, setPassos] = useState('200');
+  const [rannacher, setRannacher] = useState(true);
+  const [solucao, setSolucao] = useState<EdpEstado | null>(null);
+
+  const resolver = () => {
+    const { inputs } = result;
+    const dataAtual = parseDate(inputs.dataAtual);
+    const dataVencimento = parseDate(inputs.dataVencimento);
+    if (!dataAtual || !dataVencimento) {
+      setSolucao({ ok: false, error: 'Datas invalidas.' });
+      return;
+    }
+    const calendario = resolverCalendario(inputs);
+    if (!calendario.ok) {
+      setSolucao(calendario);
+      return;
+    }
+    const exotica = result.exotica
+      ? parseOpcoesExotica({
+        ...inputs,
+        q: inputs.q ?? '0',
+        taxaAluguel: inputs.taxaAluguel ?? '0',
+      })
+      : null;
+    if (exotica && !exotica.ok) {
+      setSolucao(exotica);
+      return;
+    }
+    const opcoes = exotica?.opcoes;
+    const barreira =
+      opcoes?.barreira !== undefined && opcoes.tipoBarreira
+        ? {
+          barreira: opcoes.barreira,
+          tipoBarreira: opcoes.tipoBarreira,
+          rebate: opcoes.rebate,
+          monitoramentoDias: opcoes.monitoramentoDias,
+        }
+        : undefined;
+    const config = {
+      pontosEspaco: Number(pontos),
+      passosTempo: Number(passos),
+      passosRannacher: rannacher ? 4 : 0,
+      exercicio: variant === 'americano' ? ('americano' as const) : ('europeu' as const),
+      barreira,
+    };
+    const argumentos = [
+      result.dividendos?.spotAjustado ?? Number(inputs.S),
+      Number(inputs.K),
+      Number(inputs.r),
+      Number(inputs.sigma),
+      dataAtual,
+      dataVencimento,
+      Number(inputs.q || '0'),
+      Number(inputs.taxaAluguel || '0'),
+    ] as const;
+    const call = precificarEdp('call', ...argumentos, config, calendario.calendario);
+    if (!call.ok) {
+      setSolucao(call);
+      return;
+    }
+    const put = precificarEdp('put', ...argumentos, config, calendario.calendario);
+    if (!put.ok) {
+      setSolucao(put);
+      return;
+    }
+    setSolucao({ ok: true, call, put });
+  };
+
+  const S = Number(result.inputs.S);
+  const K = Number(result.inputs.K);
+  const dentroDoGrafico = (spot: number) =>
+    spot >= 0.5 * Math.min(S, K) && spot <= 1.5 * Math.max(S, K);
+  const curva = (edp: ResultadoEdp) =>
+    edp.spots
+      .map((x, i) => ({ x, y: edp.precos[i] }))
+      .filter((ponto) => dentroDoGrafico(ponto.x));
+
+  return (
+    <section style={styles.section}>
+      <p style={styles.sectionTitle}>
+        Conferencia por diferencas finitas (Crank-Nicolson)
+      </p>
+      <div style={styles.searchRow}>
+        <Input
+          label="Pontos em ln S"
+          value={pontos}
+          onChange={(e) => setPontos(e.target.value)}
+          inputMode="decimal"
+        />
+        <Input
+          label="Passos de tempo"
+          value={passos}
+          onChange={(e) => setPassos(e.target.value)}
+          inputMode="decimal"
+        />
+      </div>
+      <label style={{ ...styles.sectionText, display: 'flex', gap: '8px' }}>
+        <input
+          type="checkbox"
+          checked={rannacher}
+          onChange={(e) => setRannacher(e.target.checked)}
+        />
+        Suavizacao de Rannacher (4 meios-passos implicitos)
+      </label>
+      <button style={styles.secondaryButton} type="button" onClick={resolver}>
+        Resolver EDP
+      </button>
+      {solucao && !solucao.ok ? <p style={styles.error}>{solucao.error}</p> : null}
+      {solucao?.ok ? (
+        <>
+          {[
+            { nome: 'Call', edp: solucao.call, fechado: result.call },
+            { nome: 'Put', edp: solucao.put, fechado: result.put },
+          ].map(({ nome, edp, fechado }) => (
+            <p key={nome} style={styles.sectionText}>
+              {nome}: {edp.preco.toFixed(4)} | {TITLES[variant]} {fechado.toFixed(4)} |
+              diferenca {(edp.preco - fechado).toFixed(4)} | delta{' '}
+              {edp.delta.toFixed(4)} | gama {edp.gama.toFixed(5)} | theta/du{' '}
+              {edp.theta.toFixed(4)}
+              {edp.iteracoesPsor !== undefined
+                ? ` | ${edp.iteracoesPsor} iteracoes PSOR`
+                : ''}
+            </p>
+          ))}
+          <p style={styles.sectionText}>
+            Grade: {solucao.call.pontosEspaco} intervalos em ln S x{' '}
+            {solucao.call.passosTempo} passos de tempo
+          </p>
+          <LineChart
+            titulo="Preco na grade da EDP hoje x spot"
+            rotuloX="S"
+            marcadores={[
+              { x: S, rotulo: `S ${S}`, cor: CORES.spot },
+              { x: K, rotulo: `K ${K}`, cor: CORES.strike },
+            ]}
+            series={[
+              { nome: 'Call (EDP)', cor: CORES.valorHoje, pontos: curva(solucao.call) },
+              { nome: 'Put (EDP)', cor: CORES.comparacao, pontos: curva(solucao.put) },
+            ]}
+          />
+        </>
+      ) : null}
+    </section>
+  );
+}
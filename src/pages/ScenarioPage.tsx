@@ -0,0 +1,373 @@
+import type React from 'react';
+import { useState } from 'react';
+import { useLocation, useNavigate } from 'react-router-dom';
+import { styles } from '../styles';
+import { Input, Select } from '../components/Fields';
+import Heatmap from '../components/Heatmap';
+import { listarCalendarios } from '../utils/holidayCalendar';
+import type { TipoOpcao } from '../utils/impliedVolatility';
+import { type FormState, resolverCalendario } from '../utils/scenario';
+import {
+  type GradeCenario,
+  calcularGradeSpotDias,
+  calcularGradeSpotVol,
+  parseListaNumeros,
+} from '../utils/scenarioGrid';
+import { formatDateInput, parseDate } from '../utils/dateHelpers';
+
+type EixoCenario = 'vol' | 'dias';
+
+type MetricaValor = 'resultado' | 'valor';
+
+type CenarioForm = {
+  tipo: TipoOpcao;
+  quantidade: string;
+  S: string;
+  K: string;
+  r: string;
+  sigma: string;
+  q: string;
+  taxaAluguel: string;
+  dataAtual: string;
+  dataVencimento: string;
+  choquesSpot: string;
+  choquesVol: string;
+  diasAFrente: string;
+  calendario: string;
+  feriadosExtras: string;
+};
+
+function estadoInicial(form?: FormState): CenarioForm {
+  const vencimento = new Date();
+  vencimento.setDate(vencimento.getDate() + 90);
+  return {
+    tipo: form?.tipoOpcao ?? 'call',
+    quantidade: '1',
+    S: form?.S ?? '100',
+    K: form?.K ?? '105',
+    r: form?.r ?? '0.05',
+    sigma: form?.sigma ?? '0.2',
+    q: form?.q ?? '0',
+    taxaAluguel: form?.taxaAluguel ?? '0',
+    dataAtual: form?.dataAtual ?? formatDateInput(new Date()),
+    dataVencimento: form?.dataVencimento ?? formatDateInput(vencimento),
+    choquesSpot: '-20 -15 -10 -5 0 5 10 15 20',
+    choquesVol: '-10 -5 0 5 10 20',
+    diasAFrente: '0 7 14 30 60 90',
+    calendario: form?.calendario ?? 'b3',
+    feriadosExtras: form?.feriadosExtras ?? '',
+  };
+}
+
+function formatarChoque(valor: number): string {
+  return `${valor > 0 ? '+' : ''}${valor}`;
+}
+
+export default function ScenarioPage() {
+  const location = useLocation();
+  const navigate = useNavigate();
+  const origem = (location.state as { form?: FormState } | null)?.form;
+  const [form, setForm] = useState<CenarioForm>(() => estadoInicial(origem));
+  const [eixo, setEixo] = useState<EixoCenario>('vol');
+  const [metrica, setMetrica] = useState<MetricaValor>('resultado');
+  const [grade, setGrade] = useState<{ eixo: EixoCenario; grade: GradeCenario } | null>(
+    null,
+  );
+  const [error, setError] = useState<string | null>(null);
+
+  const handleChange = (key: keyof CenarioForm, value: string) => {
+    setForm((prev) => ({ ...prev, [key]: value }));
+  };
+
+  const calcular = (event: React.FormEvent) => {
+    event.preventDefault();
+    setError(null);
+    setGrade(null);
+
+    const valores = [form.quantidade, form.S, form.K, form.r, form.sigma].map(Number);
+    const q = Number(form.q || '0');
+    const taxaAluguel = Number(form.taxaAluguel || '0');
+    const [quantidade, S, K, r, sigma] = valores;
+    if ([...valores, q, taxaAluguel].some((n) => Number.isNaN(n)) || S <= 0 || K <= 0) {
+      setError('Preencha valores numericos validos.');
+      return;
+    }
+    const dataAtual = parseDate(form.dataAtual);
+    const dataVencimento = parseDate(form.dataVencimento);
+    if (!dataAtual || !dataVencimento) {
+      setError('Datas devem estar no formato DD/MM/AAAA.');
+      return;
+    }
+
+    const choquesSpot = parseListaNumeros(form.choquesSpot, 'Choques de spot');
+    const linhas =
+      eixo === 'vol'
+        ? parseListaNumeros(form.choquesVol, 'Choques de vol')
+        : parseListaNumeros(form.diasAFrente, 'Dias a frente');
+    if (!choquesSpot.ok) {
+      setError(choquesSpot.error);
+      return;
+    }
+    if (!linhas.ok) {
+      setError(linhas.error);
+      return;
+    }
+    if (choquesSpot.valores.some((v) => v <= -100)) {
+      setError('Choques de spot devem ser maiores que -100%.');
+      return;
+    }
+    if (eixo === 'dias' && linhas.valores.some((v) => v < 0 || !Number.isInteger(v))) {
+      setError('Dias a frente devem ser inteiros nao negativos.');
+      return;
+    }
+
+    const calendario = resolverCalendario(form);
+    if (!calendario.ok) {
+      setError(calendario.error);
+      return;
+    }
+
+    const posicao = {
+      tipo: form.tipo,
+      quantidade,
+      S,
+      K,
+      r,
+      sigma,
+      q,
+      taxaAluguel,
+      dataAtual,
+      dataVencimento,
+      calendario: calendario.calendario,
+    };
+    // Choques informados em %/pontos percentuais; a grade trabalha em fracao.
+    const spots = choquesSpot.valores.map((v) => v / 100);
+    setGrade({
+      eixo,
+      grade:
+        eixo === 'vol'
+          ? calcularGradeSpotVol(
+            posicao,
+            spots,
+            linhas.valores.map((v) => v / 100),
+          )
+          : calcularGradeSpotDias(posicao, spots, linhas.valores),
+    });
+  };
+
+  const colunas = grade?.grade.choquesSpot.map((c) => `${formatarChoque(c * 100)}%`) ?? [];
+  const linhas =
+    grade?.grade.linhas.map((l) =>
+      grade.eixo === 'vol' ? `${formatarChoque(Number((l * 100).toFixed(2)))} p.p.` : `D+${l}`,
+    ) ?? [];
+  // Celula do cenario base (sem choque), quando presente na grade.
+  const destaque: [number, number] | undefined = grade
+    ? [grade.grade.linhas.indexOf(0), grade.grade.choquesSpot.indexOf(0)]
+    : undefined;
+
+  return (
+    <main style={{ ...styles.container, maxWidth: '1100px' }}>
+      <div>
+        <h1 style={styles.title}>Cenarios de estresse</h1>
+        <p style={styles.subtitle}>
+          Reprecificacao pelo Black-Scholes classico em grades de choque de spot x vol
+          e spot x tempo.
+        </p>
+      </div>
+
+      <form style={styles.form} onSubmit={calcular}>
+        <div style={styles.searchRow}>
+          <Select
+            label="Opcao"
+            value={form.tipo}
+            onChange={(e) => handleChange('tipo', e.target.value)}
+            options={[
+              { value: 'call', label: 'Call' },
+              { value: 'put', label: 'Put' },
+            ]}
+          />
+          <Input
+            label="Quantidade"
+            hint="Negativa para posicao vendida."
+            value={form.quantidade}
+            onChange={(e) => handleChange('quantidade', e.target.value)}
+            inputMode="decimal"
+          />
+        </div>
+        <div style={styles.searchRow}>
+          <Input
+            label="S - Preco do ativo"
+            value={form.S}
+            onChange={(e) => handleChange('S', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="K - Strike"
+            value={form.K}
+            onChange={(e) => handleChange('K', e.target.value)}
+            inputMode="decimal"
+          />
+        </div>
+        <div style={styles.searchRow}>
+          <Input
+            label="r (anual)"
+            value={form.r}
+            onChange={(e) => handleChange('r', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="sigma (anual)"
+            value={form.sigma}
+            onChange={(e) => handleChange('sigma', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="q (anual)"
+            value={form.q}
+            onChange={(e) => handleChange('q', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="Taxa de aluguel (anual)"
+            value={form.taxaAluguel}
+            onChange={(e) => handleChange('taxaAluguel', e.target.value)}
+            inputMode="decimal"
+          />
+        </div>
+        <div style={styles.searchRow}>
+          <Input
+            label="Data atual (DD/MM/AAAA)"
+            value={form.dataAtual}
+            onChange={(e) => handleChange('dataAtual', e.target.value)}
+            inputMode="text"
+          />
+          <Input
+            label="Vencimento (DD/MM/AAAA)"
+            value={form.dataVencimento}
+            onChange={(e) => handleChange('dataVencimento', e.target.value)}
+            inputMode="text"
+          />
+        </div>
+        <Select
+          label="Calendario de feriados"
+          value={form.calendario}
+          onChange={(e) => handleChange('calendario', e.target.value)}
+          options={listarCalendarios().map((cal) => ({
+            value: cal.id,
+            label: cal.nome,
+          }))}
+        />
+
+        <div style={styles.modeRow}>
+          <button
+            style={eixo === 'vol' ? styles.modeButtonActive : styles.modeButton}
+            type="button"
+            onClick={() => setEixo('vol')}
+          >
+            Spot x volatilidade
+          </button>
+          <button
+            style={eixo === 'dias' ? styles.modeButtonActive : styles.modeButton}
+            type="button"
+            onClick={() => setEixo('dias')}
+          >
+            Spot x dias a frente
+          </button>
+        </div>
+        <Input
+          label="Choques de spot (%)"
+          hint="Variacoes percentuais de S, separadas por espaco."
+          value={form.choquesSpot}
+          onChange={(e) => handleChange('choquesSpot', e.target.value)}
+          inputMode="text"
+        />
+        {eixo === 'vol' ? (
+          <Input
+            label="Choques de vol (pontos percentuais)"
+            hint="Somados a sigma: 5 leva 20% para 25%."
+            value={form.choquesVol}
+            onChange={(e) => handleChange('choquesVol', e.target.value)}
+            inputMode="text"
+          />
+        ) : (
+          <Input
+            label="Dias corridos a frente"
+            hint="Data de avaliacao avancada; apos o vencimento vale o payoff."
+            value={form.diasAFrente}
+            onChange={(e) => handleChange('diasAFrente', e.target.value)}
+            inputMode="text"
+          />
+        )}
+
+        {error ? <p style={styles.error}>{error}</p> : null}
+
+        <div style={{ display: 'flex', gap: '12px', flexWrap: 'wrap' }}>
+          <button style={{ ...styles.button, ...styles.actionButton }} type="submit">
+            Calcular cenarios
+          </button>
+          <button
+            style={{ ...styles.secondaryButton, ...styles.actionButton }}
+            type="button"
+            onClick={() =>
+              navigate('/', { state: origem ? { form: origem } : null })
+            }
+          >
+            Voltar
+          </button>
+        </div>
+      </form>
+
+      {grade ? (
+        <>
+          <div style={styles.row}>
+            <span style={styles.rowLabel}>Valor da posicao no cenario base</span>
+            <span style={styles.rowValue}>{grade.grade.valorBase.toFixed(4)}</span>
+          </div>
+          <div style={styles.modeRow}>
+            <button
+              style={
+                metrica === 'resultado' ? styles.modeButtonActive : styles.modeButton
+              }
+              type="button"
+              onClick={() => setMetrica('resultado')}
+            >
+              Resultado (P&L)
+            </button>
+            <button
+              style={metrica === 'valor' ? styles.modeButtonActive : styles.modeButton}
+              type="button"
+              onClick={() => setMetrica('valor')}
+            >
+              Valor da posicao
+            </button>
+          </div>
+          <Heatmap
+            titulo={
+              metrica === 'resultado'
+                ? 'Resultado vs cenario base'
+                : 'Valor teorico da posicao'
+            }
+            rotuloCanto={grade.eixo === 'vol' ? 'Vol \\ Spot' : 'Data \\ Spot'}
+            colunas={colunas}
+            linhas={linhas}
+            valores={grade.grade.celulas.map((linha) =>
+              linha.map((celula) => celula[metrica]),
+            )}
+            destaque={destaque}
+          />
+          <Heatmap
+            titulo="Delta da posicao"
+            rotuloCanto={grade.eixo === 'vol' ? 'Vol \\ Spot' : 'Data \\ Spot'}
+            colunas={colunas}
+            linhas={linhas}
+            valores={grade.grade.celulas.map((linha) =>
+              linha.map((celula) => celula.delta),
+            )}
+            destaque={destaque}
+            casas={4}
+          />
+        </>
+      ) : null}
+    </main>
+  );
+}
@@ -0,0 +1,470 @@
+import type React from 'react';
+import { useState } from 'react';
+import { useLocation, useNavigate } from 'react-router-dom';
+import { styles } from '../styles';
+import { Input, Select } from '../components/Fields';
+import LineChart from '../components/LineChart';
+import { listarCalendarios } from '../utils/holidayCalendar';
+import { type FormState, resolverCalendario } from '../utils/scenario';
+import {
+  type LadoPerna,
+  type Perna,
+  type ResultadoEstrategia,
+  type TemplateEstrategia,
+  type TipoPerna,
+  TEMPLATES_ESTRATEGIA,
+  avaliarEstrategia,
+  criarPernasTemplate,
+} from '../utils/strategies';
+import { type PontoCurva } from '../utils/payoff';
+import { formatDateInput, parseDate } from '../utils/dateHelpers';
+
+type PernaForm = {
+  tipo: TipoPerna;
+  lado: LadoPerna;
+  quantidade: string;
+  K: string;
+  dataVencimento: string;
+};
+
+type MercadoForm = {
+  S: string;
+  r: string;
+  sigma: string;
+  q: string;
+  taxaAluguel: string;
+  dataAtual: string;
+  dataVencimento: string;
+  largura: string;
+  calendario: string;
+  feriadosExtras: string;
+};
+
+const TIPOS_PERNA: { value: TipoPerna; label: string }[] = [
+  { value: 'call', label: 'Call' },
+  { value: 'put', label: 'Put' },
+  { value: 'ativo', label: 'Ativo' },
+];
+
+const LADOS_PERNA: { value: LadoPerna; label: string }[] = [
+  { value: 'compra', label: 'Compra' },
+  { value: 'venda', label: 'Venda' },
+];
+
+function paraPernaForm(perna: Perna): PernaForm {
+  return {
+    tipo: perna.tipo,
+    lado: perna.lado,
+    quantidade: String(perna.quantidade),
+    K: perna.tipo === 'ativo' ? '' : String(Number(perna.K.toFixed(4))),
+    dataVencimento: formatDateInput(perna.dataVencimento),
+  };
+}
+
+function estadoInicial(form?: FormState): MercadoForm {
+  const S = Number(form?.S ?? '100') || 100;
+  const vencimento = new Date();
+  vencimento.setDate(vencimento.getDate() + 90);
+  // Largura padrao de ~5% do spot, arredondada a um numero "redondo".
+  const potencia = 10 ** Math.floor(Math.log10(S * 0.05));
+  return {
+    S: form?.S ?? '100',
+    r: form?.r ?? '0.05',
+    sigma: form?.sigma ?? '0.2',
+    q: form?.q ?? '0',
+    taxaAluguel: form?.taxaAluguel ?? '0',
+    dataAtual: form?.dataAtual ?? formatDateInput(new Date()),
+    dataVencimento: form?.dataVencimento ?? formatDateInput(vencimento),
+    largura: String(Math.round((S * 0.05) / potencia) * potencia),
+    calendario: form?.calendario ?? 'b3',
+    feriadosExtras: form?.feriadosExtras ?? '',
+  };
+}
+
+function formatarLimite(valor: number): string {
+  if (valor === Infinity) return 'Ilimitado';
+  if (valor === -Infinity) return '-Ilimitado';
+  return valor.toFixed(4);
+}
+
+export default function StrategyPage() {
+  const location = useLocation();
+  const navigate = useNavigate();
+  const origem = (location.state as { form?: FormState } | null)?.form;
+  const [mercado, setMercado] = useState<MercadoForm>(() => estadoInicial(origem));
+  const [template, setTemplate] = useState<TemplateEstrategia>('travaAlta');
+  const [pernas, setPernas] = useState<PernaForm[]>([]);
+  const [resultado, setResultado] = useState<ResultadoEstrategia | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleChange = (key: keyof MercadoForm, value: string) => {
+    setMercado((prev) => ({ ...prev, [key]: value }));
+  };
+
+  const alterarPerna = (index: number, key: keyof PernaForm, value: string) => {
+    setPernas((prev) =>
+      prev.map((perna, i) => (i === index ? { ...perna, [key]: value } : perna)),
+    );
+  };
+
+  const aplicarTemplate = () => {
+    setError(null);
+    const S = Number(mercado.S);
+    const largura = Number(mercado.largura);
+    const dataVencimento = parseDate(mercado.dataVencimento);
+    if (!(S > 0) || !(largura > 0) || !dataVencimento) {
+      setError('Informe S, largura entre strikes e vencimento validos.');
+      return;
+    }
+    setPernas(
+      criarPernasTemplate(template, S, largura, dataVencimento).map(paraPernaForm),
+    );
+    setResultado(null);
+  };
+
+  const adicionarPerna = () => {
+    setPernas((prev) => [
+      ...prev,
+      {
+        tipo: 'call',
+        lado: 'compra',
+        quantidade: '1',
+        K: mercado.S,
+        dataVencimento: mercado.dataVencimento,
+      },
+    ]);
+  };
+
+  const calcular = (event: React.FormEvent) => {
+    event.preventDefault();
+    setError(null);
+    setResultado(null);
+
+    const S = Number(mercado.S);
+    const r = Number(mercado.r);
+    const sigma = Number(mercado.sigma);
+    const q = Number(mercado.q || '0');
+    const taxaAluguel = Number(mercado.taxaAluguel || '0');
+    const dataAtual = parseDate(mercado.dataAtual);
+    if ([S, r, sigma, q, taxaAluguel].some((n) => Number.isNaN(n)) || S <= 0) {
+      setError('Preencha valores numericos validos.');
+      return;
+    }
+    if (!dataAtual) {
+      setError('Data atual deve estar no formato DD/MM/AAAA.');
+      return;
+    }
+    if (pernas.length === 0) {
+      setError('Adicione ao menos uma perna ou aplique um modelo.');
+      return;
+    }
+
+    const convertidas: Perna[] = [];
+    for (let i = 0; i < pernas.length; i += 1) {
+      const perna = pernas[i];
+      const quantidade = Number(perna.quantidade);
+      const K = Number(perna.K);
+      const dataVencimento = parseDate(perna.dataVencimento);
+      if (!(quantidade > 0)) {
+        setError(`Perna ${i + 1}: quantidade deve ser maior que zero.`);
+        return;
+      }
+      if (perna.tipo !== 'ativo' && (!(K > 0) || !dataVencimento)) {
+        setError(`Perna ${i + 1}: informe strike e vencimento (DD/MM/AAAA).`);
+        return;
+      }
+      convertidas.push({
+        tipo: perna.tipo,
+        lado: perna.lado,
+        quantidade,
+        K: perna.tipo === 'ativo' ? 0 : K,
+        dataVencimento: dataVencimento ?? dataAtual,
+      });
+    }
+
+    const calendario = resolverCalendario(mercado);
+    if (!calendario.ok) {
+      setError(calendario.error);
+      return;
+    }
+    setResultado(
+      avaliarEstrategia(convertidas, {
+        S,
+        r,
+        sigma,
+        q,
+        taxaAluguel,
+        dataAtual,
+        calendario: calendario.calendario,
+      }),
+    );
+  };
+
+  return (
+    <main style={styles.container}>
+      <div>
+        <h1 style={styles.title}>Estrategias com opcoes</h1>
+        <p style={styles.subtitle}>
+          Monte pernas de calls, puts e ativo; precos e gregas pelo Black-Scholes
+          classico.
+        </p>
+      </div>
+
+      <form style={styles.form} onSubmit={calcular}>
+        <Input
+          label="S - Preco do ativo"
+          value={mercado.S}
+          onChange={(e) => handleChange('S', e.target.value)}
+          inputMode="decimal"
+        />
+        <Input
+          label="r - Taxa livre de risco (anual)"
+          value={mercado.r}
+          onChange={(e) => handleChange('r', e.target.value)}
+          inputMode="decimal"
+        />
+        <Input
+          label="sigma - Volatilidade anual"
+          value={mercado.sigma}
+          onChange={(e) => handleChange('sigma', e.target.value)}
+          inputMode="decimal"
+        />
+        <Input
+          label="q - Dividend yield (anual)"
+          value={mercado.q}
+          onChange={(e) => handleChange('q', e.target.value)}
+          inputMode="decimal"
+          placeholder="0"
+        />
+        <Input
+          label="Taxa de aluguel (anual, opcional)"
+          value={mercado.taxaAluguel}
+          onChange={(e) => handleChange('taxaAluguel', e.target.value)}
+          inputMode="decimal"
+          placeholder="0"
+        />
+        <Input
+          label="Data atual (DD/MM/AAAA)"
+          value={mercado.dataAtual}
+          onChange={(e) => handleChange('dataAtual', e.target.value)}
+          inputMode="text"
+        />
+        <Select
+          label="Calendario de feriados"
+          value={mercado.calendario}
+          onChange={(e) => handleChange('calendario', e.target.value)}
+          options={listarCalendarios().map((cal) => ({
+            value: cal.id,
+            label: cal.nome,
+          }))}
+        />
+
+        <section style={styles.section}>
+          <p style={styles.sectionTitle}>Modelo de estrategia</p>
+          <Select
+            label="Modelo"
+            value={template}
+            onChange={(e) => setTemplate(e.target.value as TemplateEstrategia)}
+            options={Object.entries(TEMPLATES_ESTRATEGIA).map(([value, label]) => ({
+              value,
+              label,
+            }))}
+          />
+          <div style={styles.searchRow}>
+            <Input
+              label="Largura entre strikes"
+              hint="Distancia entre strikes consecutivos do modelo, a partir do strike mais proximo de S."
+              value={mercado.largura}
+              onChange={(e) => handleChange('largura', e.target.value)}
+              inputMode="decimal"
+            />
+            <Input
+              label="Vencimento (DD/MM/AAAA)"
+              value={mercado.dataVencimento}
+              onChange={(e) => handleChange('dataVencimento', e.target.value)}
+              inputMode="text"
+            />
+          </div>
+          <button style={styles.secondaryButton} type="button" onClick={aplicarTemplate}>
+            Aplicar modelo
+          </button>
+        </section>
+
+        {pernas.map((perna, index) => (
+          <section key={index} style={styles.section}>
+            <p style={styles.sectionTitle}>Perna {index + 1}</p>
+            <div style={styles.searchRow}>
+              <Select
+                label="Tipo"
+                value={perna.tipo}
+                onChange={(e) => alterarPerna(index, 'tipo', e.target.value)}
+                options={TIPOS_PERNA}
+              />
+              <Select
+                label="Lado"
+                value={perna.lado}
+                onChange={(e) => alterarPerna(index, 'lado', e.target.value)}
+                options={LADOS_PERNA}
+              />
+              <Input
+                label="Quantidade"
+                value={perna.quantidade}
+                onChange={(e) => alterarPerna(index, 'quantidade', e.target.value)}
+                inputMode="decimal"
+              />
+              {perna.tipo !== 'ativo' ? (
+                <>
+                  <Input
+                    label="Strike"
+                    value={perna.K}
+                    onChange={(e) => alterarPerna(index, 'K', e.target.value)}
+                    inputMode="decimal"
+                  />
+                  <Input
+                    label="Vencimento"
+                    value={perna.dataVencimento}
+                    onChange={(e) =>
+                      alterarPerna(index, 'dataVencimento', e.target.value)
+                    }
+                    inputMode="text"
+                  />
+                </>
+              ) : null}
+            </div>
+            <button
+              style={styles.secondaryButton}
+              type="button"
+              onClick={() => setPernas((prev) => prev.filter((_, i) => i !== index))}
+            >
+              Remover perna
+            </button>
+          </section>
+        ))}
+
+        {error ? <p style={styles.error}>{error}</p> : null}
+
+        <div style={{ display: 'flex', gap: '12px', flexWrap: 'wrap' }}>
+          <button
+            style={{ ...styles.secondaryButton, ...styles.actionButton }}
+            type="button"
+            onClick={adicionarPerna}
+          >
+            Adicionar perna
+          </button>
+          <button style={{ ...styles.button, ...styles.actionButton }} type="submit">
+            Calcular estrategia
+          </button>
+          <button
+            style={{ ...styles.secondaryButton, ...styles.actionButton }}
+            type="button"
+            onClick={() =>
+              navigate('/', { state: origem ? { form: origem } : null })
+            }
+          >
+            Voltar
+          </button>
+        </div>
+      </form>
+
+      {resultado ? (
+        <ResultadoEstrategiaSection resultado={resultado} S={Number(mercado.S)} />
+      ) : null}
+    </main>
+  );
+}
+
+function ResultadoEstrategiaSection({
+  resultado,
+  S,
+}: {
+  resultado: ResultadoEstrategia;
+  S: number;
+}) {
+  const { premioLiquido, gregas } = resultado;
+  // O grafico mostra a regiao de interesse; os limites usam a grade completa.
+  const strikes = resultado.pernas
+    .filter(({ perna }) => perna.tipo !== 'ativo')
+    .map(({ perna }) => perna.K);
+  const xMin = 0.5 * Math.min(S, ...strikes);
+  const xMax = 1.5 * Math.max(S, ...strikes);
+  const recortar = (curva: PontoCurva[]) =>
+    curva.filter((p) => p.x >= xMin && p.x <= xMax);
+  return (
+    <>
+      <div style={styles.row}>
+        <span style={styles.rowLabel}>
+          Premio liquido ({premioLiquido >= 0 ? 'debito' : 'credito'})
+        </span>
+        <span style={styles.rowValue}>{Math.abs(premioLiquido).toFixed(4)}</span>
+      </div>
+      <div style={styles.row}>
+        <span style={styles.rowLabel}>
+          Lucro maximo ({formatDateInput(resultado.horizonte)})
+        </span>
+        <span style={styles.rowValue}>{formatarLimite(resultado.lucroMaximo)}</span>
+      </div>
+      <div style={styles.row}>
+        <span style={styles.rowLabel}>Perda maxima</span>
+        <span style={styles.rowValue}>{formatarLimite(resultado.perdaMaxima)}</span>
+      </div>
+      <div style={styles.row}>
+        <span style={styles.rowLabel}>Breakevens</span>
+        <span style={styles.rowValue}>
+          {resultado.breakevens.length > 0
+            ? resultado.breakevens.map((x) => x.toFixed(2)).join(' | ')
+            : '-'}
+        </span>
+      </div>
+      <section style={styles.section}>
+        <p style={styles.sectionTitle}>Gregas da posicao</p>
+        {(
+          [
+            ['Delta', gregas.delta],
+            ['Gama', gregas.gama],
+            ['Vega (por 1% de vol)', gregas.vega],
+            ['Theta (por dia util)', gregas.theta],
+            ['Rho (por 1% de taxa)', gregas.rho],
+          ] as const
+        ).map(([label, valor]) => (
+          <div key={label} style={styles.greekRow}>
+            <span style={styles.sectionText}>{label}</span>
+            <span style={styles.greekValue}>{valor.toFixed(6)}</span>
+          </div>
+        ))}
+      </section>
+      <section style={styles.section}>
+        <p style={styles.sectionTitle}>Pernas</p>
+        {resultado.pernas.map(({ perna, preco }, index) => (
+          <p key={index} style={styles.sectionText}>
+            {perna.lado === 'compra' ? 'Compra' : 'Venda'} {perna.quantidade}{' '}
+            {perna.tipo}
+            {perna.tipo !== 'ativo'
+              ? ` K ${perna.K} (${formatDateInput(perna.dataVencimento)})`
+              : ''}{' '}
+            - preco unitario {preco.toFixed(4)}
+          </p>
+        ))}
+      </section>
+      <LineChart
+        titulo="Resultado da estrategia"
+        rotuloX="S"
+        series={[
+          {
+            nome: `No vencimento (${formatDateInput(resultado.horizonte)})`,
+            cor: '#94a3b8',
+            pontos: recortar(resultado.curvaHorizonte),
+          },
+          { nome: 'Hoje', cor: '#38bdf8', pontos: recortar(resultado.curvaHoje) },
+        ]}
+        marcadores={[
+          { x: S, rotulo: `S ${S}`, cor: '#facc15' },
+          ...resultado.breakevens.map((x) => ({
+            x,
+            rotulo: `BE ${x.toFixed(2)}`,
+            cor: '#4ade80',
+          })),
+        ]}
+      />
+    </>
+  );
+}
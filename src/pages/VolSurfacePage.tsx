@@ -0,0 +1,300 @@
+import type React from 'react';
+import { useState } from 'react';
+import { useLocation, useNavigate } from 'react-router-dom';
+import { styles } from '../styles';
+import { Input, Select } from '../components/Fields';
+import LineChart from '../components/LineChart';
+import { listarCalendarios } from '../utils/holidayCalendar';
+import { type FormState, resolverCalendario } from '../utils/scenario';
+import {
+  type SuperficieVolatilidade,
+  construirSuperficie,
+  parseCotacoes,
+  volatilidadeDaSuperficie,
+  volatilidadeDoSmile,
+} from '../utils/volSurface';
+import { formatDateInput, parseDate } from '../utils/dateHelpers';
+import { useMercadoAtivo } from '../hooks/useMercadoAtivo';
+
+type SuperficieForm = {
+  S: string;
+  r: string;
+  q: string;
+  taxaAluguel: string;
+  dataAtual: string;
+  cotacoes: string;
+  calendario: string;
+  feriadosExtras: string;
+};
+
+const CORES_FATIAS = ['#38bdf8', '#f59e0b', '#4ade80', '#f472b6', '#a78bfa', '#facc15'];
+
+function estadoInicial(form?: FormState): SuperficieForm {
+  return {
+    S: form?.S ?? '100',
+    r: form?.r ?? '0.05',
+    q: form?.q ?? '0',
+    taxaAluguel: form?.taxaAluguel ?? '0',
+    dataAtual: form?.dataAtual ?? formatDateInput(new Date()),
+    cotacoes: '',
+    calendario: form?.calendario ?? 'b3',
+    feriadosExtras: form?.feriadosExtras ?? '',
+  };
+}
+
+export default function VolSurfacePage() {
+  const location = useLocation();
+  const navigate = useNavigate();
+  const origem = (location.state as { form?: FormState } | null)?.form;
+  const [form, setForm] = useState<SuperficieForm>(() => estadoInicial(origem));
+  const { superficie: superficieAtiva, definirSuperficie } = useMercadoAtivo();
+  const [superficie, setSuperficie] = useState<SuperficieVolatilidade | null>(
+    superficieAtiva,
+  );
+  const [error, setError] = useState<string | null>(null);
+
+  const handleChange = (key: keyof SuperficieForm, value: string) => {
+    setForm((prev) => ({ ...prev, [key]: value }));
+  };
+
+  const loadCotacoesFile = (event: React.ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    if (!file) return;
+    file
+      .text()
+      .then((text) => handleChange('cotacoes', text))
+      .catch(() => setError('Erro ao ler arquivo de cotacoes.'));
+    event.target.value = '';
+  };
+
+  const construir = (event: React.FormEvent) => {
+    event.preventDefault();
+    setError(null);
+
+    const S = Number(form.S);
+    const r = Number(form.r);
+    const q = Number(form.q || '0');
+    const taxaAluguel = Number(form.taxaAluguel || '0');
+    const dataAtual = parseDate(form.dataAtual);
+    if ([S, r, q, taxaAluguel].some((n) => Number.isNaN(n)) || S <= 0) {
+      setError('Preencha valores numericos validos.');
+      return;
+    }
+    if (!dataAtual) {
+      setError('Data atual deve estar no formato DD/MM/AAAA.');
+      return;
+    }
+    const cotacoes = parseCotacoes(form.cotacoes);
+    if (!cotacoes.ok) {
+      setError(cotacoes.error);
+      return;
+    }
+    const calendario = resolverCalendario(form);
+    if (!calendario.ok) {
+      setError(calendario.error);
+      return;
+    }
+
+    const construida = construirSuperficie(cotacoes.cotacoes, {
+      S,
+      r,
+      q,
+      taxaAluguel,
+      dataAtual,
+      calendario: calendario.calendario,
+    });
+    if (construida.fatias.length === 0) {
+      setError('Nenhuma cotacao gerou volatilidade implicita valida.');
+    }
+    setSuperficie(construida);
+  };
+
+  // Ativa a superficie e volta ao formulario com sigma ja interpolado.
+  const usarNoFormulario = () => {
+    if (!superficie) return;
+    definirSuperficie(superficie);
+    if (!origem) {
+      navigate('/');
+      return;
+    }
+    const dataVencimento = parseDate(origem.dataVencimento);
+    const sigma = dataVencimento
+      ? volatilidadeDaSuperficie(superficie, Number(origem.K), dataVencimento)
+      : null;
+    navigate('/', {
+      state: {
+        form: sigma !== null && Number.isFinite(sigma)
+          ? { ...origem, sigma: sigma.toFixed(6) }
+          : origem,
+      },
+    });
+  };
+
+  return (
+    <main style={{ ...styles.container, maxWidth: '1100px' }}>
+      <div>
+        <h1 style={styles.title}>Superficie de volatilidade</h1>
+        <p style={styles.subtitle}>
+          Vol implicita de cada premio, smile por vencimento com spline cubica em
+          ln(K/F) e interpolacao da variancia total entre vencimentos.
+        </p>
+      </div>
+
+      <form style={styles.form} onSubmit={construir}>
+        <div style={styles.searchRow}>
+          <Input
+            label="S - Preco do ativo"
+            value={form.S}
+            onChange={(e) => handleChange('S', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="r (anual)"
+            value={form.r}
+            onChange={(e) => handleChange('r', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="q (anual)"
+            value={form.q}
+            onChange={(e) => handleChange('q', e.target.value)}
+            inputMode="decimal"
+          />
+          <Input
+            label="Taxa de aluguel (anual)"
+            value={form.taxaAluguel}
+            onChange={(e) => handleChange('taxaAluguel', e.target.value)}
+            inputMode="decimal"
+          />
+        </div>
+        <Input
+          label="Data atual (DD/MM/AAAA)"
+          value={form.dataAtual}
+          onChange={(e) => handleChange('dataAtual', e.target.value)}
+          inputMode="text"
+        />
+        <Select
+          label="Calendario de feriados"
+          value={form.calendario}
+          onChange={(e) => handleChange('calendario', e.target.value)}
+          options={listarCalendarios().map((cal) => ({
+            value: cal.id,
+            label: cal.nome,
+          }))}
+        />
+        <label style={styles.inputGroup}>
+          <span style={styles.label}>
+            Cotacoes (DD/MM/AAAA;strike;premio;call|put, uma por linha)
+          </span>
+          <textarea
+            style={{ ...styles.input, minHeight: '140px', resize: 'vertical' }}
+            value={form.cotacoes}
+            onChange={(e) => handleChange('cotacoes', e.target.value)}
+            placeholder={'20/03/2026;95;2.10;put\n20/03/2026;100;3.40;call\n20/03/2026;105;1.55;call'}
+          />
+          <input
+            style={styles.sectionText}
+            type="file"
+            accept=".txt,.csv"
+            onChange={loadCotacoesFile}
+          />
+        </label>
+
+        {error ? <p style={styles.error}>{error}</p> : null}
+
+        <div style={{ display: 'flex', gap: '12px', flexWrap: 'wrap' }}>
+          <button style={{ ...styles.button, ...styles.actionButton }} type="submit">
+            Construir superficie
+          </button>
+          {superficie && superficie.fatias.length > 0 ? (
+            <button
+              style={{ ...styles.secondaryButton, ...styles.actionButton }}
+              type="button"
+              onClick={usarNoFormulario}
+            >
+              Usar no formulario
+            </button>
+          ) : null}
+          <button
+            style={{ ...styles.secondaryButton, ...styles.actionButton }}
+            type="button"
+            onClick={() =>
+              navigate('/', { state: origem ? { form: origem } : null })
+            }
+          >
+            Voltar
+          </button>
+        </div>
+      </form>
+
+      {superficie && superficie.fatias.length > 0 ? (
+        <SmileSection superficie={superficie} />
+      ) : null}
+      {superficie && superficie.rejeitadas.length > 0 ? (
+        <section style={styles.section}>
+          <p style={styles.sectionTitle}>
+            Cotacoes descartadas ({superficie.rejeitadas.length})
+          </p>
+          {superficie.rejeitadas.map(({ cotacao, motivo }, index) => (
+            <p key={index} style={styles.sectionText}>
+              {formatDateInput(cotacao.dataVencimento)} K {cotacao.K} {cotacao.tipo}{' '}
+              premio {cotacao.premio}: {motivo}
+            </p>
+          ))}
+        </section>
+      ) : null}
+    </main>
+  );
+}
+
+function SmileSection({ superficie }: { superficie: SuperficieVolatilidade }) {
+  const strikes = superficie.fatias.flatMap((f) => f.pontos.map((p) => p.K));
+  const kMin = Math.min(...strikes);
+  const kMax = Math.max(...strikes);
+  const grade = Array.from({ length: 81 }, (_, i) => kMin + ((kMax - kMin) * i) / 80);
+
+  return (
+    <>
+      <LineChart
+        titulo="Smile ajustado por vencimento (vol % x strike)"
+        rotuloX="K"
+        series={superficie.fatias.map((fatia, i) => ({
+          nome: formatDateInput(fatia.dataVencimento),
+          cor: CORES_FATIAS[i % CORES_FATIAS.length],
+          pontos: grade.map((K) => ({ x: K, y: volatilidadeDoSmile(fatia, K) * 100 })),
+        }))}
+        marcadores={[
+          { x: superficie.mercado.S, rotulo: `S ${superficie.mercado.S}`, cor: '#facc15' },
+        ]}
+      />
+      {superficie.fatias.map((fatia) => (
+        <section key={fatia.dataVencimento.getTime()} style={styles.section}>
+          <p style={styles.sectionTitle}>
+            {formatDateInput(fatia.dataVencimento)} | T = {fatia.T.toFixed(4)} | F ={' '}
+            {fatia.forward.toFixed(4)}
+          </p>
+          <div style={styles.tableWrapper}>
+            <table style={styles.table}>
+              <thead>
+                <tr>
+                  <th style={styles.tableHeader}>Strike</th>
+                  <th style={styles.tableHeader}>ln(K/F)</th>
+                  <th style={styles.tableHeader}>Vol implicita</th>
+                </tr>
+              </thead>
+              <tbody>
+                {fatia.pontos.map((ponto) => (
+                  <tr key={ponto.K}>
+                    <td style={styles.tableCell}>{ponto.K}</td>
+                    <td style={styles.tableCell}>{ponto.k.toFixed(4)}</td>
+                    <td style={styles.tableCell}>{(ponto.vol * 100).toFixed(2)}%</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        </section>
+      ))}
+    </>
+  );
+}
@@ -0,0 +1,304 @@
+import type React from 'react';
+import { useState } from 'react';
+import { useLocation, useNavigate } from 'react-router-dom';
+import { styles } from '../styles';
+import { Input, Select } from '../components/Fields';
+import LineChart from '../components/LineChart';
+import { listarCalendarios } from '../utils/holidayCalendar';
+import { type FormState, resolverCalendario } from '../utils/scenario';
+import {
+  type CurvaJuros,
+  construirCurva,
+  exp252ParaContinua,
+  parseVerticesCurva,
+  taxaNoPrazo,
+  taxaParaVencimento,
+  taxaTermo,
+} from '../utils/yieldCurve';
+import { getProvider, listProviders } from '../services/marketDataProvider';
+import { formatDateInput, parseDate } from '../utils/dateHelpers';
+import { useMercadoAtivo } from '../hooks/useMercadoAtivo';
+
+type CurvaForm = {
+  dataAtual: string;
+  vertices: string;
+  calendario: string;
+  feriadosExtras: string;
+};
+
+function estadoInicial(form?: FormState): CurvaForm {
+  return {
+    dataAtual: form?.dataAtual ?? formatDateInput(new Date()),
+    vertices: '',
+    calendario: form?.calendario ?? 'b3',
+    feriadosExtras: form?.feriadosExtras ?? '',
+  };
+}
+
+function percentual(valor: number, casas = 4): string {
+  return `${(valor * 100).toFixed(casas)}%`;
+}
+
+export default function YieldCurvePage() {
+  const location = useLocation();
+  const navigate = useNavigate();
+  const origem = (location.state as { form?: FormState } | null)?.form;
+  const [form, setForm] = useState<CurvaForm>(() => estadoInicial(origem));
+  const { curva: curvaAtiva, definirCurva } = useMercadoAtivo();
+  const [curva, setCurva] = useState<CurvaJuros | null>(curvaAtiva);
+  const [provedor, setProvedor] = useState('fixture');
+  const [error, setError] = useState<string | null>(null);
+
+  const handleChange = (key: keyof CurvaForm, value: string) => {
+    setForm((prev) => ({ ...prev, [key]: value }));
+  };
+
+  const loadVerticesFile = (event: React.ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    if (!file) return;
+    file
+      .text()
+      .then((text) => handleChange('vertices', text))
+      .catch(() => setError('Erro ao ler arquivo de vertices.'));
+    event.target.value = '';
+  };
+
+  // Provedores entregam taxas em decimal; o campo trabalha em % a.a.
+  const carregarDoProvedor = () => {
+    const fonte = getProvider(provedor);
+    if (!fonte) return;
+    setError(null);
+    fonte
+      .riskFreeCurve()
+      .then((vertices) =>
+        handleChange(
+          'vertices',
+          vertices
+            .map((v) => `${v.businessDays};${(v.rate * 100).toFixed(4)}`)
+            .join('\n'),
+        ),
+      )
+      .catch((err) => setError((err as Error).message || 'Erro ao carregar curva.'));
+  };
+
+  const construir = (event: React.FormEvent) => {
+    event.preventDefault();
+    setError(null);
+
+    const dataAtual = parseDate(form.dataAtual);
+    if (!dataAtual) {
+      setError('Data atual deve estar no formato DD/MM/AAAA.');
+      return;
+    }
+    const calendario = resolverCalendario(form);
+    if (!calendario.ok) {
+      setError(calendario.error);
+      return;
+    }
+    const vertices = parseVerticesCurva(form.vertices, dataAtual, calendario.calendario);
+    if (!vertices.ok) {
+      setError(vertices.error);
+      return;
+    }
+    setCurva(construirCurva(vertices.vertices, dataAtual, calendario.calendario));
+  };
+
+  const dataVencimento = origem ? parseDate(origem.dataVencimento) : null;
+  const dataOpcao = origem ? parseDate(origem.dataAtual) : null;
+  const taxaOpcao =
+    curva && dataOpcao && dataVencimento
+      ? taxaParaVencimento(curva, dataOpcao, dataVencimento)
+      : null;
+
+  // Ativa a curva e volta ao formulario com r no prazo da opcao.
+  const usarNoFormulario = () => {
+    if (!curva) return;
+    definirCurva(curva);
+    navigate('/', {
+      state: origem
+        ? {
+          form: taxaOpcao
+            ? { ...origem, r: taxaOpcao.taxaContinua.toFixed(6) }
+            : origem,
+        }
+        : null,
+    });
+  };
+
+  return (
+    <main style={{ ...styles.container, maxWidth: '1100px' }}>
+      <div>
+        <h1 style={styles.title}>Curva de juros (DI1 / pre-DI)</h1>
+        <p style={styles.subtitle}>
+          Taxas zero em base 252 com interpolacao flat-forward; os precificadores
+          recebem a taxa continua equivalente no prazo da opcao.
+        </p>
+      </div>
+
+      <form style={styles.form} onSubmit={construir}>
+        <Input
+          label="Data base (DD/MM/AAAA)"
+          value={form.dataAtual}
+          onChange={(e) => handleChange('dataAtual', e.target.value)}
+          inputMode="text"
+        />
+        <Select
+          label="Calendario de feriados"
+          value={form.calendario}
+          onChange={(e) => handleChange('calendario', e.target.value)}
+          options={listarCalendarios().map((cal) => ({
+            value: cal.id,
+            label: cal.nome,
+          }))}
+        />
+        <div style={styles.searchRow}>
+          <Select
+            label="Provedor de dados"
+            value={provedor}
+            onChange={(e) => setProvedor(e.target.value)}
+            options={listProviders().map((item) => ({
+              value: item.id,
+              label: item.label,
+            }))}
+          />
+          <button
+            style={{ ...styles.secondaryButton, marginTop: 0 }}
+            type="button"
+            onClick={carregarDoProvedor}
+          >
+            Carregar curva do provedor
+          </button>
+        </div>
+        <label style={styles.inputGroup}>
+          <span style={styles.label}>
+            Vertices (du, DD/MM/AAAA ou DI1F27;taxa em % a.a., um por linha)
+          </span>
+          <textarea
+            style={{ ...styles.input, minHeight: '140px', resize: 'vertical' }}
+            value={form.vertices}
+            onChange={(e) => handleChange('vertices', e.target.value)}
+            placeholder={'DI1F26;14,95\nDI1N26;14,60\nDI1F27;14,20\n504;13,60'}
+          />
+          <input
+            style={styles.sectionText}
+            type="file"
+            accept=".txt,.csv"
+            onChange={loadVerticesFile}
+          />
+        </label>
+
+        {error ? <p style={styles.error}>{error}</p> : null}
+
+        <div style={{ display: 'flex', gap: '12px', flexWrap: 'wrap' }}>
+          <button style={{ ...styles.button, ...styles.actionButton }} type="submit">
+            Construir curva
+          </button>
+          {curva && curva.vertices.length > 0 ? (
+            <button
+              style={{ ...styles.secondaryButton, ...styles.actionButton }}
+              type="button"
+              onClick={usarNoFormulario}
+            >
+              Usar no formulario
+            </button>
+          ) : null}
+          <button
+            style={{ ...styles.secondaryButton, ...styles.actionButton }}
+            type="button"
+            onClick={() =>
+              navigate('/', { state: origem ? { form: origem } : null })
+            }
+          >
+            Voltar
+          </button>
+        </div>
+      </form>
+
+      {curva && taxaOpcao ? (
+        <section style={styles.section}>
+          <p style={styles.sectionTitle}>
+            Prazo da opcao: {taxaOpcao.diasUteis} dias uteis
+          </p>
+          <div style={styles.greekRow}>
+            <span style={styles.sectionText}>Taxa exponencial 252</span>
+            <span style={styles.greekValue}>{percentual(taxaOpcao.taxaExp252)}</span>
+          </div>
+          <div style={styles.greekRow}>
+            <span style={styles.sectionText}>Taxa continua (r)</span>
+            <span style={styles.greekValue}>{percentual(taxaOpcao.taxaContinua)}</span>
+          </div>
+        </section>
+      ) : null}
+      {curva && curva.vertices.length > 0 ? <CurvaSection curva={curva} /> : null}
+    </main>
+  );
+}
+
+function CurvaSection({ curva }: { curva: CurvaJuros }) {
+  const ultimo = curva.vertices[curva.vertices.length - 1].diasUteis;
+  const grade = Array.from({ length: 101 }, (_, i) => Math.max(1, (ultimo * i) / 100));
+
+  return (
+    <>
+      <LineChart
+        titulo="Taxa zero e termo diario (% a.a., base 252) x dias uteis"
+        rotuloX="du"
+        series={[
+          {
+            nome: 'Zero',
+            cor: '#38bdf8',
+            pontos: grade.map((du) => ({ x: du, y: taxaNoPrazo(curva, du) * 100 })),
+          },
+          {
+            nome: 'Termo',
+            cor: '#f59e0b',
+            tracejado: true,
+            pontos: grade.map((du) => ({
+              x: du,
+              y: taxaTermo(curva, Math.max(du - 1, 0), du) * 100,
+            })),
+          },
+        ]}
+      />
+      <section style={styles.section}>
+        <p style={styles.sectionTitle}>
+          Vertices na data base {formatDateInput(curva.dataBase)}
+        </p>
+        <div style={styles.tableWrapper}>
+          <table style={styles.table}>
+            <thead>
+              <tr>
+                <th style={styles.tableHeader}>Dias uteis</th>
+                <th style={styles.tableHeader}>Taxa 252</th>
+                <th style={styles.tableHeader}>Taxa continua</th>
+                <th style={styles.tableHeader}>Fator de desconto</th>
+                <th style={styles.tableHeader}>Termo desde o anterior</th>
+              </tr>
+            </thead>
+            <tbody>
+              {curva.vertices.map((vertice, i) => (
+                <tr key={vertice.diasUteis}>
+                  <td style={styles.tableCell}>{vertice.diasUteis}</td>
+                  <td style={styles.tableCell}>{percentual(vertice.taxa)}</td>
+                  <td style={styles.tableCell}>
+                    {percentual(exp252ParaContinua(vertice.taxa))}
+                  </td>
+                  <td style={styles.tableCell}>{vertice.fatorDesconto.toFixed(6)}</td>
+                  <td style={styles.tableCell}>
+                    {percentual(
+                      taxaTermo(
+                        curva,
+                        i === 0 ? 0 : curva.vertices[i - 1].diasUteis,
+                        vertice.diasUteis,
+                      ),
+                    )}
+                  </td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        </div>
+      </section>
+    </>
+  );
+}
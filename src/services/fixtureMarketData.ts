@@ -0,0 +1,106 @@
+import { AssetDetails, RiskFreeVertex } from '../types';
+import { type BarraOhlc } from '../utils/historicalVolatility';
+
+/** Conjunto de dados servido pelos provedores em memoria (fixture ou arquivo). */
+export type MarketDataSet = {
+  assets: Array<AssetDetails & { history?: BarraOhlc[] }>;
+  riskFreeCurve?: RiskFreeVertex[];
+};
+
+const FIXTURE_END_DATE = new Date(2025, 11, 30);
+const FIXTURE_BARS = 260;
+
+// Gerador congruencial simples: a fixture precisa ser identica a cada carga.
+function gerador(semente: number): () => number {
+  let estado = semente >>> 0;
+  return () => {
+    estado = (Math.imul(estado, 1664525) + 1013904223) >>> 0;
+    return (estado + 0.5) / 2 ** 32;
+  };
+}
+
+/**
+ * Serie OHLC sintetica (passeio geometrico) terminando em `precoFinal`,
+ * apenas em dias de semana.
+ */
+function serieSintetica(
+  precoFinal: number,
+  volAnual: number,
+  semente: number,
+): BarraOhlc[] {
+  const aleatorio = gerador(semente);
+  const normal = () =>
+    Math.sqrt(-2 * Math.log(aleatorio())) * Math.cos(2 * Math.PI * aleatorio());
+  const volDiaria = volAnual / Math.sqrt(252);
+
+  const datas: Date[] = [];
+  const cursor = new Date(FIXTURE_END_DATE);
+  while (datas.length < FIXTURE_BARS) {
+    const dia = cursor.getDay();
+    if (dia !== 0 && dia !== 6) {
+      datas.unshift(new Date(cursor));
+    }
+    cursor.setDate(cursor.getDate() - 1);
+  }
+
+  // Retornos gerados de tras para frente a partir do preco final.
+  const barras: BarraOhlc[] = [];
+  let fechamento = precoFinal;
+  for (let i = datas.length - 1; i >= 0; i -= 1) {
+    const abertura = fechamento * Math.exp(-volDiaria * normal() * 0.8);
+    const extremo = Math.abs(normal()) * volDiaria * 0.5;
+    barras.unshift({
+      data: datas[i],
+      abertura,
+      maxima: Math.max(abertura, fechamento) * Math.exp(extremo),
+      minima: Math.min(abertura, fechamento) * Math.exp(-extremo),
+      fechamento,
+    });
+    fechamento = abertura * Math.exp(-volDiaria * normal() * 0.6);
+  }
+  return barras;
+}
+
+function ativo(
+  symbol: string,
+  name: string,
+  price: number,
+  volAnnual: number,
+  dividendYield: number,
+  semente: number,
+): MarketDataSet['assets'][number] {
+  return {
+    symbol,
+    name,
+    exchange: 'Fixture offline',
+    currency: 'BRL',
+    type: 'EQUITY',
+    price,
+    volAnnual,
+    dividendYield,
+    timestampISO: FIXTURE_END_DATE.toISOString(),
+    history: serieSintetica(price, volAnnual, semente),
+  };
+}
+
+/**
+ * Dados de mercado ficticios para uso offline: tres acoes com historico de
+ * ~1 ano e uma curva pre em base 252.
+ */
+export const FIXTURE_MARKET_DATA: MarketDataSet = {
+  assets: [
+    ativo('PETR4.SA', 'Petrobras PN', 36.5, 0.32, 0.12, 11),
+    ativo('VALE3.SA', 'Vale ON', 58.2, 0.28, 0.08, 23),
+    ativo('ITUB4.SA', 'Itau Unibanco PN', 34.8, 0.22, 0.06, 37),
+  ],
+  riskFreeCurve: [
+    { businessDays: 1, rate: 0.149 },
+    { businessDays: 21, rate: 0.1495 },
+    { businessDays: 63, rate: 0.1488 },
+    { businessDays: 126, rate: 0.1465 },
+    { businessDays: 252, rate: 0.142 },
+    { businessDays: 504, rate: 0.136 },
+    { businessDays: 756, rate: 0.1335 },
+    { businessDays: 1260, rate: 0.1325 },
+  ],
+};
@@ -0,0 +1,134 @@
+import { AssetDetails, AssetResult } from '../types';
+
+const MARKET_DATA_URL =
+  'https://raw.githubusercontent.com/iagomarcolino/Consultprice/main/data/marketdata.json';
+const MARKET_CACHE_TTL_MS = 5 * 60 * 1000;
+const EXCHANGE_LABEL = 'Dados GitHub';
+
+type MarketDataItem = {
+  symbol?: string;
+  name?: string;
+  price?: number;
+  vol_annual?: number;
+  dividend_yield?: number;
+};
+
+/** Ticker em maiusculas; sem sufixo de bolsa, assume B3 (.SA). */
+export function normalizeTicker(raw: string): string {
+  const ticker = raw.trim().toUpperCase();
+  if (!ticker) return '';
+  return ticker.includes('.') ? ticker : `${ticker}.SA`;
+}
+
+/** Texto de busca em minusculas, sem espacos nas pontas e com espacos simples. */
+export function normalizeText(raw: string): string {
+  return raw
+    .toLowerCase()
+    .trim()
+    .replace(/\s+/g, ' ');
+}
+
+const marketDataCache: {
+  data: MarketDataItem[] | null;
+  fetchedAt: number;
+} = {
+  data: null,
+  fetchedAt: 0,
+};
+
+async function loadMarketData(
+  signal?: AbortSignal,
+): Promise<MarketDataItem[]> {
+  if (
+    marketDataCache.data &&
+    Date.now() - marketDataCache.fetchedAt < MARKET_CACHE_TTL_MS
+  ) {
+    return marketDataCache.data;
+  }
+
+  const resp = await fetch(`${MARKET_DATA_URL}?t=${Date.now()}`, {
+    cache: 'no-store',
+    signal,
+  });
+  if (!resp.ok) {
+    throw new Error('HTTP error');
+  }
+
+  const json = await resp.json();
+  const data = Array.isArray(json?.data) ? json.data : [];
+  marketDataCache.data = data;
+  marketDataCache.fetchedAt = Date.now();
+  return data;
+}
+
+function getMatches(
+  data: MarketDataItem[],
+  rawTerm: string,
+): MarketDataItem[] {
+  const term = rawTerm.trim();
+  if (!term) return [];
+
+  const exactSymbol = normalizeTicker(term);
+  const partialSymbol = term.toUpperCase();
+  const normalizedName = normalizeText(term);
+
+  return data.filter((item) => {
+    const symbol =
+      typeof item?.symbol === 'string' ? item.symbol.toUpperCase() : '';
+    const name = typeof item?.name === 'string' ? item.name : '';
+    const symbolExactMatch = symbol !== '' && symbol === exactSymbol;
+    const symbolPartialMatch =
+      partialSymbol.length >= 2 && symbol.includes(partialSymbol);
+    const nameMatch =
+      normalizedName.length >= 2 &&
+      normalizeText(name).includes(normalizedName);
+    return symbolExactMatch || symbolPartialMatch || nameMatch;
+  });
+}
+
+/**
+ * Busca por ticker ou nome no marketdata.json publicado no GitHub.
+ */
+export async function searchAssetsGithub(
+  query: string,
+  signal?: AbortSignal,
+): Promise<AssetResult[]> {
+  const data = await loadMarketData(signal);
+  return getMatches(data, query)
+    .filter((item) => typeof item.symbol === 'string')
+    .map((item) => ({
+      symbol: item.symbol as string,
+      name: item.name ?? (item.symbol as string),
+      exchange: EXCHANGE_LABEL,
+      type: 'EQUITY',
+    }));
+}
+
+/**
+ * Detalhes de um simbolo do marketdata.json (preco, vol anual e dividend yield).
+ */
+export async function getAssetGithub(
+  symbol: string,
+  signal?: AbortSignal,
+): Promise<AssetDetails> {
+  const data = await loadMarketData(signal);
+  const wanted = symbol.toUpperCase();
+  const item = data.find(
+    (entry) =>
+      typeof entry?.symbol === 'string' && entry.symbol.toUpperCase() === wanted,
+  );
+  if (!item) {
+    throw new Error(`Ativo ${symbol} nao encontrado`);
+  }
+
+  return {
+    symbol: item.symbol ?? symbol,
+    name: item.name ?? symbol,
+    exchange: EXCHANGE_LABEL,
+    currency: 'BRL',
+    type: 'EQUITY',
+    price: item.price,
+    volAnnual: item.vol_annual,
+    dividendYield: item.dividend_yield,
+  };
+}
@@ -0,0 +1,256 @@
+import { AssetDetails, AssetResult, RiskFreeVertex } from '../types';
+import {
+  type BarraOhlc,
+  barrasDeRegistros,
+  estimarVolatilidades,
+  parseSerieOhlc,
+} from '../utils/historicalVolatility';
+import { FIXTURE_MARKET_DATA, type MarketDataSet } from './fixtureMarketData';
+import {
+  getAssetGithub,
+  normalizeText,
+  normalizeTicker,
+  searchAssetsGithub,
+} from './githubMarketData';
+import { getAssetYahoo, getHistoryYahoo, searchAssetsYahoo } from './yahooApi';
+
+export type { MarketDataSet } from './fixtureMarketData';
+
+/**
+ * Fonte de dados de mercado. Metodos que a fonte nao suporta rejeitam com
+ * um Error descritivo.
+ */
+export type MarketDataProvider = {
+  id: string;
+  label: string;
+  search: (query: string, signal?: AbortSignal) => Promise<AssetResult[]>;
+  quote: (symbol: string, signal?: AbortSignal) => Promise<AssetDetails>;
+  /** Serie diaria em ordem cronologica. */
+  history: (symbol: string, signal?: AbortSignal) => Promise<BarraOhlc[]>;
+  riskFreeCurve: (signal?: AbortSignal) => Promise<RiskFreeVertex[]>;
+};
+
+type Erro = { ok: false; error: string };
+
+function naoSuportado(label: string, recurso: string): () => Promise<never> {
+  return () => Promise.reject(new Error(`${label} nao fornece ${recurso}.`));
+}
+
+export const GITHUB_PROVIDER: MarketDataProvider = {
+  id: 'github',
+  label: 'GitHub (marketdata.json)',
+  search: searchAssetsGithub,
+  quote: getAssetGithub,
+  history: naoSuportado('GitHub (marketdata.json)', 'historico de precos'),
+  riskFreeCurve: naoSuportado('GitHub (marketdata.json)', 'curva de juros'),
+};
+
+/**
+ * So funciona no servidor de desenvolvimento: as chamadas passam pelo proxy do
+ * Vite (vite.config.mts), que nao existe no build de producao.
+ */
+export const YAHOO_PROVIDER: MarketDataProvider = {
+  id: 'yahoo',
+  label: 'Yahoo Finance',
+  search: searchAssetsYahoo,
+  quote: getAssetYahoo,
+  history: (symbol, signal) => getHistoryYahoo(symbol, signal),
+  riskFreeCurve: naoSuportado('Yahoo Finance', 'curva de juros'),
+};
+
+/**
+ * Provedor sobre um conjunto de dados ja carregado; nao faz rede.
+ */
+export function createMemoryProvider(
+  id: string,
+  label: string,
+  data: MarketDataSet,
+): MarketDataProvider {
+  const find = (symbol: string) => {
+    const wanted = symbol.trim().toUpperCase();
+    const asset = data.assets.find((item) => item.symbol.toUpperCase() === wanted);
+    if (!asset) {
+      throw new Error(`Ativo ${symbol} nao encontrado`);
+    }
+    return asset;
+  };
+
+  return {
+    id,
+    label,
+    search: async (query) => {
+      const term = normalizeText(query);
+      if (term.length < 2) return [];
+      const exact = normalizeTicker(term);
+      return data.assets
+        .filter(
+          (item) =>
+            item.symbol.toUpperCase() === exact ||
+            item.symbol.toUpperCase().includes(term.toUpperCase()) ||
+            normalizeText(item.name).includes(term),
+        )
+        .map(({ symbol, name, exchange, type }) => ({ symbol, name, exchange, type }));
+    },
+    quote: async (symbol) => {
+      const { history: _history, ...details } = find(symbol);
+      return details;
+    },
+    history: async (symbol) => {
+      const { history } = find(symbol);
+      if (!history || history.length === 0) {
+        throw new Error(`${label} nao tem historico para ${symbol}.`);
+      }
+      return history;
+    },
+    riskFreeCurve: async () => {
+      if (!data.riskFreeCurve || data.riskFreeCurve.length === 0) {
+        throw new Error(`${label} nao fornece curva de juros.`);
+      }
+      return data.riskFreeCurve;
+    },
+  };
+}
+
+export const FIXTURE_PROVIDER = createMemoryProvider(
+  'fixture',
+  'Dados ficticios (offline)',
+  FIXTURE_MARKET_DATA,
+);
+
+function numero(valor: unknown): number | undefined {
+  const n = typeof valor === 'string' ? Number(valor.replace(',', '.')) : valor;
+  return typeof n === 'number' && Number.isFinite(n) ? n : undefined;
+}
+
+function vertice(item: Record<string, unknown>): RiskFreeVertex | null {
+  const businessDays = numero(item.businessDays ?? item.dias_uteis ?? item.du);
+  const rate = numero(item.rate ?? item.taxa);
+  if (businessDays === undefined || businessDays <= 0 || rate === undefined) {
+    return null;
+  }
+  return { businessDays, rate };
+}
+
+function ativoDoJson(
+  item: Record<string, unknown>,
+  exchange: string,
+): MarketDataSet['assets'][number] | null {
+  if (typeof item.symbol !== 'string' || !item.symbol.trim()) {
+    return null;
+  }
+  const symbol = item.symbol.trim().toUpperCase();
+  const history = Array.isArray(item.history) ? barrasDeRegistros(item.history) : [];
+  const ultimo = history[history.length - 1];
+  return {
+    symbol,
+    name: typeof item.name === 'string' ? item.name : symbol,
+    exchange,
+    currency: typeof item.currency === 'string' ? item.currency : 'BRL',
+    type: typeof item.type === 'string' ? item.type : 'EQUITY',
+    price: numero(item.price) ?? ultimo?.fechamento,
+    volAnnual: numero(item.volAnnual ?? item.vol_annual),
+    dividendYield: numero(item.dividendYield ?? item.dividend_yield),
+    history,
+  };
+}
+
+/**
+ * Le um arquivo local: JSON com { assets | data: [...], riskFreeCurve | curve: [...] }
+ * (mesmos campos do marketdata.json, com `history` opcional por ativo) ou CSV OHLC
+ * de um unico ativo, cujo ticker vem do nome do arquivo.
+ */
+export function parseMarketDataFile(
+  text: string,
+  fileName: string,
+): { ok: true; data: MarketDataSet } | Erro {
+  const conteudo = text.trim();
+  const exchange = `Arquivo ${fileName}`;
+
+  if (conteudo.startsWith('{')) {
+    let json: Record<string, unknown>;
+    try {
+      json = JSON.parse(conteudo);
+    } catch {
+      return { ok: false, error: 'JSON invalido.' };
+    }
+    const lista = json.assets ?? json.data;
+    const curva = json.riskFreeCurve ?? json.curve;
+    const objetos = (valor: unknown) =>
+      (Array.isArray(valor) ? valor : []).filter(
+        (item): item is Record<string, unknown> => !!item && typeof item === 'object',
+      );
+    const assets = objetos(lista)
+      .map((item) => ativoDoJson(item, exchange))
+      .filter((item): item is NonNullable<typeof item> => item !== null);
+    const riskFreeCurve = objetos(curva)
+      .map(vertice)
+      .filter((item): item is RiskFreeVertex => item !== null)
+      .sort((a, b) => a.businessDays - b.businessDays);
+    if (assets.length === 0 && riskFreeCurve.length === 0) {
+      return { ok: false, error: 'Arquivo sem ativos nem curva de juros.' };
+    }
+    return { ok: true, data: { assets, riskFreeCurve } };
+  }
+
+  const serie = parseSerieOhlc(conteudo);
+  if (!serie.ok) {
+    return serie;
+  }
+  const symbol = fileName.replace(/\.[^.]*$/, '').trim().toUpperCase() || 'ARQUIVO';
+  const ultimo = serie.barras[serie.barras.length - 1];
+  return {
+    ok: true,
+    data: {
+      assets: [
+        {
+          symbol,
+          name: symbol,
+          exchange,
+          currency: 'BRL',
+          type: 'EQUITY',
+          price: ultimo.fechamento,
+          volAnnual: estimarVolatilidades(serie.barras, 252).closeToClose ?? undefined,
+          history: serie.barras,
+          timestampISO: ultimo.data.toISOString(),
+        },
+      ],
+    },
+  };
+}
+
+/**
+ * Provedor de um arquivo enviado pelo usuario; substitui o arquivo anterior.
+ */
+export function createFileProvider(
+  text: string,
+  fileName: string,
+): { ok: true; provider: MarketDataProvider } | Erro {
+  const parsed = parseMarketDataFile(text, fileName);
+  if (!parsed.ok) {
+    return parsed;
+  }
+  return {
+    ok: true,
+    provider: createMemoryProvider('arquivo', `Arquivo (${fileName})`, parsed.data),
+  };
+}
+
+const providers = new Map<string, MarketDataProvider>(
+  [
+    GITHUB_PROVIDER,
+    ...(import.meta.env.DEV ? [YAHOO_PROVIDER] : []),
+    FIXTURE_PROVIDER,
+  ].map((provider) => [provider.id, provider]),
+);
+
+export function registerProvider(provider: MarketDataProvider): void {
+  providers.set(provider.id, provider);
+}
+
+export function getProvider(id: string): MarketDataProvider | undefined {
+  return providers.get(id);
+}
+
+export function listProviders(): MarketDataProvider[] {
+  return Array.from(providers.values());
+}
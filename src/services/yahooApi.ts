@@ -0,0 +1,116 @@
+import { AssetDetails, AssetResult } from '../types';
+import { type BarraOhlc, barrasDeRegistros } from '../utils/historicalVolatility';
+
+// Rotas de proxy do Vite (vite.config.mts); o Yahoo nao libera CORS no navegador.
+// O proxy so existe em `vite dev`, por isso o provedor fica fora do build.
+const SEARCH_URL = '/yahoo/search';
+const QUOTE_URL = '/yahoo/quote';
+const CHART_URL = '/yahoo/chart';
+const SEARCH_LIMIT = 10;
+
+async function getJson(url: string, signal?: AbortSignal): Promise<any> {
+  const resp = await fetch(url, { signal });
+  if (!resp.ok) {
+    throw new Error(`HTTP ${resp.status}`);
+  }
+  return resp.json();
+}
+
+/**
+ * Autocomplete de ativos pela API de busca do Yahoo Finance.
+ */
+export async function searchAssetsYahoo(
+  query: string,
+  signal?: AbortSignal,
+): Promise<AssetResult[]> {
+  const term = query.trim();
+  if (!term) {
+    return [];
+  }
+
+  const params = new URLSearchParams({
+    q: term,
+    quotesCount: String(SEARCH_LIMIT),
+    newsCount: '0',
+  });
+  const data = await getJson(`${SEARCH_URL}?${params}`, signal);
+  const quotes = Array.isArray(data?.quotes) ? data.quotes : [];
+
+  return quotes
+    .filter((q: any) => typeof q?.symbol === 'string')
+    .map((q: any) => ({
+      symbol: q.symbol,
+      name: q.shortname ?? q.longname ?? q.symbol,
+      exchange: q.exchDisp ?? q.exchange ?? '',
+      type: q.typeDisp ?? q.quoteType ?? '',
+    }));
+}
+
+/**
+ * Cotacao atual de um simbolo pela API de quote do Yahoo Finance.
+ */
+export async function getAssetYahoo(
+  symbol: string,
+  signal?: AbortSignal,
+): Promise<AssetDetails> {
+  const data = await getJson(
+    `${QUOTE_URL}?symbols=${encodeURIComponent(symbol)}`,
+    signal,
+  );
+  const q = Array.isArray(data?.quoteResponse?.result)
+    ? data.quoteResponse.result[0]
+    : undefined;
+  if (!q) {
+    throw new Error(`Ativo ${symbol} nao encontrado`);
+  }
+
+  return {
+    symbol: q.symbol ?? symbol,
+    name: q.shortName ?? q.longName ?? q.symbol ?? symbol,
+    exchange: q.fullExchangeName ?? q.exchange ?? '',
+    currency: q.currency ?? '',
+    type: q.quoteType ?? '',
+    price: q.regularMarketPrice,
+    dayHigh: q.regularMarketDayHigh,
+    dayLow: q.regularMarketDayLow,
+    dividendYield:
+      typeof q.trailingAnnualDividendYield === 'number'
+        ? q.trailingAnnualDividendYield
+        : undefined,
+    timestampISO:
+      typeof q.regularMarketTime === 'number'
+        ? new Date(q.regularMarketTime * 1000).toISOString()
+        : undefined,
+  };
+}
+
+/**
+ * Serie diaria OHLC pela API de chart do Yahoo Finance (range no formato do
+ * Yahoo: 6mo, 1y, 2y...).
+ */
+export async function getHistoryYahoo(
+  symbol: string,
+  signal?: AbortSignal,
+  range = '1y',
+): Promise<BarraOhlc[]> {
+  const params = new URLSearchParams({ range, interval: '1d' });
+  const data = await getJson(
+    `${CHART_URL}/${encodeURIComponent(symbol)}?${params}`,
+    signal,
+  );
+  const chart = data?.chart?.result?.[0];
+  const timestamps: unknown[] = Array.isArray(chart?.timestamp) ? chart.timestamp : [];
+  const quote = chart?.indicators?.quote?.[0] ?? {};
+  const coluna = (nome: string, i: number) =>
+    Array.isArray(quote[nome]) ? quote[nome][i] : undefined;
+
+  return barrasDeRegistros(
+    timestamps.map((timestamp, i) => ({
+      date: timestamp,
+      open: coluna('open', i),
+      high: coluna('high', i),
+      low: coluna('low', i),
+      close: coluna('close', i),
+    })),
+  );
+}
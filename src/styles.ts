@@ -115,6 +115,32 @@ export const styles: Record<string, React.CSSProperties> = {
     minWidth: '200px',
     textAlign: 'center',
   },
+  modeRow: {
+    display: 'flex',
+    gap: '8px',
+    flexWrap: 'wrap',
+  },
+  modeButton: {
+    flex: 1,
+    backgroundColor: 'transparent',
+    color: '#cbd5e1',
+    fontSize: '14px',
+    padding: '10px',
+    border: '1px solid #334155',
+    borderRadius: 10,
+    cursor: 'pointer',
+  },
+  modeButtonActive: {
+    flex: 1,
+    backgroundColor: '#1e293b',
+    color: '#38bdf8',
+    fontWeight: 700,
+    fontSize: '14px',
+    padding: '10px',
+    border: '1px solid #38bdf8',
+    borderRadius: 10,
+    cursor: 'pointer',
+  },
   searchRow: {
     display: 'flex',
     gap: '8px',
@@ -161,6 +187,17 @@ export const styles: Record<string, React.CSSProperties> = {
     fontSize: '13px',
     marginBottom: 2,
   },
+  greekRow: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    gap: '12px',
+  },
+  greekValue: {
+    color: '#e2e8f0',
+    fontSize: '13px',
+    fontWeight: 600,
+    fontVariantNumeric: 'tabular-nums',
+  },
   searchInput: {
     minWidth: '240px',
   },
@@ -186,4 +223,33 @@ export const styles: Record<string, React.CSSProperties> = {
     color: '#cbd5e1',
     fontSize: '13px',
   },
+  tableWrapper: {
+    overflowX: 'auto',
+    marginTop: '8px',
+  },
+  table: {
+    width: '100%',
+    borderCollapse: 'collapse',
+    fontSize: '13px',
+    fontVariantNumeric: 'tabular-nums',
+  },
+  tableHeader: {
+    color: '#cbd5e1',
+    fontWeight: 600,
+    textAlign: 'right',
+    padding: '6px 8px',
+    borderBottom: '1px solid #334155',
+    whiteSpace: 'nowrap',
+  },
+  tableCell: {
+    color: '#e2e8f0',
+    textAlign: 'right',
+    padding: '6px 8px',
+    borderBottom: '1px solid #1e293b',
+    whiteSpace: 'nowrap',
+  },
+  tableRowAtm: {
+    backgroundColor: '#1e293b',
+    outline: '1px solid #38bdf8',
+  },
 };
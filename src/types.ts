@@ -14,5 +14,14 @@ export type AssetDetails = {
   price?: number;
   dayHigh?: number;
   dayLow?: number;
+  /** Vol anual historica, quando a fonte fornece. */
+  volAnnual?: number;
+  dividendYield?: number;
   timestampISO?: string;
 };
+
+/** Vertice da curva livre de risco: taxa anual exponencial base 252 (convencao DI). */
+export type RiskFreeVertex = {
+  businessDays: number;
+  rate: number;
+};
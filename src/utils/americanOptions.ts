@@ -0,0 +1,429 @@
+import {
+  blackScholesCall,
+  blackScholesPut,
+  calcularTempoEmAnos,
+  ensurePositive,
+  normalCdf,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+import type { TipoOpcao } from './impliedVolatility';
+
+export type MetodoAmericano = 'crr' | 'leisenReimer' | 'bjerksundStensland';
+
+type OpcoesAmericana = {
+  metodo?: MetodoAmericano;
+  /** Passos da arvore binomial (ignorado no Bjerksund-Stensland). */
+  passos?: number;
+};
+
+export type ResultadoAmericano = {
+  americana: number;
+  europeia: number;
+  /** Premio de exercicio antecipado: americana - europeia. */
+  premioExercicio: number;
+};
+
+const PASSOS_PADRAO = 200;
+
+/**
+ * Inversao de Peizer-Pratt (metodo 2) usada pela arvore de Leisen-Reimer.
+ */
+function peizerPratt(z: number, n: number): number {
+  const termo = z / (n + 1 / 3 + 0.1 / (n + 1));
+  const raiz = Math.sqrt(1 - Math.exp(-termo * termo * (n + 1 / 6)));
+  return 0.5 + Math.sign(z) * 0.5 * raiz;
+}
+
+/**
+ * Arvore binomial com exercicio antecipado.
+ * b e o custo de carregamento (r - q); CRR usa u = e^{sigma sqrt(dt)},
+ * Leisen-Reimer ajusta u, d e p para convergir em O(1/n^2) (n impar).
+ */
+function arvoreBinomial(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  T: number,
+  r: number,
+  b: number,
+  sigma: number,
+  passos: number,
+  metodo: 'crr' | 'leisenReimer',
+): number {
+  const n =
+    metodo === 'leisenReimer'
+      ? Math.max(3, Math.floor(passos) | 1)
+      : Math.max(1, Math.floor(passos));
+  const dt = T / n;
+  const crescimento = Math.exp(b * dt);
+  const desconto = Math.exp(-r * dt);
+
+  let u: number;
+  let d: number;
+  let pu: number;
+  if (metodo === 'leisenReimer') {
+    const sqrtT = Math.sqrt(T);
+    const d1 = (Math.log(S / K) + (b + 0.5 * sigma * sigma) * T) / (sigma * sqrtT);
+    const d2 = d1 - sigma * sqrtT;
+    pu = peizerPratt(d2, n);
+    const pLinha = peizerPratt(d1, n);
+    u = (crescimento * pLinha) / pu;
+    d = (crescimento - pu * u) / (1 - pu);
+  } else {
+    u = Math.exp(sigma * Math.sqrt(dt));
+    d = 1 / u;
+    pu = (crescimento - d) / (u - d);
+  }
+  const pd = 1 - pu;
+
+  const payoff = (spot: number) =>
+    tipo === 'call' ? Math.max(spot - K, 0) : Math.max(K - spot, 0);
+
+  // Spot do no i no passo k: S d^k (u/d)^i, por recorrencia a partir do no mais baixo.
+  const razao = u / d;
+  const valores = new Float64Array(n + 1);
+  let spot = S * d ** n;
+  for (let i = 0; i <= n; i += 1) {
+    valores[i] = payoff(spot);
+    spot *= razao;
+  }
+
+  for (let passo = n - 1; passo >= 0; passo -= 1) {
+    spot = S * d ** passo;
+    for (let i = 0; i <= passo; i += 1) {
+      const continuacao = desconto * (pu * valores[i + 1] + pd * valores[i]);
+      valores[i] = Math.max(continuacao, payoff(spot));
+      spot *= razao;
+    }
+  }
+
+  return valores[0];
+}
+
+// Nos e pesos de Gauss-Legendre (metade simetrica) para 6, 12 e 20 pontos.
+const GL_X = [
+  [-0.9324695142031522, -0.6612093864662647, -0.238619186083197],
+  [
+    -0.9815606342467191, -0.904117256370475, -0.769902674194305,
+    -0.5873179542866171, -0.3678314989981802, -0.1252334085114692,
+  ],
+  [
+    -0.9931285991850949, -0.9639719272779138, -0.9122344282513259,
+    -0.8391169718222188, -0.7463319064601508, -0.636053680726515,
+    -0.5108670019508271, -0.3737060887154196, -0.2277858511416451,
+    -0.07652652113349733,
+  ],
+];
+const GL_W = [
+  [0.1713244923791705, 0.3607615730481384, 0.4679139345726904],
+  [
+    0.04717533638651177, 0.1069393259953183, 0.1600783285433464,
+    0.2031674267230659, 0.2334925365383547, 0.2491470458134029,
+  ],
+  [
+    0.01761400713915212, 0.04060142980038694, 0.06267204833410906,
+    0.08327674157670475, 0.1019301198172404, 0.1181945319615184,
+    0.1316886384491766, 0.1420961093183821, 0.1491729864726037,
+    0.1527533871307259,
+  ],
+];
+
+/**
+ * CDF normal bivariada M(a, b; rho) pelo algoritmo de Genz (2004).
+ */
+function normalBivariadaCdf(a: number, b: number, rho: number): number {
+  // Genz calcula P(X > h, Y > k); M(a, b) = P(X > -a, Y > -b).
+  const h = -a;
+  let k = -b;
+  let hk = h * k;
+  const absR = Math.abs(rho);
+  const ng = absR < 0.3 ? 0 : absR < 0.75 ? 1 : 2;
+  const x = GL_X[ng];
+  const w = GL_W[ng];
+  let bvn = 0;
+
+  if (absR < 0.925) {
+    const hs = (h * h + k * k) / 2;
+    const asr = Math.asin(rho);
+    for (let i = 0; i < x.length; i += 1) {
+      let sn = Math.sin((asr * (1 - x[i])) / 2);
+      bvn += w[i] * Math.exp((sn * hk - hs) / (1 - sn * sn));
+      sn = Math.sin((asr * (1 + x[i])) / 2);
+      bvn += w[i] * Math.exp((sn * hk - hs) / (1 - sn * sn));
+    }
+    return (bvn * asr) / (4 * Math.PI) + normalCdf(-h) * normalCdf(-k);
+  }
+
+  if (rho < 0) {
+    k = -k;
+    hk = -hk;
+  }
+  if (absR < 1) {
+    const as = (1 - rho) * (1 + rho);
+    let aa = Math.sqrt(as);
+    const bs = (h - k) ** 2;
+    const c = (4 - hk) / 8;
+    const d = (12 - hk) / 16;
+    bvn =
+      aa *
+      Math.exp(-(bs / as + hk) / 2) *
+      (1 - (c * (bs - as) * (1 - (d * bs) / 5)) / 3 + (c * d * as * as) / 5);
+    if (hk > -160) {
+      const bb = Math.sqrt(bs);
+      bvn -=
+        Math.exp(-hk / 2) *
+        Math.sqrt(2 * Math.PI) *
+        normalCdf(-bb / aa) *
+        bb *
+        (1 - (c * bs * (1 - (d * bs) / 5)) / 3);
+    }
+    aa /= 2;
+    for (let i = 0; i < x.length; i += 1) {
+      for (const sinal of [-1, 1]) {
+        const xs = (aa * (sinal * x[i] + 1)) ** 2;
+        const rs = Math.sqrt(1 - xs);
+        bvn +=
+          aa *
+          w[i] *
+          (Math.exp(-bs / (2 * xs) - hk / (1 + rs)) / rs -
+            Math.exp(-(bs / xs + hk) / 2) * (1 + c * xs * (1 + d * xs)));
+      }
+    }
+    bvn = -bvn / (2 * Math.PI);
+  }
+
+  if (rho > 0) {
+    return bvn + normalCdf(-Math.max(h, k));
+  }
+  return -bvn + Math.max(0, normalCdf(-h) - normalCdf(-k));
+}
+
+function phiBs(
+  S: number,
+  T: number,
+  gamma: number,
+  h: number,
+  I: number,
+  r: number,
+  b: number,
+  v: number,
+): number {
+  const v2 = v * v;
+  const lambda = (-r + gamma * b + 0.5 * gamma * (gamma - 1) * v2) * T;
+  const sqrtT = Math.sqrt(T);
+  const d = -(Math.log(S / h) + (b + (gamma - 0.5) * v2) * T) / (v * sqrtT);
+  const kappa = (2 * b) / v2 + 2 * gamma - 1;
+  return (
+    Math.exp(lambda) *
+    S ** gamma *
+    (normalCdf(d) -
+      (I / S) ** kappa * normalCdf(d - (2 * Math.log(I / S)) / (v * sqrtT)))
+  );
+}
+
+function ksiBs(
+  S: number,
+  T2: number,
+  gamma: number,
+  h: number,
+  I2: number,
+  I1: number,
+  t1: number,
+  r: number,
+  b: number,
+  v: number,
+): number {
+  const v2 = v * v;
+  const drift = b + (gamma - 0.5) * v2;
+  const sqrtT1 = Math.sqrt(t1);
+  const sqrtT2 = Math.sqrt(T2);
+  const e1 = (Math.log(S / I1) + drift * t1) / (v * sqrtT1);
+  const e2 = (Math.log((I2 * I2) / (S * I1)) + drift * t1) / (v * sqrtT1);
+  const e3 = (Math.log(S / I1) - drift * t1) / (v * sqrtT1);
+  const e4 = (Math.log((I2 * I2) / (S * I1)) - drift * t1) / (v * sqrtT1);
+  const f1 = (Math.log(S / h) + drift * T2) / (v * sqrtT2);
+  const f2 = (Math.log((I2 * I2) / (S * h)) + drift * T2) / (v * sqrtT2);
+  const f3 = (Math.log((I1 * I1) / (S * h)) + drift * T2) / (v * sqrtT2);
+  const f4 = (Math.log((S * I1 * I1) / (h * I2 * I2)) + drift * T2) / (v * sqrtT2);
+  const rho = Math.sqrt(t1 / T2);
+  const lambda = -r + gamma * b + 0.5 * gamma * (gamma - 1) * v2;
+  const kappa = (2 * b) / v2 + 2 * gamma - 1;
+
+  return (
+    Math.exp(lambda * T2) *
+    S ** gamma *
+    (normalBivariadaCdf(-e1, -f1, rho) -
+      (I2 / S) ** kappa * normalBivariadaCdf(-e2, -f2, rho) -
+      (I1 / S) ** kappa * normalBivariadaCdf(-e3, -f3, -rho) +
+      (I1 / I2) ** kappa * normalBivariadaCdf(-e4, -f4, -rho))
+  );
+}
+
+function europeiaCarry(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  T: number,
+  r: number,
+  b: number,
+  v: number,
+): number {
+  const sqrtT = Math.sqrt(T);
+  const d1 = (Math.log(S / K) + (b + 0.5 * v * v) * T) / (v * sqrtT);
+  const d2 = d1 - v * sqrtT;
+  const fator = Math.exp((b - r) * T);
+  return tipo === 'call'
+    ? S * fator * normalCdf(d1) - K * Math.exp(-r * T) * normalCdf(d2)
+    : K * Math.exp(-r * T) * normalCdf(-d2) - S * fator * normalCdf(-d1);
+}
+
+/**
+ * Call americana de Bjerksund-Stensland (2002), com duas fronteiras de exercicio.
+ */
+function bjerksundStenslandCall(
+  S: number,
+  K: number,
+  T: number,
+  r: number,
+  b: number,
+  v: number,
+): number {
+  // Sem rendimento do ativo nao compensa exercer a call antes do vencimento.
+  if (b >= r) {
+    return europeiaCarry('call', S, K, T, r, b, v);
+  }
+
+  const v2 = v * v;
+  const t1 = 0.5 * (Math.sqrt(5) - 1) * T;
+  const beta =
+    0.5 - b / v2 + Math.sqrt((b / v2 - 0.5) ** 2 + (2 * r) / v2);
+  const bInfinito = (beta / (beta - 1)) * K;
+  const b0 = Math.max(K, (r / (r - b)) * K);
+  const h1 = (-(b * t1 + 2 * v * Math.sqrt(t1)) * K * K) / ((bInfinito - b0) * b0);
+  const h2 = (-(b * T + 2 * v * Math.sqrt(T)) * K * K) / ((bInfinito - b0) * b0);
+  const i1 = b0 + (bInfinito - b0) * (1 - Math.exp(h1));
+  const i2 = b0 + (bInfinito - b0) * (1 - Math.exp(h2));
+  const alfa1 = (i1 - K) * i1 ** -beta;
+  const alfa2 = (i2 - K) * i2 ** -beta;
+
+  if (S >= i2) {
+    return S - K;
+  }
+
+  const valor =
+    alfa2 * S ** beta -
+    alfa2 * phiBs(S, t1, beta, i2, i2, r, b, v) +
+    phiBs(S, t1, 1, i2, i2, r, b, v) -
+    phiBs(S, t1, 1, i1, i2, r, b, v) -
+    K * phiBs(S, t1, 0, i2, i2, r, b, v) +
+    K * phiBs(S, t1, 0, i1, i2, r, b, v) +
+    alfa1 * phiBs(S, t1, beta, i1, i2, r, b, v) -
+    alfa1 * ksiBs(S, T, beta, i1, i2, i1, t1, r, b, v) +
+    ksiBs(S, T, 1, i1, i2, i1, t1, r, b, v) -
+    ksiBs(S, T, 1, K, i2, i1, t1, r, b, v) -
+    K * ksiBs(S, T, 0, i1, i2, i1, t1, r, b, v) +
+    K * ksiBs(S, T, 0, K, i2, i1, t1, r, b, v);
+
+  // A aproximacao nunca deve ficar abaixo da europeia nem do intrinseco.
+  return Math.max(valor, europeiaCarry('call', S, K, T, r, b, v), S - K);
+}
+
+/**
+ * Bjerksund-Stensland 2002; a put usa a simetria P(S, K, r, b) = C(K, S, r - b, -b).
+ */
+function bjerksundStensland(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  T: number,
+  r: number,
+  b: number,
+  v: number,
+): number {
+  return tipo === 'call'
+    ? bjerksundStenslandCall(S, K, T, r, b, v)
+    : bjerksundStenslandCall(K, S, T, r - b, -b, v);
+}
+
+/**
+ * Preco de opcao americana (exercicio a qualquer momento ate o vencimento).
+ * O prazo segue a mesma convencao de dias uteis/252 dos precificadores europeus.
+ */
+export function precoAmericano(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  { metodo = 'bjerksundStensland', passos = PASSOS_PADRAO }: OpcoesAmericana = {},
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return tipo === 'call' ? Math.max(S - K, 0) : Math.max(K - S, 0);
+  }
+
+  const safeSigma = ensurePositive(sigma);
+  const b = r - (q + taxaAluguel);
+
+  if (metodo === 'bjerksundStensland') {
+    return bjerksundStensland(tipo, S, K, T, r, b, safeSigma);
+  }
+  return arvoreBinomial(tipo, S, K, T, r, b, safeSigma, passos, metodo);
+}
+
+/**
+ * Preco americano junto com o europeu equivalente e o premio de exercicio antecipado.
+ */
+export function calcularAmericana(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  opcoes: OpcoesAmericana = {},
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoAmericano {
+  const precificarEuropeia = tipo === 'call' ? blackScholesCall : blackScholesPut;
+  const europeia = precificarEuropeia(
+    S,
+    K,
+    r,
+    sigma,
+    dataAtual,
+    dataVencimento,
+    q,
+    taxaAluguel,
+    calendario,
+  );
+  const americana = Math.max(
+    precoAmericano(
+      tipo,
+      S,
+      K,
+      r,
+      sigma,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      opcoes,
+      calendario,
+    ),
+    europeia,
+  );
+
+  return {
+    americana,
+    europeia,
+    premioExercicio: americana - europeia,
+  };
+}
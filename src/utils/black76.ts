@@ -0,0 +1,121 @@
+import {
+  BUSINESS_DAYS_IN_YEAR,
+  CALENDAR_DAYS_IN_YEAR,
+  type ResultadoGregas,
+  calcularTempoEmAnos,
+  ensurePositive,
+  normalCdf,
+  normalPdf,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+
+export type ResultadoGregasBlack76 = Pick<
+  ResultadoGregas,
+  | 'deltaCall'
+  | 'deltaPut'
+  | 'gama'
+  | 'vega'
+  | 'thetaCall'
+  | 'thetaPut'
+  | 'thetaCallCorrido'
+  | 'thetaPutCorrido'
+  | 'rhoCall'
+  | 'rhoPut'
+>;
+
+function calcularD1D2(
+  F: number,
+  K: number,
+  sigma: number,
+  T: number,
+): { d1: number; d2: number } {
+  const sqrtT = Math.sqrt(T);
+  const d1 = (Math.log(F / K) + 0.5 * sigma * sigma * T) / (sigma * sqrtT);
+  return { d1, d2: d1 - sigma * sqrtT };
+}
+
+/**
+ * Black-76 para call europeia sobre futuro (DOL, IND, DI1...).
+ * F = preco do futuro; o premio e descontado a r.
+ */
+export function black76Call(
+  F: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return Math.max(F - K, 0);
+  }
+
+  const { d1, d2 } = calcularD1D2(F, K, ensurePositive(sigma), T);
+  return Math.exp(-r * T) * (F * normalCdf(d1) - K * normalCdf(d2));
+}
+
+/**
+ * Black-76 para put europeia sobre futuro.
+ */
+export function black76Put(
+  F: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return Math.max(K - F, 0);
+  }
+
+  const { d1, d2 } = calcularD1D2(F, K, ensurePositive(sigma), T);
+  return Math.exp(-r * T) * (K * normalCdf(-d2) - F * normalCdf(-d1));
+}
+
+/**
+ * Gregas do Black-76, nas mesmas unidades de calculaGregas
+ * (vega e rho por ponto percentual, theta por dia).
+ */
+export function calculaGregasBlack76(
+  F: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoGregasBlack76 {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+
+  const safeSigma = ensurePositive(sigma);
+  const sqrtT = Math.sqrt(T);
+  const { d1, d2 } = calcularD1D2(F, K, safeSigma, T);
+  const desconto = Math.exp(-r * T);
+  const phid1 = normalPdf(d1);
+
+  const call = desconto * (F * normalCdf(d1) - K * normalCdf(d2));
+  const put = desconto * (K * normalCdf(-d2) - F * normalCdf(-d1));
+
+  const decaimento = (-F * desconto * phid1 * safeSigma) / (2 * sqrtT);
+  const thetaCallAnual = decaimento + r * call;
+  const thetaPutAnual = decaimento + r * put;
+
+  return {
+    deltaCall: desconto * normalCdf(d1),
+    deltaPut: -desconto * normalCdf(-d1),
+    gama: (desconto * phid1) / (F * safeSigma * sqrtT),
+    vega: (F * desconto * phid1 * sqrtT) / 100,
+    thetaCall: thetaCallAnual / BUSINESS_DAYS_IN_YEAR,
+    thetaPut: thetaPutAnual / BUSINESS_DAYS_IN_YEAR,
+    thetaCallCorrido: thetaCallAnual / CALENDAR_DAYS_IN_YEAR,
+    thetaPutCorrido: thetaPutAnual / CALENDAR_DAYS_IN_YEAR,
+    // F nao depende de r, so o desconto do premio.
+    rhoCall: (-T * call) / 100,
+    rhoPut: (-T * put) / 100,
+  };
+}
@@ -1,37 +1,129 @@
-const BUSINESS_DAYS_IN_YEAR = 252;
+import {
+  type CalendarioFeriados,
+  type Feriado,
+  CALENDARIO_B3,
+  chaveDataUTC,
+  feriadosPorData,
+} from './holidayCalendar';
+
+export const BUSINESS_DAYS_IN_YEAR = 252;
+
+// Coeficientes das aproximacoes racionais de Cody (1969), como no pnorm do R.
+const CODY_A = [
+  2.2352520354606839287, 161.02823106855587881, 1067.6894854603709582,
+  18154.981253343561249, 0.065682337918207449113,
+];
+const CODY_B = [
+  47.20258190468824187, 976.09855173777669322, 10260.932208618978205,
+  45507.789335026729956,
+];
+const CODY_C = [
+  0.39894151208813466764, 8.8831497943883759412, 93.506656132177855979,
+  597.27027639480026226, 2494.5375852903726711, 6848.1904505362823326,
+  11602.651437647350124, 9842.7148383839780218, 1.0765576773720192317e-8,
+];
+const CODY_D = [
+  22.266688044328115691, 235.38790178262499861, 1519.377599407554805,
+  6485.558298266760755, 18615.571640885098091, 34900.952721145977266,
+  38912.003286093271411, 19685.429676859990727,
+];
+const CODY_P = [
+  0.21589853405795699, 0.1274011611602473639, 0.022235277870649807,
+  0.001421619193227893466, 2.9112874951168792e-5, 0.02307344176494017303,
+];
+const CODY_Q = [
+  1.28426009614491121, 0.468238212480865118, 0.0659881378689285515,
+  0.00378239633202758244, 7.29751555083966205e-5,
+];
+const UM_SOBRE_RAIZ_2PI = 0.398942280401432677939946059934;
 
 /**
- * Funcao erro aproximada (Abramowitz & Stegun 7.1.26).
- * Evita dependencias externas para a CDF da normal padrao.
+ * Cauda inferior N(-y), y >= 0.67448975, pelo algoritmo de Cody.
+ * Mantem precisao relativa de dupla mesmo para caudas como N(-30).
  */
-function erf(x: number): number {
-  const sign = x < 0 ? -1 : 1;
-  const absX = Math.abs(x);
-  const a1 = 0.254829592;
-  const a2 = -0.284496736;
-  const a3 = 1.421413741;
-  const a4 = -1.453152027;
-  const a5 = 1.061405429;
-  const p = 0.3275911;
-
-  const t = 1 / (1 + p * absX);
-  const y =
-    1 -
-    (((((a5 * t + a4) * t + a3) * t + a2) * t + a1) * t) *
-    Math.exp(-absX * absX);
+function caudaNormal(y: number): number {
+  let temp: number;
+  if (y <= Math.sqrt(32)) {
+    let xnum = CODY_C[8] * y;
+    let xden = y;
+    for (let i = 0; i < 7; i += 1) {
+      xnum = (xnum + CODY_C[i]) * y;
+      xden = (xden + CODY_D[i]) * y;
+    }
+    temp = (xnum + CODY_C[7]) / (xden + CODY_D[7]);
+  } else {
+    const xsq = 1 / (y * y);
+    let xnum = CODY_P[5] * xsq;
+    let xden = xsq;
+    for (let i = 0; i < 4; i += 1) {
+      xnum = (xnum + CODY_P[i]) * xsq;
+      xden = (xden + CODY_Q[i]) * xsq;
+    }
+    temp = (xsq * (xnum + CODY_P[4])) / (xden + CODY_Q[4]);
+    temp = (UM_SOBRE_RAIZ_2PI - temp) / y;
+  }
 
-  return sign * y;
+  // Divide exp(-y^2/2) em duas partes para evitar cancelamento em y^2.
+  const yArred = Math.trunc(y * 16) / 16;
+  const delta = (y - yArred) * (y + yArred);
+  return Math.exp(-0.5 * yArred * yArred) * Math.exp(-0.5 * delta) * temp;
 }
 
+// Coeficientes de Acklam para o chute inicial da inversa.
+const ACKLAM_A = [
+  -3.969683028665376e1, 2.209460984245205e2, -2.759285104469687e2,
+  1.38357751867269e2, -3.066479806614716e1, 2.506628277459239,
+];
+const ACKLAM_B = [
+  -5.447609879822406e1, 1.615858368580409e2, -1.556989798598866e2,
+  6.680131188771972e1, -1.328068155288572e1,
+];
+const ACKLAM_C = [
+  -7.784894002430293e-3, -3.223964580411365e-1, -2.400758277161838,
+  -2.549732539343734, 4.374664141464968, 2.938163982698783,
+];
+const ACKLAM_D = [
+  7.784695709041462e-3, 3.224671290700398e-1, 2.445134137142996,
+  3.754408661907416,
+];
+const ACKLAM_P_BAIXO = 0.02425;
+
+function acklam(p: number): number {
+  const [a1, a2, a3, a4, a5, a6] = ACKLAM_A;
+  const [b1, b2, b3, b4, b5] = ACKLAM_B;
+  const [c1, c2, c3, c4, c5, c6] = ACKLAM_C;
+  const [d1, d2, d3, d4] = ACKLAM_D;
+
+  if (p < ACKLAM_P_BAIXO) {
+    const q = Math.sqrt(-2 * Math.log(p));
+    return (
+      (((((c1 * q + c2) * q + c3) * q + c4) * q + c5) * q + c6) /
+      ((((d1 * q + d2) * q + d3) * q + d4) * q + 1)
+    );
+  }
+  if (p > 1 - ACKLAM_P_BAIXO) {
+    const q = Math.sqrt(-2 * Math.log(1 - p));
+    return -(
+      (((((c1 * q + c2) * q + c3) * q + c4) * q + c5) * q + c6) /
+      ((((d1 * q + d2) * q + d3) * q + d4) * q + 1)
+    );
+  }
+
+  const q = p - 0.5;
+  const r = q * q;
+  return (
+    ((((((a1 * r + a2) * r + a3) * r + a4) * r + a5) * r + a6) * q) /
+    (((((b1 * r + b2) * r + b3) * r + b4) * r + b5) * r + 1)
+  );
+}
 
 // Alias semantico para aderir a notacao N(x).
 export const N = normalCdf;
 
-/**
- * Conta dias uteis (segunda a sexta) entre duas datas.
- * Datas sao normalizadas para meia-noite para evitar problemas de fuso horario.
-*/
-export function calcularDiasUteis(dataAtual: Date, dataVencimento: Date): number {
+function normalizarPeriodo(
+  dataAtual: Date,
+  dataVencimento: Date,
+): { inicio: Date; fim: Date } {
   const inicio = new Date(
     Date.UTC(
       dataAtual.getFullYear(),
@@ -46,23 +138,71 @@ export function calcularDiasUteis(dataAtual: Date, dataVencimento: Date): number
       dataVencimento.getDate(),
     ),
   );
+  return { inicio, fim };
+}
+
+/**
+ * Percorre [dataAtual, dataVencimento) contando dias uteis e
+ * registrando os feriados em dia de semana que foram pulados.
+ */
+function percorrerDiasUteis(
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados,
+): { dias: number; feriados: Feriado[] } {
+  const { inicio, fim } = normalizarPeriodo(dataAtual, dataVencimento);
 
   if (fim <= inicio) {
-    return 0;
+    return { dias: 0, feriados: [] };
   }
 
+  const indice = feriadosPorData(
+    calendario,
+    inicio.getUTCFullYear(),
+    fim.getUTCFullYear(),
+  );
+  const feriados: Feriado[] = [];
   let dias = 0;
   const cursor = new Date(inicio);
 
   while (cursor < fim) {
     const diaSemana = cursor.getUTCDay(); // 0 domingo, 6 sabado
     if (diaSemana !== 0 && diaSemana !== 6) {
-      dias += 1;
+      const feriado = indice.get(chaveDataUTC(cursor));
+      if (feriado) {
+        feriados.push(feriado);
+      } else {
+        dias += 1;
+      }
     }
     cursor.setUTCDate(cursor.getUTCDate() + 1);
   }
 
-  return dias;
+  return { dias, feriados };
+}
+
+/**
+ * Conta dias uteis (segunda a sexta, exceto feriados do calendario) entre duas datas.
+ * Datas sao normalizadas para meia-noite para evitar problemas de fuso horario.
+ * Sem calendario explicito usa o da B3.
+*/
+export function calcularDiasUteis(
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  return percorrerDiasUteis(dataAtual, dataVencimento, calendario).dias;
+}
+
+/**
+ * Feriados em dia de semana que foram desconsiderados na contagem de dias uteis.
+ */
+export function listarFeriadosNoPeriodo(
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): Feriado[] {
+  return percorrerDiasUteis(dataAtual, dataVencimento, calendario).feriados;
 }
 
 /**
@@ -71,38 +211,122 @@ export function calcularDiasUteis(dataAtual: Date, dataVencimento: Date): number
 export function calcularTempoEmAnos(
   dataAtual: Date,
   dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
 ): number {
-  const diasUteis = calcularDiasUteis(dataAtual, dataVencimento);
+  const diasUteis = calcularDiasUteis(dataAtual, dataVencimento, calendario);
   return diasUteis / BUSINESS_DAYS_IN_YEAR;
 }
 
-function ensurePositive(value: number, fallback = 1e-12): number {
+/** Troca valores nao positivos por um piso, evitando divisao por zero nas formulas. */
+export function ensurePositive(value: number, fallback = 1e-12): number {
   return value > 0 ? value : fallback;
 }
 
 
 /**
- * CDF da normal padrao N(x) usando erf (precisao ~1e-7).
+ * CDF da normal padrao N(x) em precisao dupla (algoritmo de Cody).
+ * Erro relativo ~1e-15, inclusive nas caudas.
  */
 export function normalCdf(x: number): number {
-  return 0.5 * (1 + erf(x / Math.SQRT2));
+  if (Number.isNaN(x)) {
+    return NaN;
+  }
+
+  const y = Math.abs(x);
+  // Alem de |x| = 38.5 a cauda nao cabe em double (inclui +-Infinity, onde a
+  // reducao de Cody daria Inf - Inf); devolve 0 ou 1 como o pnorm do R.
+  if (y > 38.5) {
+    return x > 0 ? 1 : 0;
+  }
+  if (y <= 0.67448975) {
+    let xnum = 0;
+    let xden = 0;
+    if (y > 1e-300) {
+      const xsq = x * x;
+      xnum = CODY_A[4] * xsq;
+      xden = xsq;
+      for (let i = 0; i < 3; i += 1) {
+        xnum = (xnum + CODY_A[i]) * xsq;
+        xden = (xden + CODY_B[i]) * xsq;
+      }
+    }
+    return 0.5 + (x * (xnum + CODY_A[3])) / (xden + CODY_B[3]);
+  }
+
+  const cauda = caudaNormal(y);
+  return x > 0 ? 1 - cauda : cauda;
+}
+
+/**
+ * Inversa da CDF normal: chute de Acklam (~1e-9) refinado por um passo de Halley
+ * sobre normalCdf, o que leva o erro a precisao de maquina.
+ */
+export function normalCdfInversa(p: number): number {
+  if (Number.isNaN(p) || p < 0 || p > 1) {
+    return NaN;
+  }
+  if (p === 0) {
+    return -Infinity;
+  }
+  if (p === 1) {
+    return Infinity;
+  }
+
+  // Trabalha na cauda inferior para preservar a precisao relativa.
+  if (p > 0.5) {
+    return -normalCdfInversa(1 - p);
+  }
+
+  const x = acklam(p);
+  const e = normalCdf(x) - p;
+  const u = e * Math.sqrt(2 * Math.PI) * Math.exp(0.5 * x * x);
+  return x - u / (1 + 0.5 * x * u);
 }
 
 export function normalPdf(x: number): number {
   return (1.0 / Math.sqrt(2 * Math.PI)) * Math.exp(-0.5 * x * x);
 }
 
+/**
+ * Rendimento continuo total do ativo: dividend yield q mais taxa de aluguel
+ * (borrow/lease), ambos anuais em decimal. Reduz o forward como um dividendo.
+ */
+function rendimentoTotal(q: number, taxaAluguel: number): number {
+  const total = q + taxaAluguel;
+  return Number.isFinite(total) ? total : 0;
+}
+
 // Cria objeto para exportar todas juntas
 
-interface ResultadoGregas {
+export interface ResultadoGregas {
   deltaCall: number;
   deltaPut: number;
+  /** Variacao do preco para +1 ponto percentual de volatilidade. */
   vega: number;
   gama: number;
-
+  /** Theta por dia util (ano de 252 dias). */
+  thetaCall: number;
+  thetaPut: number;
+  /** Theta por dia corrido (ano de 365 dias). */
+  thetaCallCorrido: number;
+  thetaPutCorrido: number;
+  /** Variacao do preco para +1 ponto percentual na taxa r. */
+  rhoCall: number;
+  rhoPut: number;
+  /** dDelta/dSigma para +1 ponto percentual de volatilidade. */
+  vanna: number;
+  /** dVega/dSigma (vomma), vega e sigma em pontos percentuais. */
+  volga: number;
+  /** Decaimento do delta por dia util. */
+  charmCall: number;
+  charmPut: number;
+  /** dGama/dS. */
+  speed: number;
 }
 
-// calcula as 3 gregas (Delta, Vega, Gama)
+export const CALENDAR_DAYS_IN_YEAR = 365;
+
+// calcula as gregas de primeira e segunda ordem (Merton, com rendimento q)
 export function calculaGregas(
   S: number,
   K: number,
@@ -110,28 +334,68 @@ export function calculaGregas(
   sigma: number,
   dataAtual: Date,
   dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
 ): ResultadoGregas {
 
-  const T = calcularTempoEmAnos(dataAtual, dataVencimento);
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  const y = rendimentoTotal(q, taxaAluguel);
 
   const safeSigma = ensurePositive(sigma);
   const sqrtT = Math.sqrt(T);
   const d1 =
-    (Math.log(S / K) + (r + 0.5 * safeSigma * safeSigma) * T) /
+    (Math.log(S / K) + (r - y + 0.5 * safeSigma * safeSigma) * T) /
     (safeSigma * sqrtT);
+  const d2 = d1 - safeSigma * sqrtT;
 
+  const fatorY = Math.exp(-y * T);
   const phid1 = normalPdf(d1);
-  const deltaCall = normalCdf(d1);
-  const deltaPut = deltaCall - 1;
-
-  const vega = S * phid1 * sqrtT;
-  const gama = phid1 / (S * safeSigma * sqrtT);
+  const deltaCall = fatorY * normalCdf(d1);
+  const deltaPut = fatorY * (normalCdf(d1) - 1);
+
+  const vega = S * fatorY * phid1 * sqrtT;
+  const gama = (fatorY * phid1) / (S * safeSigma * sqrtT);
+
+  // Theta e charm anuais; convertidos por dia abaixo.
+  const desconto = K * Math.exp(-r * T);
+  const decaimento = (-S * fatorY * phid1 * safeSigma) / (2 * sqrtT);
+  const thetaCallAnual =
+    decaimento -
+    r * desconto * normalCdf(d2) +
+    y * S * fatorY * normalCdf(d1);
+  const thetaPutAnual =
+    decaimento +
+    r * desconto * normalCdf(-d2) -
+    y * S * fatorY * normalCdf(-d1);
+  const charmComum =
+    (-fatorY * phid1 * (2 * (r - y) * T - d2 * safeSigma * sqrtT)) /
+    (2 * T * safeSigma * sqrtT);
+  const charmCallAnual = y * fatorY * normalCdf(d1) + charmComum;
+  const charmPutAnual = -y * fatorY * normalCdf(-d1) + charmComum;
+
+  const rhoCall = K * T * Math.exp(-r * T) * normalCdf(d2);
+  const rhoPut = -K * T * Math.exp(-r * T) * normalCdf(-d2);
+  const vanna = (-fatorY * phid1 * d2) / safeSigma;
+  const volga = (vega * d1 * d2) / safeSigma;
+  const speed = (-gama / S) * (d1 / (safeSigma * sqrtT) + 1);
 
   return {
     deltaCall,
     deltaPut,
     vega: vega / 100,
     gama,
+    thetaCall: thetaCallAnual / BUSINESS_DAYS_IN_YEAR,
+    thetaPut: thetaPutAnual / BUSINESS_DAYS_IN_YEAR,
+    thetaCallCorrido: thetaCallAnual / CALENDAR_DAYS_IN_YEAR,
+    thetaPutCorrido: thetaPutAnual / CALENDAR_DAYS_IN_YEAR,
+    rhoCall: rhoCall / 100,
+    rhoPut: rhoPut / 100,
+    vanna: vanna / 100,
+    volga: volga / 10000,
+    charmCall: charmCallAnual / BUSINESS_DAYS_IN_YEAR,
+    charmPut: charmPutAnual / BUSINESS_DAYS_IN_YEAR,
+    speed,
   };
 
 
@@ -139,6 +403,7 @@ export function calculaGregas(
 
 /**
  * Black-Scholes para opcao de compra europeia (CALL).
+ * q e taxaAluguel opcionais aplicam o ajuste de Merton (rendimento continuo).
  */
 export function blackScholesCall(
   S: number,
@@ -147,20 +412,27 @@ export function blackScholesCall(
   sigma: number,
   dataAtual: Date,
   dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
 ): number {
-  const T = calcularTempoEmAnos(dataAtual, dataVencimento);
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
   if (T <= 0) {
     return Math.max(S - K, 0);
   }
 
+  const y = rendimentoTotal(q, taxaAluguel);
   const safeSigma = ensurePositive(sigma);
   const sqrtT = Math.sqrt(T);
   const d1 =
-    (Math.log(S / K) + (r + 0.5 * safeSigma * safeSigma) * T) /
+    (Math.log(S / K) + (r - y + 0.5 * safeSigma * safeSigma) * T) /
     (safeSigma * sqrtT);
   const d2 = d1 - safeSigma * sqrtT;
 
-  return S * normalCdf(d1) - K * Math.exp(-r * T) * normalCdf(d2);
+  return (
+    S * Math.exp(-y * T) * normalCdf(d1) -
+    K * Math.exp(-r * T) * normalCdf(d2)
+  );
 }
 
 /**
@@ -173,25 +445,33 @@ export function blackScholesPut(
   sigma: number,
   dataAtual: Date,
   dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
 ): number {
-  const T = calcularTempoEmAnos(dataAtual, dataVencimento);
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
   if (T <= 0) {
     return Math.max(K - S, 0);
   }
 
+  const y = rendimentoTotal(q, taxaAluguel);
   const safeSigma = ensurePositive(sigma);
   const sqrtT = Math.sqrt(T);
   const d1 =
-    (Math.log(S / K) + (r + 0.5 * safeSigma * safeSigma) * T) /
+    (Math.log(S / K) + (r - y + 0.5 * safeSigma * safeSigma) * T) /
     (safeSigma * sqrtT);
   const d2 = d1 - safeSigma * sqrtT;
 
-  return K * Math.exp(-r * T) * normalCdf(-d2) - S * normalCdf(-d1);
+  return (
+    K * Math.exp(-r * T) * normalCdf(-d2) -
+    S * Math.exp(-y * T) * normalCdf(-d1)
+  );
 }
 
 /**
  * Variante modificada do Black-Scholes com parametro p e fator A(tau).
  * tau = tempo ate o vencimento (anos).
+ * O rendimento q (+ aluguel) entra como no classico, via S e^{-q tau}.
  */
 export function blackScholesCallModified(
   S: number,
@@ -201,23 +481,30 @@ export function blackScholesCallModified(
   p: number,
   dataAtual: Date,
   dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
 ): number {
-  const tau = calcularTempoEmAnos(dataAtual, dataVencimento);
+  const tau = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
   if (tau <= 0) {
     return Math.max(S - K, 0);
   }
 
+  const y = rendimentoTotal(q, taxaAluguel);
+  const sDesc = S * Math.exp(-y * tau);
   const safeSigma = ensurePositive(sigma);
   const safeP = ensurePositive(p);
   const sigmaSq = safeSigma * safeSigma;
   const sqrtPTau = Math.sqrt(safeP * tau);
   const aTau = Math.exp((safeP - 1) * (sigmaSq / 2) * tau);
-  const base = Math.log(S / K) - 0.5 * sigmaSq * tau + r * tau;
+  const base = Math.log(sDesc / K) - 0.5 * sigmaSq * tau + r * tau;
   const d1 =
     (base + safeP * sigmaSq * tau) / (safeSigma * sqrtPTau);
   const d2 = base / (safeSigma * sqrtPTau);
 
-  return aTau * S * normalCdf(d1) - K * Math.exp(-r * tau) * normalCdf(d2);
+  return (
+    aTau * sDesc * normalCdf(d1) - K * Math.exp(-r * tau) * normalCdf(d2)
+  );
 }
 
 export function blackScholesPutModified(
@@ -228,24 +515,179 @@ export function blackScholesPutModified(
   p: number,
   dataAtual: Date,
   dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
 ): number {
-  const tau = calcularTempoEmAnos(dataAtual, dataVencimento);
+  const tau = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
   if (tau <= 0) {
     return Math.max(K - S, 0);
   }
 
+  const y = rendimentoTotal(q, taxaAluguel);
+  const sDesc = S * Math.exp(-y * tau);
   const safeSigma = ensurePositive(sigma);
   const safeP = ensurePositive(p);
   const sigmaSq = safeSigma * safeSigma;
   const sqrtPTau = Math.sqrt(safeP * tau);
   const aTau = Math.exp((safeP - 1) * (sigmaSq / 2) * tau);
-  const base = Math.log(S / K) - 0.5 * sigmaSq * tau + r * tau;
+  const base = Math.log(sDesc / K) - 0.5 * sigmaSq * tau + r * tau;
   const d1 =
     (base + safeP * sigmaSq * tau) / (safeSigma * sqrtPTau);
   const d2 = base / (safeSigma * sqrtPTau);
 
   return (
     K * Math.exp(-r * tau) * normalCdf(-d2) -
-    aTau * S * normalCdf(-d1)
+    aTau * sDesc * normalCdf(-d1)
+  );
+}
+
+export interface ResultadoGregasModificado {
+  deltaCall: number;
+  deltaPut: number;
+  gama: number;
+  /** Vega por ponto percentual; difere entre call e put quando p != 1. */
+  vegaCall: number;
+  vegaPut: number;
+  /** Theta por dia util (ano de 252 dias). */
+  thetaCall: number;
+  thetaPut: number;
+  /** Theta por dia corrido (ano de 365 dias). */
+  thetaCallCorrido: number;
+  thetaPutCorrido: number;
+  /** Variacao do preco para +1 ponto percentual na taxa r. */
+  rhoCall: number;
+  rhoPut: number;
+  /** Derivada do preco em relacao ao parametro p. */
+  sensibilidadePCall: number;
+  sensibilidadePut: number;
+}
+
+/**
+ * Gregas analiticas do Black-Scholes modificado.
+ * Usa a identidade A S' phi(d1) = K e^{-r tau} phi(d2), com S' = S e^{-q tau},
+ * analoga a do classico, de modo que os termos em dd1 e dd2 se cancelam.
+ */
+export function calculaGregasModificado(
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  p: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoGregasModificado {
+  const tau = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+
+  const y = rendimentoTotal(q, taxaAluguel);
+  const fatorY = Math.exp(-y * tau);
+  const sDesc = S * fatorY;
+  const safeSigma = ensurePositive(sigma);
+  const safeP = ensurePositive(p);
+  const sigmaSq = safeSigma * safeSigma;
+  const sqrtPTau = Math.sqrt(safeP * tau);
+  const s = safeSigma * sqrtPTau;
+  const aTau = Math.exp((safeP - 1) * (sigmaSq / 2) * tau);
+  const base = Math.log(sDesc / K) - 0.5 * sigmaSq * tau + r * tau;
+  const d2 = base / s;
+  const d1 = d2 + s;
+
+  const phid1 = normalPdf(d1);
+  const nd1 = normalCdf(d1);
+  const nMinusD1 = normalCdf(-d1);
+  const desconto = K * Math.exp(-r * tau);
+  // Termo comum A S' phi(d1), igual a K e^{-r tau} phi(d2).
+  const aSPhi = aTau * sDesc * phid1;
+
+  const deltaCall = fatorY * aTau * nd1;
+  const deltaPut = -fatorY * aTau * nMinusD1;
+  const gama = (fatorY * aTau * phid1) / (S * s);
+
+  const dADsigma = aTau * (safeP - 1) * safeSigma * tau;
+  const vegaCall = dADsigma * sDesc * nd1 + aSPhi * sqrtPTau;
+  const vegaPut = -dADsigma * sDesc * nMinusD1 + aSPhi * sqrtPTau;
+
+  // Derivadas em tau; theta = -dV/dtau. O termo -y A vem de e^{-y tau}.
+  const dADtau = aTau * ((safeP - 1) * (sigmaSq / 2) - y);
+  const dCdTau =
+    dADtau * sDesc * nd1 +
+    (aSPhi * s) / (2 * tau) +
+    r * desconto * normalCdf(d2);
+  const dPdTau =
+    -dADtau * sDesc * nMinusD1 +
+    (aSPhi * s) / (2 * tau) -
+    r * desconto * normalCdf(-d2);
+
+  const rhoCall = tau * desconto * normalCdf(d2);
+  const rhoPut = -tau * desconto * normalCdf(-d2);
+
+  const dADp = aTau * (sigmaSq / 2) * tau;
+  const sensibilidadePCall = dADp * sDesc * nd1 + (aSPhi * s) / (2 * safeP);
+  const sensibilidadePut =
+    -dADp * sDesc * nMinusD1 + (aSPhi * s) / (2 * safeP);
+
+  return {
+    deltaCall,
+    deltaPut,
+    gama,
+    vegaCall: vegaCall / 100,
+    vegaPut: vegaPut / 100,
+    thetaCall: -dCdTau / BUSINESS_DAYS_IN_YEAR,
+    thetaPut: -dPdTau / BUSINESS_DAYS_IN_YEAR,
+    thetaCallCorrido: -dCdTau / CALENDAR_DAYS_IN_YEAR,
+    thetaPutCorrido: -dPdTau / CALENDAR_DAYS_IN_YEAR,
+    rhoCall: rhoCall / 100,
+    rhoPut: rhoPut / 100,
+    sensibilidadePCall,
+    sensibilidadePut,
+  };
+}
+
+export type DividendoDiscreto = {
+  dataEx: Date;
+  /** Valor em dinheiro por acao (dividendo ou JCP). */
+  valor: number;
+};
+
+/**
+ * Valor presente dos dividendos com data ex em (dataAtual, dataVencimento].
+ * Cada pagamento e descontado a r pelo prazo em dias uteis ate a data ex.
+ */
+export function valorPresenteDividendos(
+  dividendos: DividendoDiscreto[],
+  r: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const { inicio, fim } = normalizarPeriodo(dataAtual, dataVencimento);
+
+  return dividendos.reduce((total, dividendo) => {
+    const { inicio: dataEx } = normalizarPeriodo(dividendo.dataEx, dividendo.dataEx);
+    if (dataEx <= inicio || dataEx > fim) {
+      return total;
+    }
+    const t = calcularTempoEmAnos(dataAtual, dividendo.dataEx, calendario);
+    return total + dividendo.valor * Math.exp(-r * t);
+  }, 0);
+}
+
+/**
+ * Modelo de dividendo escrowed: o spot usado nos precificadores e
+ * S menos o valor presente dos dividendos pagos antes do vencimento.
+ */
+export function spotAjustadoDividendos(
+  S: number,
+  dividendos: DividendoDiscreto[],
+  r: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  return (
+    S - valorPresenteDividendos(dividendos, r, dataAtual, dataVencimento, calendario)
   );
 }
@@ -1,5 +1,6 @@
 /**
  * Formato aceito: DD/MM/AAAA
+ * Retorna meia-noite local, coerente com a normalizacao de calcularDiasUteis.
  */
 export function parseDate(value: string): Date | null {
   const [dayStr, monthStr, yearStr] = value.split('/');
@@ -16,7 +17,7 @@ export function parseDate(value: string): Date | null {
     return null;
   }
 
-  const date = new Date(Date.UTC(year, month - 1, day));
+  const date = new Date(year, month - 1, day);
   return Number.isNaN(date.getTime()) ? null : date;
 }
 
@@ -29,3 +30,11 @@ export function formatDateInput(date: Date): string {
   const day = `${date.getDate()}`.padStart(2, '0');
   return `${day}/${month}/${year}`;
 }
+
+/**
+ * Converte AAAA-MM-DD em DD/MM/AAAA.
+ */
+export function formatIsoDate(value: string): string {
+  const [year, month, day] = value.split('-');
+  return `${day}/${month}/${year}`;
+}
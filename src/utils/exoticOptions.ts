@@ -0,0 +1,490 @@
+import {
+  BUSINESS_DAYS_IN_YEAR,
+  calcularTempoEmAnos,
+  ensurePositive,
+  normalCdf,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+import type { TipoOpcao } from './impliedVolatility';
+import type { PayoffCaminho } from './monteCarlo';
+
+export type ProdutoExotico =
+  | 'digitalDinheiro'
+  | 'digitalAtivo'
+  | 'barreira'
+  | 'asiaticaGeometrica'
+  | 'asiaticaAritmetica';
+
+export type TipoBarreira = 'downOut' | 'downIn' | 'upOut' | 'upIn';
+
+export type OpcoesBarreira = {
+  barreira: number;
+  tipoBarreira: TipoBarreira;
+  /** Pago no toque (knock-out) ou no vencimento sem toque (knock-in). */
+  rebate?: number;
+  /** Dias uteis entre observacoes da barreira; 0 = monitoramento continuo. */
+  monitoramentoDias?: number;
+};
+
+export type OpcoesExotica = Partial<OpcoesBarreira> & {
+  /** Valor pago pela digital cash-or-nothing. */
+  pagamento?: number;
+};
+
+export interface ResultadoGregasExotica {
+  deltaCall: number;
+  deltaPut: number;
+  vegaCall: number;
+  vegaPut: number;
+}
+
+export const PRODUTOS_EXOTICOS: Record<ProdutoExotico, string> = {
+  digitalDinheiro: 'Digital cash-or-nothing',
+  digitalAtivo: 'Digital asset-or-nothing',
+  barreira: 'Barreira (Reiner-Rubinstein)',
+  asiaticaGeometrica: 'Asiatica geometrica (Kemna-Vorst)',
+  asiaticaAritmetica: 'Asiatica aritmetica (Turnbull-Wakeman)',
+};
+
+export const TIPOS_BARREIRA: Record<TipoBarreira, string> = {
+  downOut: 'Down-and-out',
+  downIn: 'Down-and-in',
+  upOut: 'Up-and-out',
+  upIn: 'Up-and-in',
+};
+
+// Broadie-Glasserman-Kou (1997): -zeta(1/2) / sqrt(2 pi).
+const BETA_MONITORAMENTO_DISCRETO = 0.5826;
+
+function intrinseco(tipo: TipoOpcao, S: number, K: number): number {
+  return tipo === 'call' ? Math.max(S - K, 0) : Math.max(K - S, 0);
+}
+
+// int_0^T e^{a t} dt, estavel para a -> 0.
+function integralExp(a: number, T: number): number {
+  return Math.abs(a) < 1e-12 ? T : Math.expm1(a * T) / a;
+}
+
+/**
+ * Black-Scholes generalizado com custo de carregamento b (b = r - q para acoes).
+ */
+function europeiaCarry(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  T: number,
+  r: number,
+  b: number,
+  v: number,
+): number {
+  const sqrtT = Math.sqrt(T);
+  const d1 = (Math.log(S / K) + (b + 0.5 * v * v) * T) / (v * sqrtT);
+  const d2 = d1 - v * sqrtT;
+  const fator = Math.exp((b - r) * T);
+  return tipo === 'call'
+    ? S * fator * normalCdf(d1) - K * Math.exp(-r * T) * normalCdf(d2)
+    : K * Math.exp(-r * T) * normalCdf(-d2) - S * fator * normalCdf(-d1);
+}
+
+/**
+ * Digital cash-or-nothing: paga `pagamento` no vencimento se terminar dentro do dinheiro.
+ */
+export function digitalDinheiro(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  pagamento = 1,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return intrinseco(tipo, S, K) > 0 ? pagamento : 0;
+  }
+
+  const v = ensurePositive(sigma);
+  const b = r - (q + taxaAluguel);
+  const sqrtT = Math.sqrt(T);
+  const d2 = (Math.log(S / K) + (b - 0.5 * v * v) * T) / (v * sqrtT);
+  const phi = tipo === 'call' ? 1 : -1;
+  return pagamento * Math.exp(-r * T) * normalCdf(phi * d2);
+}
+
+/**
+ * Digital asset-or-nothing: entrega o ativo no vencimento se terminar dentro do dinheiro.
+ */
+export function digitalAtivo(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return intrinseco(tipo, S, K) > 0 ? S : 0;
+  }
+
+  const v = ensurePositive(sigma);
+  const b = r - (q + taxaAluguel);
+  const sqrtT = Math.sqrt(T);
+  const d1 = (Math.log(S / K) + (b + 0.5 * v * v) * T) / (v * sqrtT);
+  const phi = tipo === 'call' ? 1 : -1;
+  return S * Math.exp((b - r) * T) * normalCdf(phi * d1);
+}
+
+/**
+ * Barreira equivalente ao monitoramento continuo (Broadie-Glasserman-Kou):
+ * afasta a barreira do spot por e^{0.5826 sigma sqrt(dt)}.
+ */
+export function barreiraAjustada(
+  barreira: number,
+  tipoBarreira: TipoBarreira,
+  sigma: number,
+  monitoramentoDias = 0,
+): number {
+  if (!(monitoramentoDias > 0)) {
+    return barreira;
+  }
+  const dt = monitoramentoDias / BUSINESS_DAYS_IN_YEAR;
+  const sinal = tipoBarreira.startsWith('up') ? 1 : -1;
+  return barreira * Math.exp(sinal * BETA_MONITORAMENTO_DISCRETO * sigma * Math.sqrt(dt));
+}
+
+/**
+ * Barreira simples por Reiner-Rubinstein (1991), na notacao de Haug: termos
+ * A-F combinados conforme tipo, direcao e posicao do strike em relacao a H.
+ */
+function reinerRubinstein(
+  tipo: TipoOpcao,
+  tipoBarreira: TipoBarreira,
+  S: number,
+  K: number,
+  H: number,
+  rebate: number,
+  T: number,
+  r: number,
+  b: number,
+  v: number,
+): number {
+  const phi = tipo === 'call' ? 1 : -1;
+  const eta = tipoBarreira.startsWith('down') ? 1 : -1;
+  const v2 = v * v;
+  const vSqrtT = v * Math.sqrt(T);
+  const mu = (b - 0.5 * v2) / v2;
+  const lambda = Math.sqrt(mu * mu + (2 * r) / v2);
+  const x1 = Math.log(S / K) / vSqrtT + (1 + mu) * vSqrtT;
+  const x2 = Math.log(S / H) / vSqrtT + (1 + mu) * vSqrtT;
+  const y1 = Math.log((H * H) / (S * K)) / vSqrtT + (1 + mu) * vSqrtT;
+  const y2 = Math.log(H / S) / vSqrtT + (1 + mu) * vSqrtT;
+  const z = Math.log(H / S) / vSqrtT + lambda * vSqrtT;
+  const ativo = S * Math.exp((b - r) * T);
+  const strike = K * Math.exp(-r * T);
+  const razao = H / S;
+
+  const A =
+    phi * ativo * normalCdf(phi * x1) - phi * strike * normalCdf(phi * (x1 - vSqrtT));
+  const B =
+    phi * ativo * normalCdf(phi * x2) - phi * strike * normalCdf(phi * (x2 - vSqrtT));
+  const C =
+    phi * ativo * razao ** (2 * (mu + 1)) * normalCdf(eta * y1) -
+    phi * strike * razao ** (2 * mu) * normalCdf(eta * (y1 - vSqrtT));
+  const D =
+    phi * ativo * razao ** (2 * (mu + 1)) * normalCdf(eta * y2) -
+    phi * strike * razao ** (2 * mu) * normalCdf(eta * (y2 - vSqrtT));
+  const E =
+    rebate *
+    Math.exp(-r * T) *
+    (normalCdf(eta * (x2 - vSqrtT)) - razao ** (2 * mu) * normalCdf(eta * (y2 - vSqrtT)));
+  const F =
+    rebate *
+    (razao ** (mu + lambda) * normalCdf(eta * z) +
+      razao ** (mu - lambda) * normalCdf(eta * (z - 2 * lambda * vSqrtT)));
+
+  const acimaDaBarreira = K >= H;
+  switch (`${tipo}-${tipoBarreira}`) {
+    case 'call-downIn':
+      return acimaDaBarreira ? C + E : A - B + D + E;
+    case 'call-upIn':
+      return acimaDaBarreira ? A + E : B - C + D + E;
+    case 'put-downIn':
+      return acimaDaBarreira ? B - C + D + E : A + E;
+    case 'put-upIn':
+      return acimaDaBarreira ? A - B + D + E : C + E;
+    case 'call-downOut':
+      return acimaDaBarreira ? A - C + F : B - D + F;
+    case 'call-upOut':
+      return acimaDaBarreira ? F : A - B + C - D + F;
+    case 'put-downOut':
+      return acimaDaBarreira ? A - B + C - D + F : F;
+    default:
+      // put-upOut
+      return acimaDaBarreira ? B - D + F : A - C + F;
+  }
+}
+
+/**
+ * Opcao com barreira simples (knock-in/knock-out). Com `monitoramentoDias` > 0 a
+ * barreira e observada a cada tantos dias uteis e a formula continua recebe a
+ * barreira corrigida de Broadie-Glasserman-Kou. Se o spot ja atravessou a
+ * barreira, a knock-out vale o rebate e a knock-in vira a europeia.
+ */
+export function precoBarreira(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  { barreira, tipoBarreira, rebate = 0, monitoramentoDias = 0 }: OpcoesBarreira,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const entrada = tipoBarreira.endsWith('In');
+  const tocou = tipoBarreira.startsWith('down') ? S <= barreira : S >= barreira;
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  const b = r - (q + taxaAluguel);
+  const v = ensurePositive(sigma);
+
+  if (tocou) {
+    if (!entrada) {
+      return rebate;
+    }
+    return T <= 0 ? intrinseco(tipo, S, K) : europeiaCarry(tipo, S, K, T, r, b, v);
+  }
+  if (T <= 0) {
+    return entrada ? rebate : intrinseco(tipo, S, K);
+  }
+
+  const H = barreiraAjustada(barreira, tipoBarreira, v, monitoramentoDias);
+  return reinerRubinstein(tipo, tipoBarreira, S, K, H, rebate, T, r, b, v);
+}
+
+/**
+ * Asiatica de media geometrica continua (Kemna-Vorst), com a media iniciando
+ * hoje: europeia com sigma / sqrt(3) e carregamento (b - sigma^2/6) / 2.
+ */
+export function asiaticaGeometrica(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return intrinseco(tipo, S, K);
+  }
+
+  const v = ensurePositive(sigma);
+  const b = r - (q + taxaAluguel);
+  return europeiaCarry(tipo, S, K, T, r, 0.5 * (b - (v * v) / 6), v / Math.sqrt(3));
+}
+
+/**
+ * Asiatica de media aritmetica continua por Turnbull-Wakeman: ajusta uma
+ * lognormal aos dois primeiros momentos da media, com a media iniciando hoje.
+ */
+export function asiaticaAritmetica(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return intrinseco(tipo, S, K);
+  }
+
+  const v = ensurePositive(sigma);
+  const v2 = v * v;
+  const b = r - (q + taxaAluguel);
+  // Momentos de A = (1/T) int_0^T S_t/S dt; c = b + sigma^2 -> 0 usa o limite.
+  const c = b + v2;
+  const m1 = integralExp(b, T) / T;
+  const m2 =
+    Math.abs(c) < 1e-10
+      ? (2 * (Math.exp(b * T) * (b * T - 1) + 1)) / (b * b * T * T)
+      : (2 / (c * T * T)) * (integralExp(b + c, T) - integralExp(b, T));
+  const bA = Math.log(m1) / T;
+  const vA = Math.sqrt(Math.max(Math.log(m2) / T - 2 * bA, 1e-24));
+  return europeiaCarry(tipo, S, K, T, r, bA, vA);
+}
+
+/**
+ * Preco do produto exotico escolhido, com a mesma assinatura posicional dos
+ * precificadores europeus e os parametros do produto ao final.
+ */
+export function precoExotico(
+  produto: ProdutoExotico,
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  opcoes: OpcoesExotica = {},
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const argumentos = [S, K, r, sigma, dataAtual, dataVencimento, q, taxaAluguel] as const;
+  switch (produto) {
+    case 'digitalDinheiro':
+      return digitalDinheiro(tipo, ...argumentos, opcoes.pagamento, calendario);
+    case 'digitalAtivo':
+      return digitalAtivo(tipo, ...argumentos, calendario);
+    case 'barreira':
+      return precoBarreira(
+        tipo,
+        ...argumentos,
+        {
+          barreira: opcoes.barreira ?? NaN,
+          tipoBarreira: opcoes.tipoBarreira ?? 'downOut',
+          rebate: opcoes.rebate,
+          monitoramentoDias: opcoes.monitoramentoDias,
+        },
+        calendario,
+      );
+    case 'asiaticaGeometrica':
+      return asiaticaGeometrica(tipo, ...argumentos, calendario);
+    default:
+      return asiaticaAritmetica(tipo, ...argumentos, calendario);
+  }
+}
+
+/**
+ * Delta e vega (por 1% de vol) por diferencas centrais, ja que as formulas
+ * fechadas dos exoticos nao tem gregas analiticas aqui.
+ */
+export function calculaGregasExotica(
+  produto: ProdutoExotico,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  opcoes: OpcoesExotica = {},
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoGregasExotica {
+  const preco = (tipo: TipoOpcao, spot: number, vol: number) =>
+    precoExotico(
+      produto,
+      tipo,
+      spot,
+      K,
+      r,
+      vol,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      opcoes,
+      calendario,
+    );
+  const dS = 1e-4 * S;
+  const dSigma = 1e-4;
+  const delta = (tipo: TipoOpcao) =>
+    (preco(tipo, S + dS, sigma) - preco(tipo, S - dS, sigma)) / (2 * dS);
+  const vega = (tipo: TipoOpcao) =>
+    (preco(tipo, S, sigma + dSigma) - preco(tipo, S, Math.max(sigma - dSigma, 1e-12))) /
+    (sigma + dSigma - Math.max(sigma - dSigma, 1e-12)) /
+    100;
+
+  return {
+    deltaCall: delta('call'),
+    deltaPut: delta('put'),
+    vegaCall: vega('call'),
+    vegaPut: vega('put'),
+  };
+}
+
+/**
+ * Datas de observacao da simulacao: uma para digitais, cada monitoramento da
+ * barreira (diario se continua) e cada dia util da media das asiaticas.
+ */
+export function observacoesExotica(
+  produto: ProdutoExotico,
+  diasUteis: number,
+  { monitoramentoDias = 0 }: OpcoesExotica = {},
+): number {
+  if (produto === 'digitalDinheiro' || produto === 'digitalAtivo') {
+    return 1;
+  }
+  const intervalo = produto === 'barreira' && monitoramentoDias > 0 ? monitoramentoDias : 1;
+  return Math.max(1, Math.floor(diasUteis / intervalo));
+}
+
+/**
+ * Payoff do produto sobre a trajetoria do Monte Carlo, para conferir as formulas
+ * fechadas; cada ponto da trajetoria e uma data de observacao (barreira) ou de media.
+ */
+export function payoffExoticoCaminho(
+  produto: ProdutoExotico,
+  tipo: TipoOpcao,
+  K: number,
+  opcoes: OpcoesExotica = {},
+): PayoffCaminho {
+  const final = (caminho: Float64Array) => caminho[caminho.length - 1];
+  switch (produto) {
+    case 'digitalDinheiro': {
+      const pagamento = opcoes.pagamento ?? 1;
+      return (caminho) =>
+        intrinseco(tipo, final(caminho), K) > 0 ? pagamento : 0;
+    }
+    case 'digitalAtivo':
+      return (caminho) =>
+        intrinseco(tipo, final(caminho), K) > 0 ? final(caminho) : 0;
+    case 'barreira': {
+      const { barreira = NaN, tipoBarreira = 'downOut', rebate = 0 } = opcoes;
+      const baixa = tipoBarreira.startsWith('down');
+      const entrada = tipoBarreira.endsWith('In');
+      // Rebate da knock-out sai no vencimento aqui; a formula paga no toque.
+      return (caminho) => {
+        const tocou = caminho.some((spot) =>
+          baixa ? spot <= barreira : spot >= barreira,
+        );
+        return tocou === entrada ? intrinseco(tipo, final(caminho), K) : rebate;
+      };
+    }
+    case 'asiaticaGeometrica':
+      return (caminho) => {
+        let somaLog = 0;
+        for (let i = 0; i < caminho.length; i += 1) somaLog += Math.log(caminho[i]);
+        return intrinseco(tipo, Math.exp(somaLog / caminho.length), K);
+      };
+    default:
+      return (caminho) => {
+        let soma = 0;
+        for (let i = 0; i < caminho.length; i += 1) soma += caminho[i];
+        return intrinseco(tipo, soma / caminho.length, K);
+      };
+  }
+}
@@ -0,0 +1,450 @@
+import {
+  BUSINESS_DAYS_IN_YEAR,
+  calcularDiasUteis,
+  calcularTempoEmAnos,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+import type { OpcoesBarreira } from './exoticOptions';
+import type { TipoOpcao } from './impliedVolatility';
+
+export type ExercicioEdp = 'europeu' | 'americano';
+
+export type ConfigEdp = {
+  /** Intervalos da grade em log-spot. */
+  pontosEspaco?: number;
+  /** Passos de tempo (minimo; barreiras discretas alinham os passos as observacoes). */
+  passosTempo?: number;
+  /** Meios-passos implicitos iniciais (Rannacher); 0 = Crank-Nicolson puro. */
+  passosRannacher?: number;
+  exercicio?: ExercicioEdp;
+  /** Barreira simples; monitoramentoDias > 0 observa a barreira so nessas datas. */
+  barreira?: OpcoesBarreira;
+  /** Desvios-padrao de ln S cobertos pela grade de cada lado. */
+  desvios?: number;
+  /** Fator de sobre-relaxacao do PSOR (entre 1 e 2). */
+  omega?: number;
+  tolerancia?: number;
+};
+
+export type ResultadoEdp = {
+  ok: true;
+  preco: number;
+  delta: number;
+  gama: number;
+  /** Theta por dia util, da diferenca entre os dois ultimos niveis de tempo. */
+  theta: number;
+  /** Grade de precos hoje por spot (somente a regiao viva, para barreiras). */
+  spots: number[];
+  precos: number[];
+  pontosEspaco: number;
+  passosTempo: number;
+  /** Iteracoes PSOR somadas em todos os passos (exercicio americano). */
+  iteracoesPsor?: number;
+};
+
+type Erro = { ok: false; error: string };
+
+export const MAX_PONTOS_ESPACO_EDP = 5000;
+export const MAX_PASSOS_TEMPO_EDP = 20000;
+
+const MAX_ITERACOES_PSOR = 10000;
+
+type Grade = {
+  xMin: number;
+  dx: number;
+  valores: Float64Array;
+  /** Valores um passo de tempo antes do fim (tau = T - dt), para o theta. */
+  anteriores: Float64Array;
+  dt: number;
+  passos: number;
+  iteracoes: number;
+};
+
+type ProblemaEdp = {
+  xMin: number;
+  xMax: number;
+  M: number;
+  N: number;
+  T: number;
+  r: number;
+  b: number;
+  sigma: number;
+  terminal: (spot: number) => number;
+  /** Valores de Dirichlet nas pontas no tempo restante tau. */
+  inferior: (tau: number) => number;
+  superior: (tau: number) => number;
+  obstaculo?: (spot: number) => number;
+  /**
+   * Barreira discreta: observada a cada `aCada` passos contados a partir de hoje
+   * (o vencimento fica a cargo do payoff terminal).
+   */
+  observacao?: { aCada: number; aplicar: (v: Float64Array, spots: Float64Array) => void };
+  rannacher: number;
+  omega: number;
+  tolerancia: number;
+};
+
+function resolverTridiagonal(
+  inf: number,
+  diag: number,
+  sup: number,
+  rhs: Float64Array,
+  saida: Float64Array,
+  c: Float64Array,
+) {
+  const n = rhs.length;
+  c[0] = sup / diag;
+  saida[0] = rhs[0] / diag;
+  for (let i = 1; i < n; i += 1) {
+    const m = diag - inf * c[i - 1];
+    c[i] = sup / m;
+    saida[i] = (rhs[i] - inf * saida[i - 1]) / m;
+  }
+  for (let i = n - 2; i >= 0; i -= 1) {
+    saida[i] -= c[i] * saida[i + 1];
+  }
+}
+
+/**
+ * Resolve V_tau = 1/2 sigma^2 V_xx + (b - sigma^2/2) V_x - r V em x = ln S, do
+ * vencimento (tau = 0) ate hoje, pelo esquema theta: Crank-Nicolson com os
+ * primeiros passos trocados por meios-passos implicitos (Rannacher) para
+ * amortecer a descontinuidade do payoff. Com obstaculo, cada passo e um
+ * problema de complementaridade linear resolvido por PSOR.
+ */
+function resolverGrade(problema: ProblemaEdp): Grade {
+  const { xMin, xMax, M, N, T, r, b, sigma, obstaculo, observacao } = problema;
+  const dx = (xMax - xMin) / M;
+  const dt = T / N;
+  const spots = new Float64Array(M + 1);
+  for (let i = 0; i <= M; i += 1) spots[i] = Math.exp(xMin + i * dx);
+
+  const difusao = (0.5 * sigma * sigma) / (dx * dx);
+  const conveccao = (b - 0.5 * sigma * sigma) / (2 * dx);
+  // Operador L: l V_{i-1} + d V_i + u V_{i+1}.
+  const l = difusao - conveccao;
+  const d = -2 * difusao - r;
+  const u = difusao + conveccao;
+
+  let v = new Float64Array(M + 1);
+  let novo = new Float64Array(M + 1);
+  const anteriores = new Float64Array(M + 1);
+  const rhs = new Float64Array(M - 1);
+  const interior = new Float64Array(M - 1);
+  const auxiliar = new Float64Array(M - 1);
+  const limite = obstaculo ? Float64Array.from(spots, obstaculo) : null;
+  for (let i = 0; i <= M; i += 1) v[i] = problema.terminal(spots[i]);
+  v[0] = problema.inferior(0);
+  v[M] = problema.superior(0);
+
+  let iteracoes = 0;
+  let tau = 0;
+  const avancar = (passo: number, theta: number) => {
+    const tauNovo = tau + passo;
+    const inf = -theta * passo * l;
+    const diag = 1 - theta * passo * d;
+    const sup = -theta * passo * u;
+    const explicito = (1 - theta) * passo;
+    for (let i = 1; i < M; i += 1) {
+      rhs[i - 1] = v[i] + explicito * (l * v[i - 1] + d * v[i] + u * v[i + 1]);
+    }
+    novo[0] = problema.inferior(tauNovo);
+    novo[M] = problema.superior(tauNovo);
+    rhs[0] -= inf * novo[0];
+    rhs[M - 2] -= sup * novo[M];
+
+    if (!limite) {
+      resolverTridiagonal(inf, diag, sup, rhs, interior, auxiliar);
+      for (let i = 1; i < M; i += 1) novo[i] = interior[i - 1];
+    } else {
+      for (let i = 1; i < M; i += 1) novo[i] = Math.max(v[i], limite[i]);
+      for (let k = 0; k < MAX_ITERACOES_PSOR; k += 1) {
+        let erro = 0;
+        for (let i = 1; i < M; i += 1) {
+          const vizinhos =
+            (i > 1 ? inf * novo[i - 1] : 0) + (i < M - 1 ? sup * novo[i + 1] : 0);
+          const gaussSeidel = (rhs[i - 1] - vizinhos) / diag;
+          const valor = Math.max(
+            limite[i],
+            novo[i] + problema.omega * (gaussSeidel - novo[i]),
+          );
+          erro = Math.max(erro, Math.abs(valor - novo[i]));
+          novo[i] = valor;
+        }
+        iteracoes += 1;
+        if (erro < problema.tolerancia) break;
+      }
+    }
+    [v, novo] = [novo, v];
+    tau = tauNovo;
+  };
+
+  // Cada observacao da barreira reintroduz uma descontinuidade: reinicia Rannacher.
+  let ultimaDescontinuidade = 0;
+  for (let n = 1; n <= N; n += 1) {
+    if (n === N) anteriores.set(v);
+    if (n - ultimaDescontinuidade <= problema.rannacher / 2) {
+      avancar(dt / 2, 1);
+      avancar(dt / 2, 1);
+    } else {
+      avancar(dt, 0.5);
+    }
+    if (observacao && n < N && (N - n) % observacao.aCada === 0) {
+      observacao.aplicar(v, spots);
+      ultimaDescontinuidade = n;
+    }
+  }
+
+  return { xMin, dx, valores: v, anteriores, dt, passos: N, iteracoes };
+}
+
+/**
+ * Valor e derivadas em x pela parabola nos tres nos mais proximos de x0.
+ */
+function interpolarGrade(
+  valores: Float64Array,
+  xMin: number,
+  dx: number,
+  x0: number,
+): { valor: number; dVdx: number; d2Vdx2: number } {
+  const M = valores.length - 1;
+  const j = Math.min(Math.max(Math.round((x0 - xMin) / dx), 1), M - 1);
+  const h = x0 - (xMin + j * dx);
+  const primeira = (valores[j + 1] - valores[j - 1]) / (2 * dx);
+  const segunda = (valores[j + 1] - 2 * valores[j] + valores[j - 1]) / (dx * dx);
+  return {
+    valor: valores[j] + primeira * h + 0.5 * segunda * h * h,
+    dVdx: primeira + segunda * h,
+    d2Vdx2: segunda,
+  };
+}
+
+function payoff(tipo: TipoOpcao, K: number): (spot: number) => number {
+  return tipo === 'call'
+    ? (spot) => Math.max(spot - K, 0)
+    : (spot) => Math.max(K - spot, 0);
+}
+
+/**
+ * Precifica pela EDP de Black-Scholes (mesma convencao de prazo em dias
+ * uteis/252 e drift r - q - aluguel dos precificadores fechados). Resolve
+ * europeias, americanas (PSOR) e barreiras knock-out direto na grade; a
+ * knock-in sai da paridade in = vanilla - out, com a vanilla na propria grade.
+ */
+export function precificarEdp(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  config: ConfigEdp = {},
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoEdp | Erro {
+  const {
+    pontosEspaco = 400,
+    passosTempo = 200,
+    passosRannacher = 4,
+    exercicio = 'europeu',
+    barreira,
+    desvios = 5,
+    omega = 1.2,
+    tolerancia = 1e-8,
+  } = config;
+
+  if (
+    !Number.isInteger(pontosEspaco) ||
+    pontosEspaco < 10 ||
+    pontosEspaco > MAX_PONTOS_ESPACO_EDP
+  ) {
+    return {
+      ok: false,
+      error: `Pontos da grade devem ser um inteiro entre 10 e ${MAX_PONTOS_ESPACO_EDP}.`,
+    };
+  }
+  if (
+    !Number.isInteger(passosTempo) ||
+    passosTempo < 1 ||
+    passosTempo > MAX_PASSOS_TEMPO_EDP
+  ) {
+    return {
+      ok: false,
+      error: `Passos de tempo devem ser um inteiro entre 1 e ${MAX_PASSOS_TEMPO_EDP}.`,
+    };
+  }
+  if (!Number.isInteger(passosRannacher) || passosRannacher < 0) {
+    return { ok: false, error: 'Passos de Rannacher devem ser um inteiro >= 0.' };
+  }
+  if (!(omega >= 1 && omega < 2)) {
+    return { ok: false, error: 'Omega do PSOR deve estar em [1, 2).' };
+  }
+  if (!(S > 0) || !(K > 0) || !(sigma > 0)) {
+    return { ok: false, error: 'Parametros de mercado invalidos para a EDP.' };
+  }
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return { ok: false, error: 'Vencimento deve ser posterior a data atual.' };
+  }
+
+  const b = r - (q + taxaAluguel);
+  const americano = exercicio === 'americano';
+  const ganho = payoff(tipo, K);
+  const x0 = Math.log(S);
+  const largura = desvios * sigma * Math.sqrt(T);
+  const base = {
+    xMin: Math.min(x0, Math.log(K)) - largura,
+    xMax: Math.max(x0, Math.log(K)) + largura,
+    M: pontosEspaco,
+    N: passosTempo,
+    T,
+    r,
+    b,
+    sigma,
+    rannacher: passosRannacher,
+    omega,
+    tolerancia,
+  };
+  // Contornos assintoticos de Dirichlet para a vanilla.
+  const contornos = (xMin: number, xMax: number) => {
+    const longe = (spot: number) => (tau: number) => {
+      const europeia =
+        tipo === 'call'
+          ? Math.max(spot * Math.exp((b - r) * tau) - K * Math.exp(-r * tau), 0)
+          : Math.max(K * Math.exp(-r * tau) - spot * Math.exp((b - r) * tau), 0);
+      return americano ? Math.max(europeia, ganho(spot)) : europeia;
+    };
+    return { inferior: longe(Math.exp(xMin)), superior: longe(Math.exp(xMax)) };
+  };
+
+  const montarResultado = (
+    grade: Grade,
+    combinar?: (x: number, valor: number, anterior: boolean) => number,
+  ): ResultadoEdp => {
+    const valores = combinar
+      ? grade.valores.map((valor, i) => combinar(grade.xMin + i * grade.dx, valor, false))
+      : grade.valores;
+    const anteriores = combinar
+      ? grade.anteriores.map((valor, i) =>
+        combinar(grade.xMin + i * grade.dx, valor, true),
+      )
+      : grade.anteriores;
+    const hoje = interpolarGrade(valores, grade.xMin, grade.dx, x0);
+    const antes = interpolarGrade(anteriores, grade.xMin, grade.dx, x0);
+    const spots = Array.from(valores, (_, i) => Math.exp(grade.xMin + i * grade.dx));
+    return {
+      ok: true,
+      preco: hoje.valor,
+      delta: hoje.dVdx / S,
+      gama: (hoje.d2Vdx2 - hoje.dVdx) / (S * S),
+      // dV/dt = -dV/dtau; convertido de ano para dia util.
+      theta: (antes.valor - hoje.valor) / grade.dt / BUSINESS_DAYS_IN_YEAR,
+      spots,
+      precos: Array.from(valores),
+      pontosEspaco: valores.length - 1,
+      passosTempo: grade.passos,
+      ...(americano ? { iteracoesPsor: grade.iteracoes } : {}),
+    };
+  };
+
+  if (!barreira) {
+    const grade = resolverGrade({
+      ...base,
+      ...contornos(base.xMin, base.xMax),
+      terminal: ganho,
+      ...(americano ? { obstaculo: ganho } : {}),
+    });
+    return montarResultado(grade);
+  }
+
+  const { barreira: H, tipoBarreira, rebate = 0, monitoramentoDias = 0 } = barreira;
+  const baixa = tipoBarreira.startsWith('down');
+  const entrada = tipoBarreira.endsWith('In');
+  if (!(H > 0)) {
+    return { ok: false, error: 'Barreira deve ser maior que zero.' };
+  }
+  if (baixa ? S <= H : S >= H) {
+    return { ok: false, error: 'Spot ja atravessou a barreira.' };
+  }
+  if (entrada && americano) {
+    return { ok: false, error: 'Barreira knock-in americana nao suportada na EDP.' };
+  }
+
+  // Knock-out: paga o rebate no toque. A knock-in usa out com payoff - rebate
+  // e rebate zero: in = vanilla - out(payoff - R) (o rebate da in sai no vencimento).
+  const terminalOut = entrada ? (spot: number) => ganho(spot) - rebate : ganho;
+  const rebateOut = entrada ? 0 : rebate;
+  const xH = Math.log(H);
+  const fora = (spot: number) => (baixa ? spot <= H : spot >= H);
+  let problemaOut: ProblemaEdp;
+
+  if (monitoramentoDias > 0) {
+    // Observacoes a cada monitoramentoDias dias uteis: grade temporal em dias uteis.
+    const diasUteis = calcularDiasUteis(dataAtual, dataVencimento, calendario);
+    const porDia = Math.max(1, Math.ceil(passosTempo / diasUteis));
+    const N = porDia * diasUteis;
+    if (N > MAX_PASSOS_TEMPO_EDP) {
+      return { ok: false, error: 'Prazo longo demais para a grade diaria da barreira.' };
+    }
+    // Barreira no meio de dois nos: a projecao cria um degrau em H e o ponto medio
+    // mantem a convergencia de segunda ordem em dx.
+    const inicio = baixa ? Math.min(base.xMin, xH - largura) : base.xMin;
+    const fim = baixa ? base.xMax : Math.max(base.xMax, xH + largura);
+    const dx = (fim - inicio) / base.M;
+    const xMin = xH - (Math.ceil((xH - inicio) / dx) - 0.5) * dx;
+    const xMax = xMin + base.M * dx;
+    const observaNoVencimento = diasUteis % monitoramentoDias === 0;
+    problemaOut = {
+      ...base,
+      xMin,
+      xMax,
+      N,
+      ...contornos(xMin, xMax),
+      ...(baixa ? { inferior: () => rebateOut } : { superior: () => rebateOut }),
+      terminal: (spot) =>
+        observaNoVencimento && fora(spot) ? rebateOut : terminalOut(spot),
+      observacao: {
+        aCada: monitoramentoDias * porDia,
+        aplicar: (v, spots) => {
+          for (let i = 0; i < v.length; i += 1) {
+            if (fora(spots[i])) v[i] = rebateOut;
+          }
+        },
+      },
+    };
+  } else {
+    problemaOut = {
+      ...base,
+      ...(baixa ? { xMin: xH } : { xMax: xH }),
+      ...contornos(baixa ? xH : base.xMin, baixa ? base.xMax : xH),
+      ...(baixa ? { inferior: () => rebateOut } : { superior: () => rebateOut }),
+      terminal: terminalOut,
+    };
+  }
+  if (americano) {
+    problemaOut.obstaculo = (spot) => (fora(spot) ? rebateOut : ganho(spot));
+  }
+
+  const gradeOut = resolverGrade(problemaOut);
+  if (!entrada) {
+    return montarResultado(gradeOut);
+  }
+
+  // Grade da vanilla cobre toda a regiao viva da out, para interpolar sem extrapolar.
+  const xMinVanilla = Math.min(base.xMin, problemaOut.xMin);
+  const xMaxVanilla = Math.max(base.xMax, problemaOut.xMax);
+  const vanilla = resolverGrade({
+    ...base,
+    xMin: xMinVanilla,
+    xMax: xMaxVanilla,
+    N: problemaOut.N,
+    ...contornos(xMinVanilla, xMaxVanilla),
+    terminal: ganho,
+  });
+  return montarResultado(gradeOut, (x, valor, anterior) => {
+    const referencia = anterior ? vanilla.anteriores : vanilla.valores;
+    return interpolarGrade(referencia, vanilla.xMin, vanilla.dx, x).valor - valor;
+  });
+}
@@ -0,0 +1,272 @@
+import { normalCdf } from './blackScholes';
+
+export type Complexo = { re: number; im: number };
+
+export const complexo = (re: number, im = 0): Complexo => ({ re, im });
+export const somar = (a: Complexo, b: Complexo): Complexo =>
+  complexo(a.re + b.re, a.im + b.im);
+export const subtrair = (a: Complexo, b: Complexo): Complexo =>
+  complexo(a.re - b.re, a.im - b.im);
+export const multiplicar = (a: Complexo, b: Complexo): Complexo =>
+  complexo(a.re * b.re - a.im * b.im, a.re * b.im + a.im * b.re);
+export const escalar = (a: Complexo, k: number): Complexo =>
+  complexo(a.re * k, a.im * k);
+
+export function dividir(a: Complexo, b: Complexo): Complexo {
+  const modulo = b.re * b.re + b.im * b.im;
+  return complexo(
+    (a.re * b.re + a.im * b.im) / modulo,
+    (a.im * b.re - a.re * b.im) / modulo,
+  );
+}
+
+export function exponencial(a: Complexo): Complexo {
+  const fator = Math.exp(a.re);
+  return complexo(fator * Math.cos(a.im), fator * Math.sin(a.im));
+}
+
+export function logaritmo(a: Complexo): Complexo {
+  return complexo(Math.log(Math.hypot(a.re, a.im)), Math.atan2(a.im, a.re));
+}
+
+/** Raiz principal (parte real >= 0). */
+export function raiz(a: Complexo): Complexo {
+  const modulo = Math.hypot(a.re, a.im);
+  const re = Math.sqrt(0.5 * (modulo + a.re));
+  const im = Math.sqrt(Math.max(0.5 * (modulo - a.re), 0));
+  return complexo(re, a.im < 0 ? -im : im);
+}
+
+/** Funcao caracteristica de X = ln(F_T / F), avaliada em argumento complexo. */
+export type FuncaoCaracteristica = (u: Complexo) => Complexo;
+
+export type ConfigFourier = {
+  /** Variancia de X, usada no Black de controle e no ponto de sela. */
+  varianciaTotal: number;
+  /**
+   * Variancia da parte difusiva (modelos com saltos): o integrando passa a ter duas
+   * escalas, a dos saltos e a da difusao, e a integracao fica adaptativa.
+   */
+  varianciaDifusao?: number;
+  /** Se E[e^{wX}] e finito com folga; limita o deslocamento do contorno. */
+  momentoFinito?: (w: number) => boolean;
+};
+
+const PONTOS_LAGUERRE = 64;
+
+/**
+ * Nos e pesos de Gauss-Laguerre (Newton sobre a recorrencia, como no gaulag do
+ * Numerical Recipes); o peso ja vem multiplicado por e^x para integrar f direto.
+ */
+function nosLaguerre(n: number): { nos: Float64Array; pesos: Float64Array } {
+  const nos = new Float64Array(n);
+  const pesos = new Float64Array(n);
+  let z = 0;
+  for (let i = 0; i < n; i += 1) {
+    if (i === 0) {
+      z = 3 / (1 + 2.4 * n);
+    } else if (i === 1) {
+      z += 15 / (1 + 2.5 * n);
+    } else {
+      const ai = i - 1;
+      z += ((1 + 2.55 * ai) / (1.9 * ai)) * (z - nos[i - 2]);
+    }
+    let p1 = 1;
+    let p2 = 0;
+    let derivada = 0;
+    for (let iteracao = 0; iteracao < 100; iteracao += 1) {
+      p1 = 1;
+      p2 = 0;
+      for (let j = 1; j <= n; j += 1) {
+        const p3 = p2;
+        p2 = p1;
+        p1 = ((2 * j - 1 - z) * p2 - (j - 1) * p3) / j;
+      }
+      derivada = (n * p1 - n * p2) / z;
+      const anterior = z;
+      z = anterior - p1 / derivada;
+      if (Math.abs(z - anterior) <= 1e-14 * Math.max(1, z)) break;
+    }
+    nos[i] = z;
+    pesos[i] = Math.exp(z) / (z * derivada * derivada);
+  }
+  return { nos, pesos };
+}
+
+const LAGUERRE = nosLaguerre(PONTOS_LAGUERRE);
+
+// Gauss-Kronrod 7-15 (QUADPACK): nos positivos de Kronrod; os de indice impar sao
+// os de Gauss.
+const NOS_KRONROD = [
+  0.991455371120812639, 0.949107912342758525, 0.864864423359769073,
+  0.741531185599394440, 0.586087235467691130, 0.405845151377397167,
+  0.207784955007898468, 0,
+];
+const PESOS_KRONROD = [
+  0.022935322010529225, 0.063092092629978553, 0.104790010322250184,
+  0.140653259715525919, 0.169004726639267903, 0.190350578064785410,
+  0.204432940075298892, 0.209482141084727828,
+];
+const PESOS_GAUSS = [
+  0.129484966168869693, 0.279705391489276668, 0.381830050505118945,
+  0.417959183673469388,
+];
+const PROFUNDIDADE_MAXIMA = 30;
+
+// Integral de f em [a, b] por bisseccao ate Kronrod e Gauss concordarem.
+function integrarKronrod(
+  f: (u: number) => number,
+  a: number,
+  b: number,
+  tolerancia: number,
+  profundidade = 0,
+): number {
+  const centro = 0.5 * (a + b);
+  const meio = 0.5 * (b - a);
+  const fCentro = f(centro);
+  let kronrod = PESOS_KRONROD[7] * fCentro;
+  let gauss = PESOS_GAUSS[3] * fCentro;
+  for (let i = 0; i < 7; i += 1) {
+    const soma = f(centro - meio * NOS_KRONROD[i]) + f(centro + meio * NOS_KRONROD[i]);
+    kronrod += PESOS_KRONROD[i] * soma;
+    if (i % 2 === 1) gauss += PESOS_GAUSS[(i - 1) / 2] * soma;
+  }
+  kronrod *= meio;
+  gauss *= meio;
+  if (Math.abs(kronrod - gauss) <= tolerancia || profundidade >= PROFUNDIDADE_MAXIMA) {
+    return kronrod;
+  }
+  return (
+    integrarKronrod(f, a, centro, tolerancia / 2, profundidade + 1) +
+    integrarKronrod(f, centro, b, tolerancia / 2, profundidade + 1)
+  );
+}
+
+// Ponto de sela do integrando de Black no contorno deslocado: raiz s > 1/2 de
+// V s - 2 s / (s^2 - 1/4) = |k|.
+function pontoDeSela(k: number, varianciaTotal: number): number {
+  const alvo = Math.abs(k);
+  const g = (s: number) => varianciaTotal * s - (2 * s) / (s * s - 0.25);
+  let baixo = 0.5;
+  let alto = 1.5;
+  while (g(alto) < alvo) alto = 0.5 + 2 * (alto - 0.5);
+  for (let i = 0; i < 60; i += 1) {
+    const meio = 0.5 * (baixo + alto);
+    if (g(meio) < alvo) baixo = meio;
+    else alto = meio;
+  }
+  return 0.5 * (baixo + alto);
+}
+
+/**
+ * Momento w = alpha + 1 do contorno (Lord & Kahl, 2007): minimiza o integrando em
+ * u = 0, e^{-alpha k} E[e^{wX}] / (alpha (alpha + 1)), funcao convexa de w em cada
+ * lado, por busca ternaria ate o dobro da sela de Black. Null quando nenhum
+ * momento desse lado e seguro (usa-se o contorno de Lewis).
+ */
+function momentoDoContorno(
+  phi: FuncaoCaracteristica,
+  k: number,
+  varianciaTotal: number,
+  momentoFinito: (w: number) => boolean,
+): number | null {
+  const lado = k >= 0 ? 1 : -1;
+  const momento = (s: number) => 0.5 + lado * s;
+  let limite = 0.5 + 2 * (pontoDeSela(k, varianciaTotal) - 0.5);
+  while (limite > 0.55 && !momentoFinito(momento(limite))) {
+    limite = 0.5 + (limite - 0.5) / 2;
+  }
+  if (!momentoFinito(momento(limite))) return null;
+
+  const objetivo = (s: number) => {
+    const w = momento(s);
+    const alpha = w - 1;
+    const valor = phi(complexo(0, -w)).re;
+    return valor > 0 && Number.isFinite(valor)
+      ? -alpha * k + Math.log(valor) - Math.log(alpha * (alpha + 1))
+      : Infinity;
+  };
+  let baixo = 0.5;
+  let alto = limite;
+  for (let i = 0; i < 80; i += 1) {
+    const m1 = baixo + (alto - baixo) / 3;
+    const m2 = alto - (alto - baixo) / 3;
+    if (objetivo(m1) <= objetivo(m2)) alto = m2;
+    else baixo = m1;
+  }
+  const s = 0.5 * (baixo + alto);
+  return Number.isFinite(objetivo(s)) ? momento(s) : null;
+}
+
+/**
+ * Call nao descontada em unidades de F, E[(F_T/F - K/F)^+], por Carr-Madan com
+ * amortecimento alpha e integracao de Gauss-Laguerre (Gauss-Kronrod adaptativo
+ * com saltos): para k = ln(K/F) >= 0 integra a call (alpha > 0) e, abaixo, a put
+ * (alpha < -1), com alpha escolhido por momentoDoContorno (sem momento seguro
+ * volta ao contorno de Lewis, alpha = -1/2). Integra so a diferenca para a funcao
+ * caracteristica de um Black com a mesma variancia (controle de
+ * Andersen-Piterbarg), somando o Black fechado: o integrando fica suave mesmo em
+ * prazos curtos e strikes longe do dinheiro.
+ */
+export function callNormalizadaFourier(
+  phi: FuncaoCaracteristica,
+  k: number,
+  {
+    varianciaTotal: variancia,
+    varianciaDifusao,
+    momentoFinito = () => true,
+  }: ConfigFourier,
+): number {
+  const varianciaTotal = Math.max(variancia, 1e-12);
+  const desvio = Math.sqrt(varianciaTotal);
+
+  const contorno = momentoDoContorno(phi, k, varianciaTotal, momentoFinito);
+  const lewis = contorno === null;
+  const w = contorno ?? 0.5;
+  const alpha = w - 1;
+
+  const d1 = -k / desvio + 0.5 * desvio;
+  const d2 = d1 - desvio;
+  const callBlack = normalCdf(d1) - Math.exp(k) * normalCdf(d2);
+  const putBlack = Math.exp(k) * normalCdf(-d2) - normalCdf(-d1);
+
+  const integrando = (u: number) => {
+    const valor = phi(complexo(u, -w));
+    // Black: phi(u - iw) = exp(-V/2 (u^2 + w - w^2 + iu(1 - 2w))).
+    const controle = escalar(
+      exponencial(complexo(0, -0.5 * varianciaTotal * u * (1 - 2 * w))),
+      Math.exp(-0.5 * varianciaTotal * (u * u + w - w * w)),
+    );
+    const denominador = multiplicar(complexo(alpha, u), complexo(alpha + 1, u));
+    const fase = complexo(Math.cos(u * k), -Math.sin(u * k));
+    return multiplicar(fase, dividir(subtrair(valor, controle), denominador)).re;
+  };
+
+  let integral = 0;
+  if (varianciaDifusao === undefined) {
+    const escala = 1 / (8 * desvio);
+    for (let i = 0; i < PONTOS_LAGUERRE; i += 1) {
+      integral += LAGUERRE.pesos[i] * integrando(LAGUERRE.nos[i] * escala);
+    }
+    integral *= escala;
+  } else {
+    // Paineis geometricos da escala total ate onde a difusao zera o integrando.
+    const fim = Math.sqrt(140 / Math.max(varianciaDifusao, 1e-12));
+    const tolerancia = 1e-12 * Math.PI * Math.exp(alpha * k);
+    let inicio = 0;
+    let corte = 0.5 / desvio;
+    while (inicio < fim) {
+      const ate = Math.min(corte, fim);
+      integral += integrarKronrod(integrando, inicio, ate, tolerancia);
+      inicio = ate;
+      corte *= 2;
+    }
+  }
+  integral *= Math.exp(-alpha * k) / Math.PI;
+
+  // Lewis e a sela da call somam a call de Black; a sela da put soma a put de
+  // Black e volta para a call pela paridade.
+  return k >= 0 || lewis
+    ? callBlack + integral
+    : putBlack + integral + 1 - Math.exp(k);
+}
@@ -0,0 +1,144 @@
+import {
+  type ResultadoGregas,
+  calculaGregas,
+  calcularTempoEmAnos,
+  ensurePositive,
+  normalCdf,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+
+export interface ResultadoGregasFx extends ResultadoGregas {
+  /** Variacao do preco para +1 ponto percentual na taxa estrangeira. */
+  rhoEstrangeiroCall: number;
+  rhoEstrangeiroPut: number;
+}
+
+export type CotacaoPremioFx = {
+  /** Premio em moeda domestica (BRL) por unidade de moeda estrangeira. */
+  premio: number;
+  /** Premio em pips domesticos (1 pip = 0,0001 BRL). */
+  pips: number;
+  /** Premio como percentual do nocional estrangeiro (premio / S). */
+  percentualNocional: number;
+  /** Premio total em BRL para o nocional informado. */
+  total: number;
+};
+
+const PIPS_POR_UNIDADE = 10000;
+
+function calcularD1D2(
+  S: number,
+  K: number,
+  rd: number,
+  rf: number,
+  sigma: number,
+  T: number,
+): { d1: number; d2: number } {
+  const sqrtT = Math.sqrt(T);
+  const d1 =
+    (Math.log(S / K) + (rd - rf + 0.5 * sigma * sigma) * T) / (sigma * sqrtT);
+  return { d1, d2: d1 - sigma * sqrtT };
+}
+
+/**
+ * Garman-Kohlhagen para call de cambio (ex.: USD/BRL).
+ * S = BRL por unidade estrangeira, rd = taxa domestica (DI), rf = taxa estrangeira.
+ */
+export function garmanKohlhagenCall(
+  S: number,
+  K: number,
+  rd: number,
+  rf: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return Math.max(S - K, 0);
+  }
+
+  const { d1, d2 } = calcularD1D2(S, K, rd, rf, ensurePositive(sigma), T);
+  return (
+    S * Math.exp(-rf * T) * normalCdf(d1) -
+    K * Math.exp(-rd * T) * normalCdf(d2)
+  );
+}
+
+/**
+ * Garman-Kohlhagen para put de cambio.
+ */
+export function garmanKohlhagenPut(
+  S: number,
+  K: number,
+  rd: number,
+  rf: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return Math.max(K - S, 0);
+  }
+
+  const { d1, d2 } = calcularD1D2(S, K, rd, rf, ensurePositive(sigma), T);
+  return (
+    K * Math.exp(-rd * T) * normalCdf(-d2) -
+    S * Math.exp(-rf * T) * normalCdf(-d1)
+  );
+}
+
+/**
+ * Gregas de Garman-Kohlhagen. A taxa estrangeira atua como um dividend yield,
+ * entao as gregas comuns vem de calculaGregas com q = rf.
+ */
+export function calculaGregasGarmanKohlhagen(
+  S: number,
+  K: number,
+  rd: number,
+  rf: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoGregasFx {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  const gregas = calculaGregas(
+    S,
+    K,
+    rd,
+    sigma,
+    dataAtual,
+    dataVencimento,
+    rf,
+    0,
+    calendario,
+  );
+  const { d1 } = calcularD1D2(S, K, rd, rf, ensurePositive(sigma), T);
+  const spotDesc = S * Math.exp(-rf * T);
+
+  return {
+    ...gregas,
+    rhoEstrangeiroCall: (-T * spotDesc * normalCdf(d1)) / 100,
+    rhoEstrangeiroPut: (T * spotDesc * normalCdf(-d1)) / 100,
+  };
+}
+
+/**
+ * Cotacoes usuais do premio de opcoes de cambio.
+ */
+export function cotarPremioFx(
+  premio: number,
+  S: number,
+  nocional: number,
+): CotacaoPremioFx {
+  return {
+    premio,
+    pips: premio * PIPS_POR_UNIDADE,
+    percentualNocional: (premio / S) * 100,
+    total: premio * nocional,
+  };
+}
@@ -0,0 +1,410 @@
+import { calcularTempoEmAnos } from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+import {
+  type Complexo,
+  callNormalizadaFourier,
+  complexo,
+  dividir,
+  escalar,
+  exponencial,
+  logaritmo,
+  multiplicar,
+  raiz,
+  somar,
+  subtrair,
+} from './fourier';
+import type { TipoOpcao } from './impliedVolatility';
+import type { CotacaoOpcao, MercadoSuperficie } from './volSurface';
+
+/**
+ * dv = kappa (theta - v) dt + xi sqrt(v) dW_v, com corr(dW_S, dW_v) = rho.
+ */
+export type ParametrosHeston = {
+  /** Variancia inicial (sigma^2 de hoje). */
+  v0: number;
+  /** Velocidade de reversao a media. */
+  kappa: number;
+  /** Variancia de longo prazo. */
+  theta: number;
+  /** Vol da variancia (vol da vol). */
+  xi: number;
+  rho: number;
+};
+
+export type AjusteCotacao = {
+  cotacao: CotacaoOpcao;
+  modelo: number;
+};
+
+export type ResultadoCalibracaoHeston = {
+  ok: true;
+  parametros: ParametrosHeston;
+  /** Raiz do erro quadratico medio dos premios. */
+  rmse: number;
+  iteracoes: number;
+  ajustes: AjusteCotacao[];
+};
+
+type Erro = { ok: false; error: string };
+
+/**
+ * Funcao caracteristica de ln(F_T / F) no Heston, na forma "little trap" de
+ * Albrecher et al. (2007), que evita o salto de ramo do logaritmo complexo.
+ */
+function funcaoCaracteristica(
+  u: Complexo,
+  T: number,
+  { v0, kappa, theta, xi, rho }: ParametrosHeston,
+): Complexo {
+  const iu = complexo(-u.im, u.re);
+  const xi2 = xi * xi;
+  const beta = subtrair(complexo(kappa), escalar(iu, rho * xi));
+  const d = raiz(
+    somar(multiplicar(beta, beta), escalar(somar(iu, multiplicar(u, u)), xi2)),
+  );
+  const menos = subtrair(beta, d);
+  const g = dividir(menos, somar(beta, d));
+  const expDT = exponencial(escalar(d, -T));
+  const umMenosGExp = subtrair(complexo(1), multiplicar(g, expDT));
+  const C = escalar(
+    subtrair(
+      escalar(menos, T),
+      escalar(logaritmo(dividir(umMenosGExp, subtrair(complexo(1), g))), 2),
+    ),
+    (kappa * theta) / xi2,
+  );
+  const D = multiplicar(
+    escalar(menos, 1 / xi2),
+    dividir(subtrair(complexo(1), expDT), umMenosGExp),
+  );
+  return exponencial(somar(C, escalar(D, v0)));
+}
+
+/**
+ * Instante em que o momento E[(F_T/F)^w] explode (Andersen-Piterbarg, 2007);
+ * Infinity se o momento e finito em qualquer prazo.
+ */
+function tempoExplosao(w: number, { kappa, xi, rho }: ParametrosHeston): number {
+  const beta = kappa - rho * xi * w;
+  const discriminante = beta * beta - xi * xi * w * (w - 1);
+  if (discriminante >= 0) {
+    if (beta > 0) return Infinity;
+    const d = Math.sqrt(discriminante);
+    return Math.log((beta - d) / (beta + d)) / d;
+  }
+  const gama = Math.sqrt(-discriminante);
+  return (2 / gama) * (Math.PI / 2 + Math.atan(beta / gama));
+}
+
+/**
+ * Call do Heston em R$: integral de Fourier da call normalizada, com o controle
+ * de Black na variancia media do prazo e o contorno limitado pela explosao dos
+ * momentos.
+ */
+function callHeston(
+  F: number,
+  K: number,
+  T: number,
+  desconto: number,
+  parametros: ParametrosHeston,
+): number {
+  const { v0, kappa, theta } = parametros;
+  const kappaT = kappa * T;
+  const fracao = kappaT > 1e-8 ? (1 - Math.exp(-kappaT)) / kappaT : 1 - kappaT / 2;
+  const normalizada = callNormalizadaFourier(
+    (u) => funcaoCaracteristica(u, T, parametros),
+    Math.log(K / F),
+    {
+      varianciaTotal: (theta + (v0 - theta) * fracao) * T,
+      // A margem evita a vizinhanca da explosao.
+      momentoFinito: (w) => tempoExplosao(w, parametros) >= 2 * T,
+    },
+  );
+  const preco = desconto * F * normalizada;
+  // Ruido de quadratura nao pode violar os limites de arbitragem.
+  return Math.min(Math.max(preco, desconto * Math.max(F - K, 0)), desconto * F);
+}
+
+/**
+ * Preco de call/put no Heston, com a mesma assinatura posicional dos
+ * precificadores fechados e os parametros do modelo no lugar de sigma.
+ */
+export function precoHeston(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  parametros: ParametrosHeston,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    return tipo === 'call' ? Math.max(S - K, 0) : Math.max(K - S, 0);
+  }
+
+  const F = S * Math.exp((r - q - taxaAluguel) * T);
+  const desconto = Math.exp(-r * T);
+  const call = callHeston(F, K, T, desconto, parametros);
+  // Paridade put-call.
+  return tipo === 'call' ? call : call - desconto * (F - K);
+}
+
+export function hestonCall(
+  S: number,
+  K: number,
+  r: number,
+  parametros: ParametrosHeston,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  return precoHeston(
+    'call',
+    S,
+    K,
+    r,
+    parametros,
+    dataAtual,
+    dataVencimento,
+    q,
+    taxaAluguel,
+    calendario,
+  );
+}
+
+export function hestonPut(
+  S: number,
+  K: number,
+  r: number,
+  parametros: ParametrosHeston,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  return precoHeston(
+    'put',
+    S,
+    K,
+    r,
+    parametros,
+    dataAtual,
+    dataVencimento,
+    q,
+    taxaAluguel,
+    calendario,
+  );
+}
+
+/**
+ * Mensagem de erro para parametros fora do dominio, ou null.
+ */
+export function validarParametrosHeston(parametros: ParametrosHeston): string | null {
+  const { v0, kappa, theta, xi, rho } = parametros;
+  if (!(v0 > 0) || !(kappa > 0) || !(theta > 0) || !(xi > 0)) {
+    return 'v0, kappa, theta e xi devem ser maiores que zero.';
+  }
+  if (!(rho > -1 && rho < 1)) {
+    return 'rho deve estar entre -1 e 1.';
+  }
+  return null;
+}
+
+/** Condicao de Feller 2 kappa theta >= xi^2: a variancia nao toca zero. */
+export function satisfazFeller({ kappa, theta, xi }: ParametrosHeston): boolean {
+  return 2 * kappa * theta >= xi * xi;
+}
+
+// Caixa da calibracao: [min, max] de v0, kappa, theta, xi e rho. Os positivos
+// variam em escala log; a caixa impede que xi colapse a zero e trave rho.
+const LIMITES_CALIBRACAO: [number, number, boolean][] = [
+  [1e-4, 4, true],
+  [1e-2, 20, true],
+  [1e-4, 4, true],
+  [1e-2, 5, true],
+  [-0.999, 0.999, false],
+];
+
+// Parametrizacao irrestrita: logit da posicao relativa dentro da caixa.
+function paraIrrestrito(p: ParametrosHeston): number[] {
+  return [p.v0, p.kappa, p.theta, p.xi, p.rho].map((valor, j) => {
+    const [minimo, maximo, logaritmica] = LIMITES_CALIBRACAO[j];
+    const escala = logaritmica ? Math.log : (v: number) => v;
+    const fracao = (escala(valor) - escala(minimo)) / (escala(maximo) - escala(minimo));
+    const limitada = Math.min(Math.max(fracao, 1e-6), 1 - 1e-6);
+    return Math.log(limitada / (1 - limitada));
+  });
+}
+
+function deIrrestrito(x: number[]): ParametrosHeston {
+  const [v0, kappa, theta, xi, rho] = x.map((valor, j) => {
+    const [minimo, maximo, logaritmica] = LIMITES_CALIBRACAO[j];
+    const fracao = 1 / (1 + Math.exp(-valor));
+    return logaritmica
+      ? minimo * (maximo / minimo) ** fracao
+      : minimo + (maximo - minimo) * fracao;
+  });
+  return { v0, kappa, theta, xi, rho };
+}
+
+// Resolve A dx = g por eliminacao de Gauss com pivoteamento parcial.
+function resolverSistema(A: number[][], g: number[]): number[] | null {
+  const n = g.length;
+  const M = A.map((linha, i) => [...linha, g[i]]);
+  for (let col = 0; col < n; col += 1) {
+    let pivo = col;
+    for (let i = col + 1; i < n; i += 1) {
+      if (Math.abs(M[i][col]) > Math.abs(M[pivo][col])) pivo = i;
+    }
+    if (Math.abs(M[pivo][col]) < 1e-300) return null;
+    [M[col], M[pivo]] = [M[pivo], M[col]];
+    for (let i = col + 1; i < n; i += 1) {
+      const fator = M[i][col] / M[col][col];
+      for (let j = col; j <= n; j += 1) M[i][j] -= fator * M[col][j];
+    }
+  }
+  const x = new Array<number>(n).fill(0);
+  for (let i = n - 1; i >= 0; i -= 1) {
+    let soma = M[i][n];
+    for (let j = i + 1; j < n; j += 1) soma -= M[i][j] * x[j];
+    x[i] = soma / M[i][i];
+  }
+  return x;
+}
+
+/**
+ * Calibra o Heston aos premios cotados por minimos quadrados (Levenberg-Marquardt
+ * com jacobiano por diferencas finitas), nos parametros transformados para
+ * manter v0, kappa, theta, xi > 0 e |rho| < 1.
+ */
+export function calibrarHeston(
+  cotacoes: CotacaoOpcao[],
+  mercado: MercadoSuperficie,
+  inicial: ParametrosHeston,
+  {
+    maxIteracoes = 100,
+    tolerancia = 1e-10,
+  }: { maxIteracoes?: number; tolerancia?: number } = {},
+): ResultadoCalibracaoHeston | Erro {
+  const invalido = validarParametrosHeston(inicial);
+  if (invalido) {
+    return { ok: false, error: invalido };
+  }
+  const { S, r, q, taxaAluguel, dataAtual, calendario } = mercado;
+  const validas = cotacoes.filter(
+    (cotacao) => calcularTempoEmAnos(dataAtual, cotacao.dataVencimento, calendario) > 0,
+  );
+  if (validas.length < 5) {
+    return {
+      ok: false,
+      error: 'Calibracao exige ao menos 5 cotacoes com vencimento futuro.',
+    };
+  }
+
+  const residuos = (x: number[]) => {
+    const parametros = deIrrestrito(x);
+    return validas.map(
+      (cotacao) =>
+        precoHeston(
+          cotacao.tipo,
+          S,
+          cotacao.K,
+          r,
+          parametros,
+          dataAtual,
+          cotacao.dataVencimento,
+          q,
+          taxaAluguel,
+          calendario,
+        ) - cotacao.premio,
+    );
+  };
+  const somaQuadrados = (res: number[]) => res.reduce((soma, e) => soma + e * e, 0);
+
+  // Levenberg-Marquardt a partir de x0; devolve o ponto final e seu custo.
+  const ajustar = (x0: number[]) => {
+    let x = x0;
+    let res = residuos(x);
+    let custo = somaQuadrados(res);
+    let mu = 1;
+    let iteracoes = 0;
+    let convergiu = false;
+
+    while (!convergiu && iteracoes < maxIteracoes) {
+      iteracoes += 1;
+      const jacobiano = x.map((_, j) => {
+        const passo = 1e-5 * Math.max(1, Math.abs(x[j]));
+        const deslocado = [...x];
+        deslocado[j] += passo;
+        return residuos(deslocado).map((valor, i) => (valor - res[i]) / passo);
+      });
+      const JtJ = x.map((_, a) =>
+        x.map((__, b) =>
+          jacobiano[a].reduce((soma, v, i) => soma + v * jacobiano[b][i], 0),
+        ),
+      );
+      const gradiente = x.map((_, a) =>
+        jacobiano[a].reduce((soma, v, i) => soma - v * res[i], 0),
+      );
+
+      let melhorou = false;
+      while (mu < 1e12) {
+        const amortecido = JtJ.map((linha, a) =>
+          linha.map((valor, b) => (a === b ? valor * (1 + mu) + 1e-12 : valor)),
+        );
+        const passo = resolverSistema(amortecido, gradiente);
+        if (passo) {
+          const candidato = x.map((valor, j) => valor + passo[j]);
+          const resCandidato = residuos(candidato);
+          const custoCandidato = somaQuadrados(resCandidato);
+          if (Number.isFinite(custoCandidato) && custoCandidato < custo) {
+            const reducao = custo - custoCandidato;
+            x = candidato;
+            res = resCandidato;
+            custo = custoCandidato;
+            mu = Math.max(mu / 3, 1e-12);
+            melhorou = true;
+            convergiu = reducao <= tolerancia * Math.max(custo, 1e-12);
+            break;
+          }
+        }
+        mu *= 4;
+      }
+      // Sem passo que reduza o custo: minimo local.
+      if (!melhorou) convergiu = true;
+    }
+    return { x, res, custo, iteracoes };
+  };
+
+  // O custo tem minimos locais nos cantos (xi pequeno, |rho| ~ 1): alem do chute
+  // do usuario, parte de dois pontos tipicos com a mesma variancia e fica com o melhor.
+  const partidas: ParametrosHeston[] = [
+    inicial,
+    { ...inicial, kappa: 2, xi: 0.5, rho: -0.5 },
+    { ...inicial, kappa: 1, xi: 1, rho: 0 },
+  ];
+  let melhor = ajustar(paraIrrestrito(inicial));
+  let iteracoes = melhor.iteracoes;
+  for (const partida of partidas.slice(1)) {
+    const tentativa = ajustar(paraIrrestrito(partida));
+    iteracoes += tentativa.iteracoes;
+    if (tentativa.custo < melhor.custo) melhor = tentativa;
+  }
+  const { x, res, custo } = melhor;
+
+  const parametros = deIrrestrito(x);
+  return {
+    ok: true,
+    parametros,
+    rmse: Math.sqrt(custo / validas.length),
+    iteracoes,
+    ajustes: validas.map((cotacao, i) => ({ cotacao, modelo: cotacao.premio + res[i] })),
+  };
+}
@@ -0,0 +1,258 @@
+import { BUSINESS_DAYS_IN_YEAR } from './blackScholes';
+import { parseDate } from './dateHelpers';
+
+export type BarraOhlc = {
+  data: Date;
+  /** Abertura, maxima e minima sao opcionais: series so de fechamento valem para close-to-close e EWMA. */
+  abertura?: number;
+  maxima?: number;
+  minima?: number;
+  fechamento: number;
+};
+
+export type EstimadorVolatilidade =
+  | 'closeToClose'
+  | 'ewma'
+  | 'parkinson'
+  | 'garmanKlass'
+  | 'rogersSatchell'
+  | 'yangZhang';
+
+export const ESTIMADORES_VOLATILIDADE: Record<EstimadorVolatilidade, string> = {
+  closeToClose: 'Close-to-close',
+  ewma: 'EWMA (RiskMetrics)',
+  parkinson: 'Parkinson',
+  garmanKlass: 'Garman-Klass',
+  rogersSatchell: 'Rogers-Satchell',
+  yangZhang: 'Yang-Zhang',
+};
+
+/** Vols anualizadas (base 252); null quando a serie nao tem os campos necessarios. */
+export type EstimativasVolatilidade = Record<EstimadorVolatilidade, number | null>;
+
+export const LAMBDA_RISKMETRICS = 0.94;
+
+type Erro = { ok: false; error: string };
+
+type BarraCompleta = Required<BarraOhlc>;
+
+const COLUNAS: Record<keyof Omit<BarraOhlc, 'data'> | 'data', string[]> = {
+  data: ['date', 'data'],
+  abertura: ['open', 'abertura'],
+  maxima: ['high', 'maxima', 'max'],
+  minima: ['low', 'minima', 'min'],
+  fechamento: ['close', 'adj close', 'adjclose', 'fechamento'],
+};
+
+function parseDataSerie(valor: string): Date | null {
+  const texto = valor.trim();
+  const iso = /^(\d{4})-(\d{2})-(\d{2})/.exec(texto);
+  if (iso) {
+    return new Date(Number(iso[1]), Number(iso[2]) - 1, Number(iso[3]));
+  }
+  return parseDate(texto);
+}
+
+/**
+ * Aceita numero ou texto; com virgula, trata-a como decimal ("1.234,56").
+ */
+function parseNumeroSerie(valor: unknown): number | undefined {
+  if (typeof valor === 'number') {
+    return Number.isFinite(valor) ? valor : undefined;
+  }
+  if (typeof valor !== 'string' || !valor.trim()) {
+    return undefined;
+  }
+  const texto = valor.includes(',')
+    ? valor.replace(/\./g, '').replace(',', '.')
+    : valor;
+  const numero = Number(texto.trim());
+  return Number.isFinite(numero) ? numero : undefined;
+}
+
+function montarBarra(campos: Record<string, unknown>): BarraOhlc | null {
+  const buscar = (chave: keyof typeof COLUNAS) => {
+    const nome = Object.keys(campos).find((k) =>
+      COLUNAS[chave].includes(k.trim().toLowerCase()),
+    );
+    return nome === undefined ? undefined : campos[nome];
+  };
+
+  const dataBruta = buscar('data');
+  const data =
+    typeof dataBruta === 'number'
+      ? new Date(dataBruta * (dataBruta < 1e11 ? 1000 : 1))
+      : typeof dataBruta === 'string'
+        ? parseDataSerie(dataBruta)
+        : null;
+  const fechamento = parseNumeroSerie(buscar('fechamento'));
+  if (!data || fechamento === undefined || fechamento <= 0) {
+    return null;
+  }
+
+  return {
+    data,
+    abertura: parseNumeroSerie(buscar('abertura')),
+    maxima: parseNumeroSerie(buscar('maxima')),
+    minima: parseNumeroSerie(buscar('minima')),
+    fechamento,
+  };
+}
+
+/**
+ * Converte registros ja estruturados (ex.: JSON de um provedor) em barras
+ * validas, em ordem cronologica.
+ */
+export function barrasDeRegistros(registros: unknown[]): BarraOhlc[] {
+  return registros
+    .filter((item): item is Record<string, unknown> => !!item && typeof item === 'object')
+    .map((registro) => montarBarra(registro))
+    .filter((barra): barra is BarraOhlc => barra !== null)
+    .sort((a, b) => a.data.getTime() - b.data.getTime());
+}
+
+/**
+ * Le uma serie diaria em CSV (cabecalho com date/open/high/low/close, separador
+ * "," ou ";") ou JSON (lista de objetos com as mesmas chaves, ou { data: [...] }).
+ * Linhas sem data ou fechamento validos sao ignoradas; a serie sai em ordem cronologica.
+ */
+export function parseSerieOhlc(
+  texto: string,
+): { ok: true; barras: BarraOhlc[]; ignoradas: number } | Erro {
+  const conteudo = texto.trim();
+  if (!conteudo) {
+    return { ok: false, error: 'Informe a serie de precos.' };
+  }
+
+  let registros: Record<string, unknown>[];
+  if (conteudo.startsWith('[') || conteudo.startsWith('{')) {
+    try {
+      const json = JSON.parse(conteudo);
+      const lista = Array.isArray(json) ? json : json?.data;
+      if (!Array.isArray(lista)) {
+        return { ok: false, error: 'JSON deve ser uma lista de barras.' };
+      }
+      registros = lista.filter((item) => item && typeof item === 'object');
+    } catch {
+      return { ok: false, error: 'JSON invalido.' };
+    }
+  } else {
+    const linhas = conteudo.split(/\r?\n/).filter((linha) => linha.trim());
+    const separador = linhas[0].includes(';') ? ';' : ',';
+    const cabecalho = linhas[0].split(separador).map((c) => c.trim());
+    registros = linhas.slice(1).map((linha) => {
+      const valores = linha.split(separador);
+      return Object.fromEntries(cabecalho.map((nome, i) => [nome, valores[i] ?? '']));
+    });
+  }
+
+  const barras = barrasDeRegistros(registros);
+  if (barras.length < 2) {
+    return {
+      ok: false,
+      error: 'Serie precisa de ao menos duas barras com data e fechamento.',
+    };
+  }
+  return { ok: true, barras, ignoradas: registros.length - barras.length };
+}
+
+function anualizar(varianciaDiaria: number): number {
+  return Math.sqrt(Math.max(varianciaDiaria, 0) * BUSINESS_DAYS_IN_YEAR);
+}
+
+function media(valores: number[]): number {
+  return valores.reduce((soma, v) => soma + v, 0) / valores.length;
+}
+
+/** Variancia amostral (n - 1). */
+function variancia(valores: number[]): number {
+  const m = media(valores);
+  return valores.reduce((soma, v) => soma + (v - m) ** 2, 0) / (valores.length - 1);
+}
+
+function temOhlc(barra: BarraOhlc): barra is BarraCompleta {
+  const { abertura, maxima, minima } = barra;
+  return (
+    abertura !== undefined &&
+    maxima !== undefined &&
+    minima !== undefined &&
+    abertura > 0 &&
+    minima > 0 &&
+    maxima >= minima
+  );
+}
+
+/**
+ * Termo diario de Rogers-Satchell: ln(H/C)ln(H/O) + ln(L/C)ln(L/O).
+ */
+function termoRogersSatchell({ abertura, maxima, minima, fechamento }: BarraCompleta): number {
+  return (
+    Math.log(maxima / fechamento) * Math.log(maxima / abertura) +
+    Math.log(minima / fechamento) * Math.log(minima / abertura)
+  );
+}
+
+/**
+ * Estimadores de volatilidade historica sobre as ultimas `janela` barras
+ * (todas quando omitida). Estimadores de amplitude exigem OHLC em toda a janela.
+ */
+export function estimarVolatilidades(
+  barras: BarraOhlc[],
+  janela?: number,
+  lambda = LAMBDA_RISKMETRICS,
+): EstimativasVolatilidade {
+  // Uma barra extra antes da janela fornece o fechamento anterior.
+  const inicio = janela ? Math.max(barras.length - janela - 1, 0) : 0;
+  const serie = barras.slice(inicio);
+  const atuais = serie.slice(1);
+  const retornos = atuais.map((barra, i) => Math.log(barra.fechamento / serie[i].fechamento));
+
+  const closeToClose = retornos.length >= 2 ? anualizar(variancia(retornos)) : null;
+
+  // RiskMetrics: sigma2_t = lambda * sigma2_{t-1} + (1 - lambda) * r_t^2, semeado com r_1^2.
+  const ewma =
+    retornos.length >= 1
+      ? anualizar(
+        retornos
+          .slice(1)
+          .reduce((s2, r) => lambda * s2 + (1 - lambda) * r * r, retornos[0] ** 2),
+      )
+      : null;
+
+  if (!atuais.every(temOhlc) || atuais.length < 2) {
+    return {
+      closeToClose,
+      ewma,
+      parkinson: null,
+      garmanKlass: null,
+      rogersSatchell: null,
+      yangZhang: null,
+    };
+  }
+
+  const barrasOhlc = atuais as BarraCompleta[];
+  const amplitude = barrasOhlc.map((b) => Math.log(b.maxima / b.minima) ** 2);
+  const corpo = barrasOhlc.map((b) => Math.log(b.fechamento / b.abertura));
+  const rogersSatchell = media(barrasOhlc.map(termoRogersSatchell));
+
+  const parkinson = media(amplitude) / (4 * Math.LN2);
+  const garmanKlass = media(
+    amplitude.map((hl, i) => 0.5 * hl - (2 * Math.LN2 - 1) * corpo[i] ** 2),
+  );
+
+  // Yang-Zhang (2000): overnight + k * abertura-fechamento + (1 - k) * Rogers-Satchell.
+  const n = barrasOhlc.length;
+  const overnight = barrasOhlc.map((b, i) => Math.log(b.abertura / serie[i].fechamento));
+  const k = 0.34 / (1.34 + (n + 1) / (n - 1));
+  const yangZhang =
+    variancia(overnight) + k * variancia(corpo) + (1 - k) * rogersSatchell;
+
+  return {
+    closeToClose,
+    ewma,
+    parkinson: anualizar(parkinson),
+    garmanKlass: anualizar(garmanKlass),
+    rogersSatchell: anualizar(rogersSatchell),
+    yangZhang: anualizar(yangZhang),
+  };
+}
@@ -0,0 +1,249 @@
+export type Feriado = {
+  /** Data no formato AAAA-MM-DD. */
+  data: string;
+  nome: string;
+};
+
+export type CalendarioFeriados = {
+  id: string;
+  nome: string;
+  /** Feriados do ano informado (podem incluir fins de semana). */
+  feriadosDoAno: (ano: number) => Feriado[];
+};
+
+function chaveData(ano: number, mes: number, dia: number): string {
+  return `${ano}-${`${mes}`.padStart(2, '0')}-${`${dia}`.padStart(2, '0')}`;
+}
+
+/**
+ * Chave AAAA-MM-DD de uma data UTC (mesma normalizacao de calcularDiasUteis).
+ */
+export function chaveDataUTC(date: Date): string {
+  return chaveData(
+    date.getUTCFullYear(),
+    date.getUTCMonth() + 1,
+    date.getUTCDate(),
+  );
+}
+
+/**
+ * Domingo de Pascoa pelo algoritmo gregoriano anonimo (Meeus/Jones/Butcher).
+ */
+export function calcularPascoa(ano: number): Date {
+  const a = ano % 19;
+  const b = Math.floor(ano / 100);
+  const c = ano % 100;
+  const d = Math.floor(b / 4);
+  const e = b % 4;
+  const f = Math.floor((b + 8) / 25);
+  const g = Math.floor((b - f + 1) / 3);
+  const h = (19 * a + b - d - g + 15) % 30;
+  const i = Math.floor(c / 4);
+  const k = c % 4;
+  const l = (32 + 2 * e + 2 * i - h - k) % 7;
+  const m = Math.floor((a + 11 * h + 22 * l) / 451);
+  const mes = Math.floor((h + l - 7 * m + 114) / 31);
+  const dia = ((h + l - 7 * m + 114) % 31) + 1;
+  return new Date(Date.UTC(ano, mes - 1, dia));
+}
+
+function deslocarPascoa(pascoa: Date, dias: number, nome: string): Feriado {
+  const data = new Date(pascoa);
+  data.setUTCDate(data.getUTCDate() + dias);
+  return { data: chaveDataUTC(data), nome };
+}
+
+/**
+ * Feriados nacionais usados pela ANBIMA para contagem de dias uteis.
+ */
+function feriadosAnbima(ano: number): Feriado[] {
+  const pascoa = calcularPascoa(ano);
+  const feriados: Feriado[] = [
+    { data: chaveData(ano, 1, 1), nome: 'Confraternizacao Universal' },
+    deslocarPascoa(pascoa, -48, 'Carnaval (segunda-feira)'),
+    deslocarPascoa(pascoa, -47, 'Carnaval (terca-feira)'),
+    deslocarPascoa(pascoa, -2, 'Sexta-feira Santa'),
+    { data: chaveData(ano, 4, 21), nome: 'Tiradentes' },
+    { data: chaveData(ano, 5, 1), nome: 'Dia do Trabalho' },
+    deslocarPascoa(pascoa, 60, 'Corpus Christi'),
+    { data: chaveData(ano, 9, 7), nome: 'Independencia do Brasil' },
+    { data: chaveData(ano, 10, 12), nome: 'Nossa Senhora Aparecida' },
+    { data: chaveData(ano, 11, 2), nome: 'Finados' },
+    { data: chaveData(ano, 11, 15), nome: 'Proclamacao da Republica' },
+    { data: chaveData(ano, 12, 25), nome: 'Natal' },
+  ];
+
+  // Lei 14.759/2023 tornou o 20 de novembro feriado nacional a partir de 2024.
+  if (ano >= 2024) {
+    feriados.push({
+      data: chaveData(ano, 11, 20),
+      nome: 'Dia Nacional de Zumbi e da Consciencia Negra',
+    });
+  }
+
+  return feriados.sort((a, b) => a.data.localeCompare(b.data));
+}
+
+// Ultimo dia de semana do ano: 31/12, ou a sexta anterior se cair no fim de semana.
+function ultimoDiaSemanaDoAno(ano: number): Date {
+  const data = new Date(Date.UTC(ano, 11, 31));
+  while (data.getUTCDay() === 0 || data.getUTCDay() === 6) {
+    data.setUTCDate(data.getUTCDate() - 1);
+  }
+  return data;
+}
+
+/**
+ * Calendario de pregao da B3: feriados ANBIMA mais vespera de Natal e
+ * ultimo dia util do ano, quando nao ha negociacao.
+ */
+function feriadosB3(ano: number): Feriado[] {
+  return [
+    ...feriadosAnbima(ano),
+    { data: chaveData(ano, 12, 24), nome: 'Vespera de Natal (sem pregao)' },
+    {
+      data: chaveDataUTC(ultimoDiaSemanaDoAno(ano)),
+      nome: 'Ultimo dia util do ano (sem pregao)',
+    },
+  ].sort((a, b) => a.data.localeCompare(b.data));
+}
+
+function comCache(
+  gerar: (ano: number) => Feriado[],
+): (ano: number) => Feriado[] {
+  const cache = new Map<number, Feriado[]>();
+  return (ano) => {
+    let feriados = cache.get(ano);
+    if (!feriados) {
+      feriados = gerar(ano);
+      cache.set(ano, feriados);
+    }
+    return feriados;
+  };
+}
+
+export const CALENDARIO_ANBIMA: CalendarioFeriados = {
+  id: 'anbima',
+  nome: 'ANBIMA (feriados nacionais)',
+  feriadosDoAno: comCache(feriadosAnbima),
+};
+
+export const CALENDARIO_B3: CalendarioFeriados = {
+  id: 'b3',
+  nome: 'B3 (pregao)',
+  feriadosDoAno: comCache(feriadosB3),
+};
+
+export const CALENDARIO_SEM_FERIADOS: CalendarioFeriados = {
+  id: 'nenhum',
+  nome: 'Somente fins de semana',
+  feriadosDoAno: () => [],
+};
+
+/**
+ * Cria um calendario a partir de uma lista fixa de feriados,
+ * opcionalmente somada a um calendario base.
+ */
+export function criarCalendarioPersonalizado(
+  id: string,
+  nome: string,
+  feriados: Feriado[],
+  base?: CalendarioFeriados,
+): CalendarioFeriados {
+  const porAno = new Map<number, Feriado[]>();
+  feriados.forEach((feriado) => {
+    const ano = Number(feriado.data.slice(0, 4));
+    const lista = porAno.get(ano) ?? [];
+    lista.push(feriado);
+    porAno.set(ano, lista);
+  });
+
+  return {
+    id,
+    nome,
+    feriadosDoAno: comCache((ano) =>
+      [...(base?.feriadosDoAno(ano) ?? []), ...(porAno.get(ano) ?? [])].sort(
+        (a, b) => a.data.localeCompare(b.data),
+      ),
+    ),
+  };
+}
+
+/**
+ * Le uma lista de feriados, uma data por linha.
+ * Formatos aceitos: "DD/MM/AAAA" ou "AAAA-MM-DD", seguidos opcionalmente
+ * de ";" ou "," e o nome do feriado.
+ */
+export function parseListaFeriados(
+  texto: string,
+): { ok: true; feriados: Feriado[] } | { ok: false; error: string } {
+  const feriados: Feriado[] = [];
+  const linhas = texto.split(/\r?\n/);
+
+  for (let i = 0; i < linhas.length; i += 1) {
+    const linha = linhas[i].trim();
+    if (!linha || linha.startsWith('#')) continue;
+
+    const [rawData, ...resto] = linha.split(/[;,]/);
+    const dataStr = rawData.trim();
+    const br = /^(\d{1,2})\/(\d{1,2})\/(\d{4})$/.exec(dataStr);
+    const iso = /^(\d{4})-(\d{1,2})-(\d{1,2})$/.exec(dataStr);
+    const [ano, mes, dia] = br
+      ? [Number(br[3]), Number(br[2]), Number(br[1])]
+      : iso
+        ? [Number(iso[1]), Number(iso[2]), Number(iso[3])]
+        : [NaN, NaN, NaN];
+
+    const date = new Date(Date.UTC(ano, mes - 1, dia));
+    if (
+      Number.isNaN(date.getTime()) ||
+      date.getUTCMonth() !== mes - 1 ||
+      date.getUTCDate() !== dia
+    ) {
+      return { ok: false, error: `Data invalida na linha ${i + 1}: ${dataStr}` };
+    }
+
+    const nome = resto.join(',').trim();
+    feriados.push({ data: chaveData(ano, mes, dia), nome: nome || 'Feriado' });
+  }
+
+  return { ok: true, feriados };
+}
+
+const calendarios = new Map<string, CalendarioFeriados>(
+  [CALENDARIO_B3, CALENDARIO_ANBIMA, CALENDARIO_SEM_FERIADOS].map((cal) => [
+    cal.id,
+    cal,
+  ]),
+);
+
+export function registrarCalendario(calendario: CalendarioFeriados): void {
+  calendarios.set(calendario.id, calendario);
+}
+
+export function obterCalendario(id: string): CalendarioFeriados | undefined {
+  return calendarios.get(id);
+}
+
+export function listarCalendarios(): CalendarioFeriados[] {
+  return Array.from(calendarios.values());
+}
+
+/**
+ * Indice dos feriados do calendario por data, cobrindo os anos informados.
+ */
+export function feriadosPorData(
+  calendario: CalendarioFeriados,
+  anoInicial: number,
+  anoFinal: number,
+): Map<string, Feriado> {
+  const mapa = new Map<string, Feriado>();
+  for (let ano = anoInicial; ano <= anoFinal; ano += 1) {
+    calendario.feriadosDoAno(ano).forEach((feriado) => {
+      if (!mapa.has(feriado.data)) {
+        mapa.set(feriado.data, feriado);
+      }
+    });
+  }
+  return mapa;
+}
@@ -0,0 +1,451 @@
+import { black76Call, black76Put } from './black76';
+import {
+  blackScholesCall,
+  blackScholesPut,
+  blackScholesCallModified,
+  blackScholesPutModified,
+  calcularTempoEmAnos,
+  normalPdf,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+
+export type TipoOpcao = 'call' | 'put';
+
+export type ResultadoVolImplicita =
+  | {
+    ok: true;
+    sigma: number;
+    iteracoes: number;
+    /** Outro sigma reproduz o mesmo premio (modelo nao monotono em sigma). */
+    naoUnica?: boolean;
+  }
+  | { ok: false; error: string };
+
+type OpcoesSolver = {
+  tolerancia?: number;
+  maxIteracoes?: number;
+  sigmaInicial?: number;
+};
+
+const SIGMA_MIN = 1e-6;
+const SIGMA_MAX = 10;
+const SIGMA_INICIAL = 0.3;
+const TOLERANCIA = 1e-10;
+const MAX_ITERACOES = 100;
+// Varredura em log(sigma) do modelo modificado, que nao e monotono em sigma.
+const PONTOS_VARREDURA = 200;
+const RAZAO_AUREA = (Math.sqrt(5) - 1) / 2;
+
+/**
+ * Resolve f(sigma) = alvo cercando a raiz a partir de SIGMA_MIN e refinando com
+ * refinarRaiz. Assume f crescente em sigma, como o preco de opcoes europeias.
+ */
+function resolverVolatilidade(
+  preco: (sigma: number) => number,
+  vega: ((sigma: number) => number) | null,
+  alvo: number,
+  { tolerancia = TOLERANCIA, maxIteracoes = MAX_ITERACOES, sigmaInicial = SIGMA_INICIAL }: OpcoesSolver,
+): ResultadoVolImplicita {
+  let a = SIGMA_MIN;
+  let b = sigmaInicial;
+  let fa = preco(a) - alvo;
+  let fb = preco(b) - alvo;
+
+  if (Math.abs(fa) <= tolerancia) {
+    return { ok: true, sigma: a, iteracoes: 0 };
+  }
+  if (fa > 0) {
+    return { ok: false, error: 'Premio abaixo do valor minimo do modelo.' };
+  }
+
+  // Expande o limite superior ate cercar a raiz.
+  while (fb < 0 && b < SIGMA_MAX) {
+    a = b;
+    fa = fb;
+    b = Math.min(b * 2, SIGMA_MAX);
+    fb = preco(b) - alvo;
+  }
+  if (fb < 0) {
+    return { ok: false, error: 'Premio acima do valor maximo do modelo.' };
+  }
+
+  return refinarRaiz(preco, vega, alvo, [a, fa], [b, fb], {
+    tolerancia,
+    maxIteracoes,
+    sigmaInicial,
+  });
+}
+
+/**
+ * Combina Newton (quando ha derivada e o passo cai dentro do intervalo) com
+ * Brent (interpolacao inversa/bisseccao) em um intervalo [a, b] onde
+ * f(sigma) - alvo troca de sinal, em qualquer sentido.
+ */
+function refinarRaiz(
+  preco: (sigma: number) => number,
+  vega: ((sigma: number) => number) | null,
+  alvo: number,
+  [a, fa]: [number, number],
+  [b, fb]: [number, number],
+  opcoes: OpcoesSolver,
+): ResultadoVolImplicita {
+  const {
+    tolerancia = TOLERANCIA,
+    maxIteracoes = MAX_ITERACOES,
+    sigmaInicial = SIGMA_INICIAL,
+  } = opcoes;
+  // Brent: b e a melhor estimativa, c o ponto anterior, [b, c] cerca a raiz.
+  let c = a;
+  let fc = fa;
+  let d = b - a;
+  let e = d;
+  let x = Math.min(Math.max(sigmaInicial, a), b);
+
+  for (let iter = 1; iter <= maxIteracoes; iter += 1) {
+    if (fb * fc > 0) {
+      c = a;
+      fc = fa;
+      d = b - a;
+      e = d;
+    }
+    if (Math.abs(fc) < Math.abs(fb)) {
+      a = b;
+      b = c;
+      c = a;
+      fa = fb;
+      fb = fc;
+      fc = fa;
+    }
+
+    const tol = 2 * Number.EPSILON * Math.abs(b) + 0.5 * tolerancia;
+    const m = 0.5 * (c - b);
+    if (Math.abs(m) <= tol || Math.abs(fb) <= tolerancia) {
+      return { ok: true, sigma: b, iteracoes: iter };
+    }
+
+    // Tentativa de Newton a partir da melhor estimativa.
+    const v = vega ? vega(b) : 0;
+    const newton = v !== 0 ? b - fb / v : NaN;
+    const lo = Math.min(b, c);
+    const hi = Math.max(b, c);
+    if (Number.isFinite(newton) && newton > lo && newton < hi) {
+      x = newton;
+      e = d;
+      d = x - b;
+    } else if (Math.abs(e) >= tol && Math.abs(fa) > Math.abs(fb)) {
+      const s = fb / fa;
+      let pNum: number;
+      let q: number;
+      if (a === c) {
+        pNum = 2 * m * s;
+        q = 1 - s;
+      } else {
+        const qa = fa / fc;
+        const r = fb / fc;
+        pNum = s * (2 * m * qa * (qa - r) - (b - a) * (r - 1));
+        q = (qa - 1) * (r - 1) * (s - 1);
+      }
+      if (pNum > 0) {
+        q = -q;
+      } else {
+        pNum = -pNum;
+      }
+      if (2 * pNum < Math.min(3 * m * q - Math.abs(tol * q), Math.abs(e * q))) {
+        e = d;
+        d = pNum / q;
+      } else {
+        d = m;
+        e = m;
+      }
+      x = b + d;
+    } else {
+      d = m;
+      e = m;
+      x = b + d;
+    }
+
+    a = b;
+    fa = fb;
+    b = Math.abs(x - b) > tol ? x : b + (m > 0 ? tol : -tol);
+    fb = preco(b) - alvo;
+  }
+
+  return { ok: false, error: 'Solver nao convergiu.' };
+}
+
+type PontoPreco = { sigma: number; valor: number };
+
+/** Precos em uma grade geometrica de sigma entre SIGMA_MIN e SIGMA_MAX. */
+function varrerPrecos(preco: (sigma: number) => number): PontoPreco[] {
+  const razao = Math.log(SIGMA_MAX / SIGMA_MIN) / PONTOS_VARREDURA;
+  return Array.from({ length: PONTOS_VARREDURA + 1 }, (_, i) => {
+    const sigma = i === PONTOS_VARREDURA ? SIGMA_MAX : SIGMA_MIN * Math.exp(razao * i);
+    return { sigma, valor: preco(sigma) };
+  });
+}
+
+/**
+ * Maximo do preco em sigma: melhor ponto da varredura refinado por secao aurea
+ * (em log sigma) entre os vizinhos.
+ */
+function maximoDoPreco(
+  preco: (sigma: number) => number,
+  pontos: PontoPreco[],
+): PontoPreco {
+  const i = pontos.reduce(
+    (melhor, ponto, j) => (ponto.valor > pontos[melhor].valor ? j : melhor),
+    0,
+  );
+  let a = Math.log(pontos[Math.max(i - 1, 0)].sigma);
+  let b = Math.log(pontos[Math.min(i + 1, pontos.length - 1)].sigma);
+  let x1 = b - RAZAO_AUREA * (b - a);
+  let x2 = a + RAZAO_AUREA * (b - a);
+  let f1 = preco(Math.exp(x1));
+  let f2 = preco(Math.exp(x2));
+  for (let iter = 0; iter < 60 && b - a > 1e-12; iter += 1) {
+    if (f1 >= f2) {
+      b = x2;
+      x2 = x1;
+      f2 = f1;
+      x1 = b - RAZAO_AUREA * (b - a);
+      f1 = preco(Math.exp(x1));
+    } else {
+      a = x1;
+      x1 = x2;
+      f1 = f2;
+      x2 = a + RAZAO_AUREA * (b - a);
+      f2 = preco(Math.exp(x2));
+    }
+  }
+  const refinado =
+    f1 >= f2 ? { sigma: Math.exp(x1), valor: f1 } : { sigma: Math.exp(x2), valor: f2 };
+  return refinado.valor > pontos[i].valor ? refinado : pontos[i];
+}
+
+/**
+ * Verifica os limites de nao-arbitragem para opcoes europeias, com S' = S e^{-qT}.
+ * Call: max(S' - K e^{-rT}, 0) <= C < S'. Put: max(K e^{-rT} - S', 0) <= P < K e^{-rT}.
+ */
+function checarLimites(
+  premio: number,
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  T: number,
+): string | null {
+  if (!(premio > 0)) {
+    return 'Premio deve ser maior que zero.';
+  }
+  if (T <= 0) {
+    return 'Vencimento deve ser posterior a data atual.';
+  }
+  const kDesc = K * Math.exp(-r * T);
+  const minimo = tipo === 'call' ? Math.max(S - kDesc, 0) : Math.max(kDesc - S, 0);
+  const maximo = tipo === 'call' ? S : kDesc;
+  if (premio < minimo) {
+    return 'Premio abaixo do valor intrinseco descontado (arbitragem).';
+  }
+  if (premio >= maximo) {
+    return tipo === 'call'
+      ? 'Premio da call deve ser menor que S descontado do rendimento (arbitragem).'
+      : 'Premio da put deve ser menor que K descontado (arbitragem).';
+  }
+  return null;
+}
+
+/**
+ * Volatilidade implicita do Black-Scholes classico a partir do premio observado.
+ */
+export function volatilidadeImplicita(
+  premio: number,
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  opcoes: OpcoesSolver = {},
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoVolImplicita {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  const y = q + taxaAluguel;
+  const sDesc = S * Math.exp(-y * T);
+  const erroLimite = checarLimites(premio, tipo, sDesc, K, r, T);
+  if (erroLimite) {
+    return { ok: false, error: erroLimite };
+  }
+
+  const precificar = tipo === 'call' ? blackScholesCall : blackScholesPut;
+  const sqrtT = Math.sqrt(T);
+  // Vega analitica (sem escala de 1%), igual para call e put.
+  const vega = (sigma: number) => {
+    const d1 =
+      (Math.log(S / K) + (r - y + 0.5 * sigma * sigma) * T) / (sigma * sqrtT);
+    return sDesc * normalPdf(d1) * sqrtT;
+  };
+
+  return resolverVolatilidade(
+    (sigma) =>
+      precificar(
+        S,
+        K,
+        r,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+        calendario,
+      ),
+    vega,
+    premio,
+    opcoes,
+  );
+}
+
+/**
+ * Volatilidade implicita do Black-Scholes modificado (parametro p).
+ * A derivada e obtida por diferenca central, pois A(tau) tambem depende de sigma.
+ * Com p != 1 o preco nao e monotono em sigma: o premio e comparado ao maximo do
+ * modelo e, havendo mais de uma raiz, vale a do primeiro trecho crescente.
+ */
+export function volatilidadeImplicitaModificada(
+  premio: number,
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  p: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  opcoes: OpcoesSolver = {},
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoVolImplicita {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (!(premio > 0)) {
+    return { ok: false, error: 'Premio deve ser maior que zero.' };
+  }
+  if (T <= 0) {
+    return { ok: false, error: 'Vencimento deve ser posterior a data atual.' };
+  }
+  // A(tau) pode exceder 1 quando p > 1, entao so o limite inferior e universal.
+  const kDesc = K * Math.exp(-r * T);
+  const sDesc = S * Math.exp(-(q + taxaAluguel) * T);
+  const minimo =
+    tipo === 'call' ? Math.max(sDesc - kDesc, 0) : Math.max(kDesc - sDesc, 0);
+  if (premio < minimo) {
+    return {
+      ok: false,
+      error: 'Premio abaixo do valor intrinseco descontado (arbitragem).',
+    };
+  }
+
+  const precificar =
+    tipo === 'call' ? blackScholesCallModified : blackScholesPutModified;
+  const preco = (sigma: number) =>
+    precificar(
+      S,
+      K,
+      r,
+      sigma,
+      p,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      calendario,
+    );
+  const vega = (sigma: number) => {
+    const h = Math.max(1e-5, sigma * 1e-4);
+    const baixo = Math.max(sigma - h, SIGMA_MIN / 2);
+    return (preco(sigma + h) - preco(baixo)) / (sigma + h - baixo);
+  };
+
+  const pontos = varrerPrecos(preco);
+  const maximo = maximoDoPreco(preco, pontos);
+  const { tolerancia = TOLERANCIA } = opcoes;
+  if (premio - maximo.valor > tolerancia) {
+    return {
+      ok: false,
+      error: `Premio acima do maximo do modelo (${maximo.valor.toFixed(4)}).`,
+    };
+  }
+  if (premio >= maximo.valor) {
+    return { ok: true, sigma: maximo.sigma, iteracoes: 0 };
+  }
+
+  // Com o maximo na grade, cada troca de sinal de preco - premio cerca uma raiz.
+  const grade = [
+    ...pontos.filter((ponto) => ponto.sigma < maximo.sigma),
+    maximo,
+    ...pontos.filter((ponto) => ponto.sigma > maximo.sigma),
+  ];
+  const intervalos: [PontoPreco, PontoPreco][] = [];
+  for (let i = 1; i < grade.length; i += 1) {
+    const fa = grade[i - 1].valor - premio;
+    const fb = grade[i].valor - premio;
+    if (fa * fb < 0 || (fb === 0 && fa !== 0)) {
+      intervalos.push([grade[i - 1], grade[i]]);
+    }
+  }
+  const escolhido =
+    intervalos.find(([a, b]) => b.valor > a.valor) ?? intervalos[0];
+  if (!escolhido) {
+    return { ok: false, error: 'Premio abaixo do valor minimo do modelo.' };
+  }
+
+  const [a, b] = escolhido;
+  const resultado = refinarRaiz(
+    preco,
+    vega,
+    premio,
+    [a.sigma, a.valor - premio],
+    [b.sigma, b.valor - premio],
+    opcoes,
+  );
+  return resultado.ok && intervalos.length > 1
+    ? { ...resultado, naoUnica: true }
+    : resultado;
+}
+
+/**
+ * Volatilidade implicita do Black-76 (opcoes sobre futuros).
+ * Os limites sao os do classico com F e^{-rT} no lugar do spot.
+ */
+export function volatilidadeImplicitaBlack76(
+  premio: number,
+  tipo: TipoOpcao,
+  F: number,
+  K: number,
+  r: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  opcoes: OpcoesSolver = {},
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoVolImplicita {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  const desconto = Math.exp(-r * T);
+  const erroLimite = checarLimites(premio, tipo, F * desconto, K, r, T);
+  if (erroLimite) {
+    return { ok: false, error: erroLimite };
+  }
+
+  const precificar = tipo === 'call' ? black76Call : black76Put;
+  const sqrtT = Math.sqrt(T);
+  const vega = (sigma: number) => {
+    const d1 = (Math.log(F / K) + 0.5 * sigma * sigma * T) / (sigma * sqrtT);
+    return F * desconto * normalPdf(d1) * sqrtT;
+  };
+
+  return resolverVolatilidade(
+    (sigma) => precificar(F, K, r, sigma, dataAtual, dataVencimento, calendario),
+    vega,
+    premio,
+    opcoes,
+  );
+}
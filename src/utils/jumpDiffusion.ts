@@ -0,0 +1,337 @@
+import {
+  type ResultadoGregas,
+  blackScholesCall,
+  blackScholesPut,
+  calcularTempoEmAnos,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+import {
+  type Complexo,
+  callNormalizadaFourier,
+  complexo,
+  dividir,
+  escalar,
+  exponencial,
+  multiplicar,
+  somar,
+  subtrair,
+} from './fourier';
+import type { TipoOpcao } from './impliedVolatility';
+
+export type ModeloSaltos = 'merton' | 'kou';
+
+export const MODELOS_SALTOS: Record<ModeloSaltos, string> = {
+  merton: 'Merton (saltos lognormais)',
+  kou: 'Kou (saltos dupla exponencial)',
+};
+
+/**
+ * Saltos de Poisson sobre o GBM; Y = ln(1 + salto) e o log-retorno de cada salto.
+ */
+export type ParametrosSaltos = {
+  /** Intensidade: saltos esperados por ano. */
+  lambda: number;
+  /** Media de Y. */
+  media: number;
+  /** Desvio padrao de Y. */
+  vol: number;
+  /** Kou: probabilidade de o salto ser de alta. */
+  probAlta?: number;
+};
+
+/** Y = +Exp(eta1) com probabilidade p, -Exp(eta2) com 1 - p. */
+export type ParametrosKou = {
+  probAlta: number;
+  eta1: number;
+  eta2: number;
+};
+
+export type ResultadoGregasSaltos = Pick<
+  ResultadoGregas,
+  'deltaCall' | 'deltaPut' | 'gama' | 'vega'
+>;
+
+type Erro = { ok: false; error: string };
+
+export const MAX_LAMBDA_SALTOS = 1000;
+const MAX_TERMOS_MERTON = 2000;
+
+/**
+ * Caudas exponenciais de Kou com a media e o desvio de Y informados:
+ * 1/eta1 = m + sqrt((1-p)(s^2-m^2) / 2p) e 1/eta2 = -m + sqrt(p(s^2-m^2) / 2(1-p)).
+ */
+export function parametrosKou({
+  media,
+  vol,
+  probAlta = 0.5,
+}: ParametrosSaltos): { ok: true; parametros: ParametrosKou } | Erro {
+  if (!(probAlta > 0 && probAlta < 1)) {
+    return { ok: false, error: 'Probabilidade de salto de alta deve estar entre 0 e 1.' };
+  }
+  if (!(vol > Math.abs(media))) {
+    return {
+      ok: false,
+      error: 'No Kou a vol do salto deve ser maior que o modulo da media.',
+    };
+  }
+  const excesso = vol * vol - media * media;
+  const inversoEta1 = media + Math.sqrt(((1 - probAlta) * excesso) / (2 * probAlta));
+  const inversoEta2 = -media + Math.sqrt((probAlta * excesso) / (2 * (1 - probAlta)));
+  if (!(inversoEta1 > 0) || !(inversoEta2 > 0)) {
+    return {
+      ok: false,
+      error: 'Media e vol do salto incompativeis com a probabilidade de alta.',
+    };
+  }
+  // eta1 > 1: o salto medio de alta (e^Y) precisa ter esperanca finita.
+  if (!(inversoEta1 < 1)) {
+    return {
+      ok: false,
+      error: 'Saltos de alta grandes demais: o Kou exige eta1 > 1.',
+    };
+  }
+  return {
+    ok: true,
+    parametros: { probAlta, eta1: 1 / inversoEta1, eta2: 1 / inversoEta2 },
+  };
+}
+
+/**
+ * Mensagem de erro para parametros fora do dominio do modelo, ou null.
+ */
+export function validarParametrosSaltos(
+  modelo: ModeloSaltos,
+  parametros: ParametrosSaltos,
+): string | null {
+  const { lambda, media, vol } = parametros;
+  if (!(lambda >= 0 && lambda <= MAX_LAMBDA_SALTOS)) {
+    return `Intensidade de saltos deve estar entre 0 e ${MAX_LAMBDA_SALTOS} por ano.`;
+  }
+  if (Number.isNaN(media) || !(vol >= 0)) {
+    return 'Media do salto deve ser numerica e vol do salto zero ou positiva.';
+  }
+  if (modelo === 'kou') {
+    const kou = parametrosKou(parametros);
+    return kou.ok ? null : kou.error;
+  }
+  return null;
+}
+
+/**
+ * Merton (1976): soma sobre o numero n de saltos no prazo, com peso de Poisson
+ * de intensidade lambda (1 + k), de precos Black-Scholes com
+ * sigma_n^2 = sigma^2 + n vol^2 / T e r_n = r - lambda k + n ln(1 + k) / T,
+ * k = E[e^Y] - 1. A serie para quando os pesos restantes ficam desprezaveis.
+ */
+export function precoMerton(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  { lambda, media, vol }: ParametrosSaltos = { lambda: 0, media: 0, vol: 0 },
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const precificador = tipo === 'call' ? blackScholesCall : blackScholesPut;
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0 || lambda === 0) {
+    return precificador(
+      S,
+      K,
+      r,
+      sigma,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      calendario,
+    );
+  }
+
+  const k = Math.exp(media + 0.5 * vol * vol) - 1;
+  const intensidade = lambda * (1 + k) * T;
+  let peso = Math.exp(-intensidade);
+  let acumulado = 0;
+  let preco = 0;
+  for (let n = 0; n < MAX_TERMOS_MERTON; n += 1) {
+    if (n > 0) peso *= intensidade / n;
+    const sigmaN = Math.sqrt(sigma * sigma + (n * vol * vol) / T);
+    const rN = r - lambda * k + (n * Math.log(1 + k)) / T;
+    preco +=
+      peso *
+      precificador(
+        S,
+        K,
+        rN,
+        sigmaN,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+        calendario,
+      );
+    acumulado += peso;
+    if (n > intensidade && 1 - acumulado < 1e-14) break;
+  }
+  return preco;
+}
+
+// Funcao caracteristica de ln(F_T / F) no Kou, ja compensada (martingal).
+function funcaoCaracteristicaKou(
+  u: Complexo,
+  T: number,
+  sigma: number,
+  lambda: number,
+  { probAlta, eta1, eta2 }: ParametrosKou,
+): Complexo {
+  const iu = complexo(-u.im, u.re);
+  const alta = dividir(complexo(eta1), subtrair(complexo(eta1), iu));
+  const baixa = dividir(complexo(eta2), somar(complexo(eta2), iu));
+  const phiSalto = somar(escalar(alta, probAlta), escalar(baixa, 1 - probAlta));
+  const zeta = (probAlta * eta1) / (eta1 - 1) + ((1 - probAlta) * eta2) / (eta2 + 1) - 1;
+  const deriva = -(0.5 * sigma * sigma + lambda * zeta);
+  const expoente = somar(
+    somar(escalar(iu, deriva), escalar(multiplicar(iu, iu), 0.5 * sigma * sigma)),
+    escalar(subtrair(phiSalto, complexo(1)), lambda),
+  );
+  return exponencial(escalar(expoente, T));
+}
+
+/**
+ * Kou (2002) por integracao de Fourier da funcao caracteristica, no mesmo
+ * integrador do Heston; os momentos de e^{wY} existem para -eta2 < w < eta1.
+ */
+export function precoKou(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  parametros: ParametrosSaltos = { lambda: 0, media: 0, vol: 0 },
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  const kou = parametrosKou(parametros);
+  if (T <= 0 || parametros.lambda === 0 || !kou.ok) {
+    const precificador = tipo === 'call' ? blackScholesCall : blackScholesPut;
+    return precificador(
+      S,
+      K,
+      r,
+      sigma,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      calendario,
+    );
+  }
+
+  const { lambda, media, vol } = parametros;
+  const { eta1, eta2 } = kou.parametros;
+  const F = S * Math.exp((r - q - taxaAluguel) * T);
+  const desconto = Math.exp(-r * T);
+  const normalizada = callNormalizadaFourier(
+    (u) => funcaoCaracteristicaKou(u, T, sigma, lambda, kou.parametros),
+    Math.log(K / F),
+    {
+      varianciaTotal: (sigma * sigma + lambda * (vol * vol + media * media)) * T,
+      varianciaDifusao: sigma * sigma * T,
+      momentoFinito: (w) => w < 0.9 * eta1 && w > -0.9 * eta2,
+    },
+  );
+  // Ruido de quadratura nao pode violar os limites de arbitragem.
+  const call = Math.min(
+    Math.max(desconto * F * normalizada, desconto * Math.max(F - K, 0)),
+    desconto * F,
+  );
+  return tipo === 'call' ? call : call - desconto * (F - K);
+}
+
+export function precoSaltos(
+  modelo: ModeloSaltos,
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  parametros: ParametrosSaltos = { lambda: 0, media: 0, vol: 0 },
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): number {
+  const precificador = modelo === 'kou' ? precoKou : precoMerton;
+  return precificador(
+    tipo,
+    S,
+    K,
+    r,
+    sigma,
+    dataAtual,
+    dataVencimento,
+    q,
+    taxaAluguel,
+    parametros,
+    calendario,
+  );
+}
+
+/**
+ * Delta, gama e vega (por 1% da vol difusiva) por diferencas centrais.
+ */
+export function calculaGregasSaltos(
+  modelo: ModeloSaltos,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  parametros: ParametrosSaltos = { lambda: 0, media: 0, vol: 0 },
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoGregasSaltos {
+  const preco = (tipo: TipoOpcao, spot: number, vol: number) =>
+    precoSaltos(
+      modelo,
+      tipo,
+      spot,
+      K,
+      r,
+      vol,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      parametros,
+      calendario,
+    );
+  const dS = 1e-3 * S;
+  const dSigma = Math.min(1e-4, sigma / 2);
+  const centro = preco('call', S, sigma);
+  const acima = preco('call', S + dS, sigma);
+  const abaixo = preco('call', S - dS, sigma);
+  const putAcima = preco('put', S + dS, sigma);
+  const putAbaixo = preco('put', S - dS, sigma);
+
+  return {
+    deltaCall: (acima - abaixo) / (2 * dS),
+    deltaPut: (putAcima - putAbaixo) / (2 * dS),
+    gama: (acima - 2 * centro + abaixo) / (dS * dS),
+    vega:
+      (preco('call', S, sigma + dSigma) - preco('call', S, sigma - dSigma)) /
+      (2 * dSigma) /
+      100,
+  };
+}
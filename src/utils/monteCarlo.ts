@@ -0,0 +1,365 @@
+import {
+  blackScholesCall,
+  calcularTempoEmAnos,
+  normalCdfInversa,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+import { type TipoOpcao } from './impliedVolatility';
+import {
+  MAX_DIMENSOES_SOBOL,
+  criarGerador,
+  criarSobol,
+  uint32Aleatorio,
+} from './random';
+
+export type SequenciaMonteCarlo = 'pseudo' | 'sobol';
+
+export type VariavelControle = 'call' | 'spot';
+
+export const VARIAVEIS_CONTROLE: Record<VariavelControle, string> = {
+  call: 'Call Black-Scholes',
+  spot: 'Spot no vencimento descontado',
+};
+
+export type ConfigMonteCarlo = {
+  /** Total de trajetorias simuladas (cada par antitetico conta como duas). */
+  caminhos: number;
+  /** Datas de observacao igualmente espacadas ate o vencimento. */
+  passos?: number;
+  semente?: number;
+  antitetico?: boolean;
+  /** Usa uma variavel de controle com media conhecida (variavelControle). */
+  controle?: boolean;
+  /**
+   * Call europeia fechada (blackScholesCall) no strike de controle, por padrao,
+   * ou spot no vencimento descontado, de media S * exp(-(q + aluguel) * T).
+   */
+  variavelControle?: VariavelControle;
+  sequencia?: SequenciaMonteCarlo;
+  confianca?: number;
+};
+
+export type MercadoMonteCarlo = {
+  S: number;
+  r: number;
+  sigma: number;
+  q?: number;
+  taxaAluguel?: number;
+  dataAtual: Date;
+  dataVencimento: Date;
+  calendario?: CalendarioFeriados;
+};
+
+/** Payoff no vencimento a partir dos spots nas datas de observacao t_1..t_n. */
+export type PayoffCaminho = (caminho: Float64Array) => number;
+
+export type ResultadoMonteCarlo = {
+  ok: true;
+  preco: number;
+  erroPadrao: number;
+  intervalo: [number, number];
+  confianca: number;
+  caminhos: number;
+  passos: number;
+  /** Coeficiente da variavel de controle, quando usada. */
+  beta?: number;
+};
+
+type Erro = { ok: false; error: string };
+
+export const MAX_CAMINHOS_MONTE_CARLO = 2_000_000;
+export const MAX_PASSOS_MONTE_CARLO = 1000;
+
+// Replicas com deslocamentos digitais independentes: o erro padrao do QMC sai da
+// dispersao entre elas.
+const REPLICAS_SOBOL = 16;
+
+type PonteBrowniana = {
+  indice: Int32Array;
+  esquerda: Int32Array;
+  direita: Int32Array;
+  pesoEsquerda: Float64Array;
+  pesoDireita: Float64Array;
+  desvio: Float64Array;
+};
+
+/**
+ * Ponte browniana (Jackel) em tempos igualmente espacados: o primeiro normal fixa
+ * W(T) e os seguintes refinam pontos medios, concentrando a variancia nas primeiras
+ * dimensoes, onde o Sobol e mais uniforme.
+ */
+function criarPonte(passos: number, dt: number): PonteBrowniana {
+  const tempo = (i: number) => (i + 1) * dt;
+  const ponte: PonteBrowniana = {
+    indice: new Int32Array(passos),
+    esquerda: new Int32Array(passos),
+    direita: new Int32Array(passos),
+    pesoEsquerda: new Float64Array(passos),
+    pesoDireita: new Float64Array(passos),
+    desvio: new Float64Array(passos),
+  };
+  const mapa = new Int32Array(passos);
+  mapa[passos - 1] = 1;
+  ponte.indice[0] = passos - 1;
+  ponte.desvio[0] = Math.sqrt(tempo(passos - 1));
+
+  let j = 0;
+  for (let i = 1; i < passos; i += 1) {
+    while (mapa[j]) j += 1;
+    let k = j;
+    while (!mapa[k]) k += 1;
+    const l = j + ((k - 1 - j) >> 1);
+    mapa[l] = i + 1;
+    const tl = tempo(l);
+    const tk = tempo(k);
+    const tj = j > 0 ? tempo(j - 1) : 0;
+    ponte.indice[i] = l;
+    ponte.esquerda[i] = j;
+    ponte.direita[i] = k;
+    ponte.pesoEsquerda[i] = (tk - tl) / (tk - tj);
+    ponte.pesoDireita[i] = (tl - tj) / (tk - tj);
+    ponte.desvio[i] = Math.sqrt(((tl - tj) * (tk - tl)) / (tk - tj));
+    j = k + 1;
+    if (j >= passos) j = 0;
+  }
+  return ponte;
+}
+
+function construirBrowniano(ponte: PonteBrowniana, z: Float64Array, w: Float64Array) {
+  const n = w.length;
+  w[n - 1] = ponte.desvio[0] * z[0];
+  for (let i = 1; i < n; i += 1) {
+    const j = ponte.esquerda[i];
+    const k = ponte.direita[i];
+    w[ponte.indice[i]] =
+      (j > 0 ? ponte.pesoEsquerda[i] * w[j - 1] : 0) +
+      ponte.pesoDireita[i] * w[k] +
+      ponte.desvio[i] * z[i];
+  }
+}
+
+function media(valores: Float64Array | number[]): number {
+  let soma = 0;
+  for (let i = 0; i < valores.length; i += 1) soma += valores[i];
+  return soma / valores.length;
+}
+
+function varianciaAmostral(valores: Float64Array | number[]): number {
+  const m = media(valores);
+  let soma = 0;
+  for (let i = 0; i < valores.length; i += 1) soma += (valores[i] - m) ** 2;
+  return soma / (valores.length - 1);
+}
+
+/**
+ * Motor de Monte Carlo sob GBM neutro ao risco (drift r - q - aluguel), com
+ * sequencia pseudoaleatoria semeada ou Sobol com deslocamento digital,
+ * variaveis antiteticas e variavel de controle. O payoff recebe a trajetoria
+ * e o resultado ja vem descontado a valor presente.
+ */
+export function simularMonteCarlo(
+  mercado: MercadoMonteCarlo,
+  payoff: PayoffCaminho,
+  config: ConfigMonteCarlo,
+  strikeControle = mercado.S,
+): ResultadoMonteCarlo | Erro {
+  const {
+    caminhos,
+    passos = 1,
+    semente = 42,
+    antitetico = false,
+    controle = false,
+    variavelControle = 'call',
+    sequencia = 'pseudo',
+    confianca = 0.95,
+  } = config;
+  const {
+    S,
+    r,
+    sigma,
+    q = 0,
+    taxaAluguel = 0,
+    dataAtual,
+    dataVencimento,
+    calendario = CALENDARIO_B3,
+  } = mercado;
+
+  if (!Number.isInteger(caminhos) || caminhos < 2 || caminhos > MAX_CAMINHOS_MONTE_CARLO) {
+    return {
+      ok: false,
+      error: `Numero de caminhos deve ser um inteiro entre 2 e ${MAX_CAMINHOS_MONTE_CARLO}.`,
+    };
+  }
+  if (!Number.isInteger(passos) || passos < 1 || passos > MAX_PASSOS_MONTE_CARLO) {
+    return {
+      ok: false,
+      error: `Passos devem ser um inteiro entre 1 e ${MAX_PASSOS_MONTE_CARLO}.`,
+    };
+  }
+  if (sequencia === 'sobol' && passos > MAX_DIMENSOES_SOBOL) {
+    return {
+      ok: false,
+      error: `Sobol suporta ate ${MAX_DIMENSOES_SOBOL} passos; use a sequencia pseudoaleatoria.`,
+    };
+  }
+  if (!(S > 0) || !(sigma > 0) || !(confianca > 0 && confianca < 1)) {
+    return { ok: false, error: 'Parametros de mercado invalidos para a simulacao.' };
+  }
+
+  const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+  if (T <= 0) {
+    // Vencida: o caminho e o proprio spot e nao ha incerteza.
+    const preco = payoff(new Float64Array(passos).fill(S));
+    return {
+      ok: true,
+      preco,
+      erroPadrao: 0,
+      intervalo: [preco, preco],
+      confianca,
+      caminhos: 0,
+      passos,
+    };
+  }
+  const desconto = Math.exp(-r * T);
+  const dt = T / passos;
+  const drift = (r - q - taxaAluguel - 0.5 * sigma * sigma) * dt;
+  const ponte = criarPonte(passos, dt);
+  const controleCall = variavelControle === 'call';
+  const esperadoControle = controleCall
+    ? blackScholesCall(
+      S,
+      strikeControle,
+      r,
+      sigma,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      calendario,
+    )
+    : S * Math.exp(-(q + taxaAluguel) * T);
+
+  // Uma amostra = uma trajetoria ou a media de um par antitetico.
+  const amostras = antitetico ? Math.ceil(caminhos / 2) : caminhos;
+  const replicas = sequencia === 'sobol' ? REPLICAS_SOBOL : 1;
+  const porReplica = Math.ceil(amostras / replicas);
+  const total = porReplica * replicas;
+  const y = new Float64Array(total);
+  const x = new Float64Array(total);
+
+  const gerador = criarGerador(semente);
+  const z = new Float64Array(passos);
+  const w = new Float64Array(passos);
+  const caminho = new Float64Array(passos);
+
+  // Trajetoria a partir dos normais z (sinal -1 para o antitetico).
+  const avaliar = (sinal: 1 | -1): [number, number] => {
+    for (let i = 0; i < passos; i += 1) z[i] *= sinal;
+    construirBrowniano(ponte, z, w);
+    for (let i = 0; i < passos; i += 1) {
+      caminho[i] = S * Math.exp(drift * (i + 1) + sigma * w[i]);
+      z[i] *= sinal;
+    }
+    const final = caminho[passos - 1];
+    return [payoff(caminho), controleCall ? Math.max(final - strikeControle, 0) : final];
+  };
+
+  for (let rep = 0; rep < replicas; rep += 1) {
+    let proximoPonto: () => ArrayLike<number>;
+    if (sequencia === 'sobol') {
+      const deslocamento = new Uint32Array(passos);
+      for (let j = 0; j < passos; j += 1) deslocamento[j] = uint32Aleatorio(gerador);
+      proximoPonto = criarSobol(passos, deslocamento);
+    } else {
+      const uniformes = new Float64Array(passos);
+      proximoPonto = () => {
+        for (let j = 0; j < passos; j += 1) uniformes[j] = gerador();
+        return uniformes;
+      };
+    }
+
+    for (let n = 0; n < porReplica; n += 1) {
+      const u = proximoPonto();
+      for (let j = 0; j < passos; j += 1) z[j] = normalCdfInversa(u[j]);
+      const indice = rep * porReplica + n;
+      const [payoffA, controleA] = avaliar(1);
+      if (antitetico) {
+        const [payoffB, controleB] = avaliar(-1);
+        y[indice] = desconto * 0.5 * (payoffA + payoffB);
+        x[indice] = desconto * 0.5 * (controleA + controleB);
+      } else {
+        y[indice] = desconto * payoffA;
+        x[indice] = desconto * controleA;
+      }
+    }
+  }
+
+  // Beta de minima variancia estimado na propria amostra.
+  let beta: number | undefined;
+  if (controle) {
+    const mx = media(x);
+    const my = media(y);
+    let cov = 0;
+    let varX = 0;
+    for (let i = 0; i < total; i += 1) {
+      cov += (x[i] - mx) * (y[i] - my);
+      varX += (x[i] - mx) ** 2;
+    }
+    beta = varX > 0 ? cov / varX : 0;
+    for (let i = 0; i < total; i += 1) {
+      y[i] -= beta * (x[i] - esperadoControle);
+    }
+  }
+
+  const preco = media(y);
+  let erroPadrao: number;
+  if (replicas > 1) {
+    const estimativas = Array.from({ length: replicas }, (_, rep) =>
+      media(y.subarray(rep * porReplica, (rep + 1) * porReplica)),
+    );
+    erroPadrao = Math.sqrt(varianciaAmostral(estimativas) / replicas);
+  } else {
+    erroPadrao = Math.sqrt(varianciaAmostral(y) / total);
+  }
+  const quantil = normalCdfInversa(0.5 + confianca / 2);
+
+  return {
+    ok: true,
+    preco,
+    erroPadrao,
+    intervalo: [preco - quantil * erroPadrao, preco + quantil * erroPadrao],
+    confianca,
+    caminhos: antitetico ? 2 * total : total,
+    passos,
+    ...(beta !== undefined ? { beta } : {}),
+  };
+}
+
+/**
+ * Opcao europeia vanilla por Monte Carlo, com a mesma assinatura posicional dos
+ * precificadores fechados; o controle call usa o mesmo strike.
+ */
+export function precificarEuropeiaMonteCarlo(
+  tipo: TipoOpcao,
+  S: number,
+  K: number,
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  dataVencimento: Date,
+  q = 0,
+  taxaAluguel = 0,
+  config: ConfigMonteCarlo = { caminhos: 100_000 },
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): ResultadoMonteCarlo | Erro {
+  const payoff: PayoffCaminho =
+    tipo === 'call'
+      ? (caminho) => Math.max(caminho[caminho.length - 1] - K, 0)
+      : (caminho) => Math.max(K - caminho[caminho.length - 1], 0);
+  return simularMonteCarlo(
+    { S, r, sigma, q, taxaAluguel, dataAtual, dataVencimento, calendario },
+    payoff,
+    config,
+    K,
+  );
+}
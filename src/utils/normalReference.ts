@@ -0,0 +1,97 @@
+import { normalCdf, normalCdfInversa } from './blackScholes';
+
+/**
+ * Valores de referencia para N(x), calculados com 50 digitos (mpmath) e
+ * arredondados a 17 significativos. Em x = -38 o resultado e subnormal e o
+ * double so guarda cerca de 8 deles.
+ */
+export const TABELA_NORMAL_CDF: ReadonlyArray<{ x: number; cdf: number }> = [
+  { x: -38, cdf: 2.8854283600687843e-316 },
+  { x: -30, cdf: 4.9067139271481871e-198 },
+  { x: -20, cdf: 2.7536241186062337e-89 },
+  { x: -15, cdf: 3.6709661993127509e-51 },
+  { x: -10, cdf: 7.6198530241605261e-24 },
+  { x: -8, cdf: 6.2209605742717841e-16 },
+  { x: -7, cdf: 1.2798125438858350e-12 },
+  { x: -6, cdf: 9.8658764503769814e-10 },
+  { x: -5, cdf: 2.8665157187919391e-7 },
+  { x: -3, cdf: 0.0013498980316300945 },
+  { x: -2.5, cdf: 0.0062096653257761352 },
+  { x: -2, cdf: 0.022750131948179207 },
+  { x: -1.96, cdf: 0.024997895148220434 },
+  { x: -1, cdf: 0.15865525393145705 },
+  { x: -0.7, cdf: 0.24196365222307301 },
+  { x: -0.6, cdf: 0.27425311775007358 },
+  { x: -0.5, cdf: 0.30853753872598690 },
+  { x: -0.1, cdf: 0.46017216272297102 },
+  { x: 0, cdf: 0.5 },
+  { x: 0.5, cdf: 0.69146246127401310 },
+  { x: 1, cdf: 0.84134474606854295 },
+  { x: 2.5, cdf: 0.99379033467422386 },
+];
+
+/**
+ * Quantis de referencia para a inversa da normal, com a mesma precisao.
+ */
+export const TABELA_NORMAL_INVERSA: ReadonlyArray<{ p: number; x: number }> = [
+  { p: 1e-10, x: -6.3613409024040562 },
+  { p: 0.001, x: -3.0902323061678135 },
+  { p: 0.01, x: -2.3263478740408411 },
+  { p: 0.025, x: -1.9599639845400542 },
+  { p: 0.1, x: -1.2815515655446005 },
+  { p: 0.25, x: -0.67448975019608174 },
+  { p: 0.5, x: 0 },
+  { p: 0.75, x: 0.67448975019608174 },
+  { p: 0.9, x: 1.2815515655446005 },
+  { p: 0.975, x: 1.9599639845400542 },
+  { p: 0.995, x: 2.5758293035489008 },
+];
+
+export type LinhaComparacao = {
+  entrada: number;
+  esperado: number;
+  calculado: number;
+  erroRelativo: number;
+};
+
+function erroRelativo(calculado: number, esperado: number): number {
+  if (esperado === 0) {
+    return Math.abs(calculado);
+  }
+  return Math.abs((calculado - esperado) / esperado);
+}
+
+/**
+ * Compara normalCdf e normalCdfInversa com as tabelas de referencia.
+ * Verificado por tests/normalCdf.test.ts (npm test): erro relativo abaixo de 1e-13.
+ */
+export function compararComReferencia(): {
+  cdf: LinhaComparacao[];
+  inversa: LinhaComparacao[];
+  erroMaximo: number;
+} {
+  const cdf = TABELA_NORMAL_CDF.map(({ x, cdf: esperado }) => {
+    const calculado = normalCdf(x);
+    return {
+      entrada: x,
+      esperado,
+      calculado,
+      erroRelativo: erroRelativo(calculado, esperado),
+    };
+  });
+  const inversa = TABELA_NORMAL_INVERSA.map(({ p, x: esperado }) => {
+    const calculado = normalCdfInversa(p);
+    return {
+      entrada: p,
+      esperado,
+      calculado,
+      erroRelativo: erroRelativo(calculado, esperado),
+    };
+  });
+  const erroMaximo = Math.max(
+    ...cdf.map((linha) => linha.erroRelativo),
+    ...inversa.map((linha) => linha.erroRelativo),
+  );
+
+  return { cdf, inversa, erroMaximo };
+}
@@ -0,0 +1,235 @@
+import {
+  type ResultadoGregas,
+  blackScholesCall,
+  blackScholesPut,
+  calcularDiasUteis,
+  calcularTempoEmAnos,
+  calculaGregas,
+} from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+import { formatDateInput, parseDate } from './dateHelpers';
+
+export const MAX_STRIKES_CADEIA = 200;
+export const MAX_VENCIMENTOS_CADEIA = 12;
+
+export type LinhaCadeia = {
+  K: number;
+  /** K / S, em fracao (1 = no dinheiro em relacao ao spot). */
+  moneyness: number;
+  /** ln(K/F) / (sigma * sqrt(T)): distancia ao forward em desvios-padrao. */
+  moneynessPadronizada: number;
+  call: number;
+  put: number;
+  gregas: ResultadoGregas;
+  /** Strike mais proximo do forward no vencimento. */
+  atm: boolean;
+};
+
+export type VencimentoCadeia = {
+  dataVencimento: Date;
+  T: number;
+  diasUteis: number;
+  forward: number;
+  linhas: LinhaCadeia[];
+};
+
+type Erro = { ok: false; error: string };
+
+function parseNumero(texto: string): number {
+  return Number(texto.trim().replace(',', '.'));
+}
+
+/**
+ * Gera strikes de min ate max (inclusive) com o passo informado.
+ */
+export function gerarFaixaStrikes(
+  min: number,
+  max: number,
+  passo: number,
+): { ok: true; strikes: number[] } | Erro {
+  if ([min, max, passo].some((n) => !Number.isFinite(n))) {
+    return { ok: false, error: 'Informe strike inicial, final e passo validos.' };
+  }
+  if (min <= 0 || max < min || passo <= 0) {
+    return {
+      ok: false,
+      error: 'Faixa de strikes invalida: use 0 < inicial <= final e passo > 0.',
+    };
+  }
+
+  const quantidade = Math.floor((max - min) / passo + 1e-9) + 1;
+  if (quantidade > MAX_STRIKES_CADEIA) {
+    return {
+      ok: false,
+      error: `Faixa gera ${quantidade} strikes; o limite e ${MAX_STRIKES_CADEIA}.`,
+    };
+  }
+
+  // Arredonda para evitar ruido de ponto flutuante (ex.: 32.599999).
+  const strikes = Array.from(
+    { length: quantidade },
+    (_, i) => Math.round((min + i * passo) * 1e8) / 1e8,
+  );
+  return { ok: true, strikes };
+}
+
+/**
+ * Lista de strikes separados por espaco, ";" ou quebra de linha
+ * (virgula e lida como separador decimal, ex.: "32,5").
+ */
+export function parseListaStrikes(
+  texto: string,
+): { ok: true; strikes: number[] } | Erro {
+  const partes = texto.split(/[\s;]+/).filter(Boolean);
+  if (partes.length === 0) {
+    return { ok: false, error: 'Informe ao menos um strike.' };
+  }
+
+  const strikes: number[] = [];
+  for (const parte of partes) {
+    const K = parseNumero(parte);
+    if (!Number.isFinite(K) || K <= 0) {
+      return { ok: false, error: `Strike invalido: ${parte}.` };
+    }
+    strikes.push(K);
+  }
+  if (strikes.length > MAX_STRIKES_CADEIA) {
+    return {
+      ok: false,
+      error: `Informe no maximo ${MAX_STRIKES_CADEIA} strikes.`,
+    };
+  }
+
+  return {
+    ok: true,
+    strikes: Array.from(new Set(strikes)).sort((a, b) => a - b),
+  };
+}
+
+/**
+ * Vencimentos no formato DD/MM/AAAA, um por linha ou separados por ";" / ",".
+ */
+export function parseListaVencimentos(
+  texto: string,
+): { ok: true; vencimentos: Date[] } | Erro {
+  const partes = texto
+    .split(/[\s;,]+/)
+    .map((parte) => parte.trim())
+    .filter(Boolean);
+  if (partes.length === 0) {
+    return { ok: false, error: 'Informe ao menos um vencimento.' };
+  }
+  if (partes.length > MAX_VENCIMENTOS_CADEIA) {
+    return {
+      ok: false,
+      error: `Informe no maximo ${MAX_VENCIMENTOS_CADEIA} vencimentos.`,
+    };
+  }
+
+  const vencimentos: Date[] = [];
+  for (const parte of partes) {
+    const data = parseDate(parte);
+    if (!data) {
+      return {
+        ok: false,
+        error: `Vencimento invalido: ${parte} (use DD/MM/AAAA).`,
+      };
+    }
+    vencimentos.push(data);
+  }
+
+  vencimentos.sort((a, b) => a.getTime() - b.getTime());
+  return {
+    ok: true,
+    vencimentos: vencimentos.filter(
+      (data, i) => i === 0 || data.getTime() !== vencimentos[i - 1].getTime(),
+    ),
+  };
+}
+
+/**
+ * Precifica a grade strikes x vencimentos com o Black-Scholes classico.
+ * Cada vencimento marca como ATM o strike mais proximo do forward; vencimentos
+ * sem dias uteis a partir da data atual sao rejeitados (as gregas nao existem em T = 0).
+ */
+export function calcularCadeia(
+  S: number,
+  strikes: number[],
+  r: number,
+  sigma: number,
+  dataAtual: Date,
+  vencimentos: Date[],
+  q = 0,
+  taxaAluguel = 0,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): { ok: true; cadeia: VencimentoCadeia[] } | Erro {
+  const vencido = vencimentos.find(
+    (data) => calcularDiasUteis(dataAtual, data, calendario) <= 0,
+  );
+  if (vencido) {
+    return {
+      ok: false,
+      error: `Vencimento ${formatDateInput(vencido)} sem dias uteis apos a data atual.`,
+    };
+  }
+
+  const cadeia = vencimentos.map((dataVencimento) => {
+    const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+    const forward = S * Math.exp((r - q - taxaAluguel) * T);
+    const desvio = sigma * Math.sqrt(T);
+    const indiceAtm = strikes.reduce(
+      (melhor, K, i) =>
+        Math.abs(K - forward) < Math.abs(strikes[melhor] - forward) ? i : melhor,
+      0,
+    );
+
+    const linhas = strikes.map((K, i) => ({
+      K,
+      moneyness: K / S,
+      moneynessPadronizada: desvio > 0 ? Math.log(K / forward) / desvio : 0,
+      call: blackScholesCall(
+        S,
+        K,
+        r,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+        calendario,
+      ),
+      put: blackScholesPut(
+        S,
+        K,
+        r,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+        calendario,
+      ),
+      gregas: calculaGregas(
+        S,
+        K,
+        r,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+        calendario,
+      ),
+      atm: i === indiceAtm,
+    }));
+
+    return {
+      dataVencimento,
+      T,
+      diasUteis: calcularDiasUteis(dataAtual, dataVencimento, calendario),
+      forward,
+      linhas,
+    };
+  });
+  return { ok: true, cadeia };
+}
@@ -0,0 +1,76 @@
+import type { TipoOpcao } from './impliedVolatility';
+import { type FormState, type Variant, criarPrecificadorPorSpot } from './scenario';
+
+export type PontoCurva = { x: number; y: number };
+
+export type CurvasPorSpot = {
+  call: PontoCurva[];
+  put: PontoCurva[];
+};
+
+/**
+ * Grade de spots cobrindo S e K com folga de 50% para cada lado.
+ */
+export function gerarGradeSpot(S: number, K: number, pontos = 101): number[] {
+  const inicio = 0.5 * Math.min(S, K);
+  const fim = 1.5 * Math.max(S, K);
+  const passo = (fim - inicio) / (pontos - 1);
+  return Array.from({ length: pontos }, (_, i) => inicio + i * passo);
+}
+
+export function payoffNoVencimento(
+  tipo: TipoOpcao,
+  spot: number,
+  K: number,
+): number {
+  return tipo === 'call' ? Math.max(spot - K, 0) : Math.max(K - spot, 0);
+}
+
+export function curvaPayoff(
+  tipo: TipoOpcao,
+  K: number,
+  spots: number[],
+): PontoCurva[] {
+  return spots.map((x) => ({ x, y: payoffNoVencimento(tipo, x, K) }));
+}
+
+/**
+ * Raizes da curva (x onde y troca de sinal), por interpolacao linear.
+ */
+export function encontrarBreakevens(pontos: PontoCurva[]): number[] {
+  const raizes: number[] = [];
+  for (let i = 1; i < pontos.length; i += 1) {
+    const a = pontos[i - 1];
+    const b = pontos[i];
+    if (a.y === 0 && (i === 1 || pontos[i - 2].y !== 0)) {
+      raizes.push(a.x);
+    } else if (a.y * b.y < 0) {
+      raizes.push(a.x - (a.y * (b.x - a.x)) / (b.y - a.y));
+    }
+  }
+  return raizes;
+}
+
+/**
+ * Valor teorico hoje de call e put ao longo da grade de spots, com sigma e as
+ * demais entradas da variante fixas. Spots sem preco valido (ex.: dividendos
+ * maiores que o spot) sao omitidos.
+ */
+export function curvasValorHoje(
+  variant: Variant,
+  form: FormState,
+  spots: number[],
+): CurvasPorSpot {
+  const curvas: CurvasPorSpot = { call: [], put: [] };
+  const precificador = criarPrecificadorPorSpot(variant, form);
+  if (!precificador.ok) {
+    return curvas;
+  }
+  spots.forEach((x) => {
+    const precos = precificador.precificar(x);
+    if (!precos) return;
+    curvas.call.push({ x, y: precos.call });
+    curvas.put.push({ x, y: precos.put });
+  });
+  return curvas;
+}
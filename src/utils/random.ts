@@ -0,0 +1,126 @@
+/**
+ * Gerador uniforme reprodutivel: sfc32 semeado por splitmix32.
+ * Devolve valores em (0, 1), nunca 0 ou 1 (seguro para a inversa da normal).
+ */
+export function criarGerador(semente: number): () => number {
+  let estadoSemente = semente >>> 0;
+  const splitmix = () => {
+    estadoSemente = (estadoSemente + 0x9e3779b9) >>> 0;
+    let z = estadoSemente;
+    z = Math.imul(z ^ (z >>> 16), 0x85ebca6b);
+    z = Math.imul(z ^ (z >>> 13), 0xc2b2ae35);
+    return (z ^ (z >>> 16)) >>> 0;
+  };
+
+  let a = splitmix();
+  let b = splitmix();
+  let c = splitmix();
+  let d = splitmix();
+  const proximo = () => {
+    const t = (((a + b) >>> 0) + d) >>> 0;
+    d = (d + 1) >>> 0;
+    a = b ^ (b >>> 9);
+    b = (c + (c << 3)) >>> 0;
+    c = (c << 21) | (c >>> 11);
+    c = (c + t) >>> 0;
+    return t >>> 0;
+  };
+  // Descarta as primeiras saidas para misturar o estado.
+  for (let i = 0; i < 12; i += 1) proximo();
+
+  return () => (proximo() + 0.5) / 2 ** 32;
+}
+
+/** Inteiro uniforme de 32 bits a partir do gerador (para deslocamentos digitais). */
+export function uint32Aleatorio(gerador: () => number): number {
+  return Math.floor(gerador() * 2 ** 32) >>> 0;
+}
+
+// Joe & Kuo (2008), new-joe-kuo-6.21201: [grau s, coeficientes a, m_1..m_s] das
+// dimensoes 2 em diante; a dimensao 1 e a sequencia de van der Corput.
+const DIRECOES_JOE_KUO: [number, number, number[]][] = [
+  [1, 0, [1]],
+  [2, 1, [1, 3]],
+  [3, 1, [1, 3, 1]],
+  [3, 2, [1, 1, 1]],
+  [4, 1, [1, 1, 3, 3]],
+  [4, 4, [1, 3, 5, 13]],
+  [5, 2, [1, 1, 5, 5, 17]],
+  [5, 4, [1, 1, 5, 5, 5]],
+  [5, 7, [1, 1, 7, 11, 19]],
+  [5, 11, [1, 1, 5, 1, 1]],
+  [5, 13, [1, 1, 1, 3, 11]],
+  [5, 14, [1, 3, 5, 5, 31]],
+  [6, 1, [1, 3, 3, 9, 7, 49]],
+  [6, 13, [1, 1, 1, 15, 21, 21]],
+  [6, 16, [1, 3, 1, 13, 27, 49]],
+  [6, 19, [1, 1, 1, 15, 7, 5]],
+  [6, 22, [1, 3, 1, 15, 13, 25]],
+  [6, 25, [1, 1, 5, 5, 19, 61]],
+  [7, 1, [1, 3, 7, 11, 23, 15, 103]],
+  [7, 4, [1, 3, 7, 13, 13, 15, 69]],
+];
+
+const BITS = 32;
+
+export const MAX_DIMENSOES_SOBOL = DIRECOES_JOE_KUO.length + 1;
+
+function numerosDiretores(dimensao: number): Uint32Array {
+  const v = new Uint32Array(BITS + 1);
+  if (dimensao === 0) {
+    for (let k = 1; k <= BITS; k += 1) {
+      v[k] = (1 << (BITS - k)) >>> 0;
+    }
+    return v;
+  }
+
+  const [s, a, m] = DIRECOES_JOE_KUO[dimensao - 1];
+  for (let k = 1; k <= Math.min(s, BITS); k += 1) {
+    v[k] = (m[k - 1] << (BITS - k)) >>> 0;
+  }
+  for (let k = s + 1; k <= BITS; k += 1) {
+    let valor = v[k - s] ^ (v[k - s] >>> s);
+    for (let i = 1; i < s; i += 1) {
+      if ((a >>> (s - 1 - i)) & 1) {
+        valor ^= v[k - i];
+      }
+    }
+    v[k] = valor >>> 0;
+  }
+  return v;
+}
+
+/**
+ * Sequencia de Sobol em codigo de Gray. O ponto 0 (origem) e pulado; `deslocamento`
+ * aplica um deslocamento digital (XOR) por dimensao, para QMC aleatorizado.
+ */
+export function criarSobol(
+  dimensoes: number,
+  deslocamento?: Uint32Array,
+): () => Float64Array {
+  if (dimensoes < 1 || dimensoes > MAX_DIMENSOES_SOBOL) {
+    throw new RangeError(`Sobol suporta de 1 a ${MAX_DIMENSOES_SOBOL} dimensoes.`);
+  }
+  const diretores = Array.from({ length: dimensoes }, (_, j) => numerosDiretores(j));
+  const x = new Uint32Array(dimensoes);
+  let indice = 0;
+
+  return () => {
+    // Bit zero mais a direita de `indice` escolhe o numero diretor.
+    let c = 1;
+    let n = indice;
+    while (n & 1) {
+      n >>>= 1;
+      c += 1;
+    }
+    indice += 1;
+
+    const ponto = new Float64Array(dimensoes);
+    for (let j = 0; j < dimensoes; j += 1) {
+      x[j] = (x[j] ^ diretores[j][c]) >>> 0;
+      const bits = deslocamento ? (x[j] ^ deslocamento[j]) >>> 0 : x[j];
+      ponto[j] = (bits + 0.5) / 2 ** 32;
+    }
+    return ponto;
+  };
+}
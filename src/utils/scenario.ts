@@ -0,0 +1,1241 @@
+import {
+  blackScholesCall,
+  blackScholesPut,
+  blackScholesCallModified,
+  blackScholesPutModified,
+  calcularDiasUteis,
+  calcularTempoEmAnos,
+  calculaGregas,
+  calculaGregasModificado,
+  listarFeriadosNoPeriodo,
+  type DividendoDiscreto,
+  type ResultadoGregas,
+  type ResultadoGregasModificado,
+  valorPresenteDividendos,
+} from './blackScholes';
+import { type MetodoAmericano, calcularAmericana } from './americanOptions';
+import {
+  type CalendarioFeriados,
+  type Feriado,
+  criarCalendarioPersonalizado,
+  obterCalendario,
+  parseListaFeriados,
+} from './holidayCalendar';
+import {
+  type TipoOpcao,
+  volatilidadeImplicita,
+  volatilidadeImplicitaBlack76,
+  volatilidadeImplicitaModificada,
+} from './impliedVolatility';
+import { black76Call, black76Put, calculaGregasBlack76 } from './black76';
+import {
+  type CotacaoPremioFx,
+  type ResultadoGregasFx,
+  calculaGregasGarmanKohlhagen,
+  cotarPremioFx,
+  garmanKohlhagenCall,
+  garmanKohlhagenPut,
+} from './garmanKohlhagen';
+import {
+  type OpcoesExotica,
+  type ProdutoExotico,
+  type TipoBarreira,
+  PRODUTOS_EXOTICOS,
+  TIPOS_BARREIRA,
+  barreiraAjustada,
+  calculaGregasExotica,
+  precoExotico,
+} from './exoticOptions';
+import {
+  type ModeloSaltos,
+  type ParametrosSaltos,
+  MODELOS_SALTOS,
+  calculaGregasSaltos,
+  parametrosKou,
+  precoSaltos,
+  validarParametrosSaltos,
+} from './jumpDiffusion';
+import { parseDate } from './dateHelpers';
+
+/**
+ * Campos do formulario, como texto digitado pelo usuario.
+ */
+export type FormState = {
+  S: string;
+  K: string;
+  r: string;
+  sigma: string;
+  q: string;
+  taxaAluguel: string;
+  rf: string;
+  nocional: string;
+  p: string;
+  premio: string;
+  tipoOpcao: TipoOpcao;
+  calendario: string;
+  feriadosExtras: string;
+  dividendos: string;
+  metodoAmericano: MetodoAmericano;
+  passosBinomial: string;
+  produto: Produto;
+  tipoBarreira: TipoBarreira;
+  barreira: string;
+  rebate: string;
+  /** Dias uteis entre observacoes da barreira (0 = continuo). */
+  monitoramento: string;
+  /** Valor pago pela digital cash-or-nothing. */
+  pagamento: string;
+  modeloSaltos: ModeloSaltos;
+  /** Saltos esperados por ano. */
+  lambdaSaltos: string;
+  /** Media e desvio do log-retorno de cada salto. */
+  mediaSalto: string;
+  volSalto: string;
+  /** Kou: probabilidade de o salto ser de alta. */
+  probSaltoAlta: string;
+  dataAtual: string;
+  dataVencimento: string;
+};
+
+export type Modo = 'preco' | 'volImplicita';
+
+export type Produto = 'vanilla' | ProdutoExotico;
+
+export type Variant =
+  | 'classico'
+  | 'modificado'
+  | 'americano'
+  | 'black76'
+  | 'fx'
+  | 'exotico'
+  | 'saltos';
+
+export type Gregas = Partial<
+  ResultadoGregas & ResultadoGregasModificado & ResultadoGregasFx
+>;
+
+export type DividendosResumo = {
+  spotOriginal: number;
+  spotAjustado: number;
+  valorPresente: number;
+};
+
+export type VolImplicitaState = {
+  sigma: number;
+  premio: number;
+  tipo: TipoOpcao;
+  iteracoes: number;
+  /** Modificado: outro sigma tambem reproduz o premio. */
+  naoUnica?: boolean;
+};
+
+export type ResultState = {
+  result: Gregas & {
+    call: number;
+    put: number;
+    T: number;
+    diasUteis: number;
+    feriados?: Feriado[];
+    americana?: {
+      metodo: MetodoAmericano;
+      passos?: number;
+      callEuropeia: number;
+      putEuropeia: number;
+      premioCall: number;
+      premioPut: number;
+    };
+    fx?: {
+      nocional: number;
+      call: CotacaoPremioFx;
+      put: CotacaoPremioFx;
+    };
+    exotica?: {
+      produto: ProdutoExotico;
+      tipoBarreira?: TipoBarreira;
+      /** Barreira usada na formula continua (corrigida no monitoramento discreto). */
+      barreiraAjustada?: number;
+      callVanilla: number;
+      putVanilla: number;
+    };
+    saltos?: {
+      modelo: ModeloSaltos;
+      parametros: ParametrosSaltos;
+      /** Caudas do Kou derivadas da media e vol do salto. */
+      eta1?: number;
+      eta2?: number;
+      callSemSaltos: number;
+      putSemSaltos: number;
+      /** Vol de Black-Scholes que reproduz o preco com saltos fora do dinheiro. */
+      volEquivalente?: { tipo: TipoOpcao; sigma: number };
+    };
+    dividendos?: DividendosResumo;
+    volImplicita?: VolImplicitaState;
+    /** Formulario usado, com sigma resolvido; q e aluguel ausentes em futuro/cambio. */
+    inputs: Omit<FormState, 'q' | 'taxaAluguel'> & {
+      q?: string;
+      taxaAluguel?: string;
+    };
+  };
+  variant?: Variant;
+  p?: number;
+};
+
+export const ROTAS_RESULTADO: Record<Variant, string> = {
+  classico: '/resultado',
+  modificado: '/resultado-modificado',
+  americano: '/resultado-americano',
+  black76: '/resultado-black76',
+  fx: '/resultado-fx',
+  exotico: '/resultado-exotico',
+  saltos: '/resultado-saltos',
+};
+
+type Erro = { ok: false; error: string };
+
+export function resolverCalendario(
+  form: Pick<FormState, 'calendario' | 'feriadosExtras'>,
+): { ok: true; calendario: CalendarioFeriados } | Erro {
+  const base = obterCalendario(form.calendario);
+  if (!base) {
+    return { ok: false, error: 'Calendario de feriados desconhecido.' };
+  }
+  if (!form.feriadosExtras.trim()) {
+    return { ok: true, calendario: base };
+  }
+
+  const extras = parseListaFeriados(form.feriadosExtras);
+  if (!extras.ok) {
+    return { ok: false, error: extras.error };
+  }
+  return {
+    ok: true,
+    calendario: criarCalendarioPersonalizado(
+      `${base.id}+extras`,
+      `${base.nome} + feriados adicionais`,
+      extras.feriados,
+      base,
+    ),
+  };
+}
+
+/**
+ * Agenda de dividendos/JCP: uma linha por pagamento, "DD/MM/AAAA;valor"
+ * (data ex e valor em R$ por acao).
+ */
+export function parseDividendos(
+  texto: string,
+): { ok: true; dividendos: DividendoDiscreto[] } | Erro {
+  const dividendos: DividendoDiscreto[] = [];
+  const linhas = texto.split(/\r?\n/);
+
+  for (let i = 0; i < linhas.length; i += 1) {
+    const linha = linhas[i].trim();
+    if (!linha) continue;
+
+    const [dataStr = '', valorStr = ''] = linha.split(';');
+    const dataEx = parseDate(dataStr.trim());
+    const valor = Number(valorStr.trim().replace(',', '.'));
+    if (!dataEx || !valorStr.trim() || Number.isNaN(valor) || valor < 0) {
+      return {
+        ok: false,
+        error: `Dividendo invalido na linha ${i + 1}: use DD/MM/AAAA;valor.`,
+      };
+    }
+    dividendos.push({ dataEx, valor });
+  }
+
+  return { ok: true, dividendos };
+}
+
+export type ParsedInputs = {
+  ok: true;
+  S: number;
+  K: number;
+  r: number;
+  sigma: number;
+  q: number;
+  taxaAluguel: number;
+  p: number;
+  premio?: number;
+  dividendos: DividendoDiscreto[];
+  dataAtual: Date;
+  dataVencimento: Date;
+  calendario: CalendarioFeriados;
+};
+
+export function parseInputs(
+  form: FormState,
+  {
+    requireP,
+    requirePremio = false,
+  }: { requireP: boolean; requirePremio?: boolean },
+): ParsedInputs | Erro {
+  const S = Number(form.S);
+  const K = Number(form.K);
+  const r = Number(form.r);
+  const sigma = Number(form.sigma);
+  const q = Number(form.q || '0');
+  const taxaAluguel = Number(form.taxaAluguel || '0');
+  const p = Number(form.p);
+  const premio = Number(form.premio);
+  const dataAtual = parseDate(form.dataAtual);
+  const dataVencimento = parseDate(form.dataVencimento);
+
+  // No modo de volatilidade implicita sigma e saida, nao entrada.
+  const required = requirePremio
+    ? [S, K, r, q, taxaAluguel]
+    : [S, K, r, sigma, q, taxaAluguel];
+  if (required.some((n) => Number.isNaN(n))) {
+    return { ok: false, error: 'Preencha valores numericos validos.' };
+  }
+
+  if (requireP && (Number.isNaN(p) || p <= 0)) {
+    return { ok: false, error: 'Parametro p deve ser maior que zero.' };
+  }
+
+  if (requirePremio && (!form.premio.trim() || Number.isNaN(premio) || premio <= 0)) {
+    return { ok: false, error: 'Premio observado deve ser maior que zero.' };
+  }
+
+  if (!dataAtual || !dataVencimento) {
+    return { ok: false, error: 'Datas devem estar no formato DD/MM/AAAA.' };
+  }
+
+  const dividendos = parseDividendos(form.dividendos);
+  if (!dividendos.ok) {
+    return dividendos;
+  }
+
+  const calendario = resolverCalendario(form);
+  if (!calendario.ok) {
+    return calendario;
+  }
+
+  return {
+    ok: true,
+    S,
+    K,
+    r,
+    sigma,
+    q,
+    taxaAluguel,
+    p,
+    ...(requirePremio ? { premio } : {}),
+    dividendos: dividendos.dividendos,
+    dataAtual,
+    dataVencimento,
+    calendario: calendario.calendario,
+  };
+}
+
+// Modelo escrowed: substitui S pelo spot liquido do VP dos dividendos discretos.
+function aplicarDividendos(
+  parsed: ParsedInputs,
+): { ok: true; parsed: ParsedInputs; resumo?: DividendosResumo } | Erro {
+  if (parsed.dividendos.length === 0) {
+    return { ok: true, parsed };
+  }
+
+  const { S, r, dividendos, dataAtual, dataVencimento, calendario } = parsed;
+  const valorPresente = valorPresenteDividendos(
+    dividendos,
+    r,
+    dataAtual,
+    dataVencimento,
+    calendario,
+  );
+  const spotAjustado = S - valorPresente;
+  if (spotAjustado <= 0) {
+    return {
+      ok: false,
+      error: 'Valor presente dos dividendos excede o preco do ativo.',
+    };
+  }
+
+  return {
+    ok: true,
+    parsed: { ...parsed, S: spotAjustado },
+    resumo: { spotOriginal: S, spotAjustado, valorPresente },
+  };
+}
+
+/**
+ * Parametros do produto exotico escolhido no formulario.
+ */
+export function parseOpcoesExotica(
+  form: FormState,
+): { ok: true; produto: ProdutoExotico; opcoes: OpcoesExotica } | Erro {
+  const { produto } = form;
+  if (produto === 'vanilla') {
+    return { ok: false, error: 'Escolha um produto exotico.' };
+  }
+  if (produto === 'digitalDinheiro') {
+    const pagamento = Number(form.pagamento || '1');
+    if (!(pagamento > 0)) {
+      return { ok: false, error: 'Pagamento da digital deve ser maior que zero.' };
+    }
+    return { ok: true, produto, opcoes: { pagamento } };
+  }
+  if (produto !== 'barreira') {
+    return { ok: true, produto, opcoes: {} };
+  }
+
+  const barreira = Number(form.barreira);
+  const rebate = Number(form.rebate || '0');
+  const monitoramentoDias = Number(form.monitoramento || '0');
+  if (!(barreira > 0)) {
+    return { ok: false, error: 'Barreira deve ser maior que zero.' };
+  }
+  if (Number.isNaN(rebate) || rebate < 0) {
+    return { ok: false, error: 'Rebate deve ser zero ou positivo.' };
+  }
+  if (!Number.isInteger(monitoramentoDias) || monitoramentoDias < 0) {
+    return {
+      ok: false,
+      error: 'Monitoramento deve ser um inteiro de dias uteis (0 = continuo).',
+    };
+  }
+  return {
+    ok: true,
+    produto,
+    opcoes: { barreira, tipoBarreira: form.tipoBarreira, rebate, monitoramentoDias },
+  };
+}
+
+/**
+ * Parametros de saltos do formulario, validados para o modelo escolhido.
+ */
+export function parseParametrosSaltos(
+  form: FormState,
+): { ok: true; modelo: ModeloSaltos; parametros: ParametrosSaltos } | Erro {
+  if (!form.lambdaSaltos.trim()) {
+    return { ok: false, error: 'Informe a intensidade de saltos.' };
+  }
+  const modelo = form.modeloSaltos;
+  const parametros: ParametrosSaltos = {
+    lambda: Number(form.lambdaSaltos),
+    media: Number(form.mediaSalto || '0'),
+    vol: Number(form.volSalto || '0'),
+    ...(modelo === 'kou' ? { probAlta: Number(form.probSaltoAlta || '0.5') } : {}),
+  };
+  const erro = validarParametrosSaltos(modelo, parametros);
+  if (erro) {
+    return { ok: false, error: erro };
+  }
+  return { ok: true, modelo, parametros };
+}
+
+type EntradasVariante = {
+  ok: true;
+  rf: number;
+  nocional: number;
+  exotica: { produto: ProdutoExotico; opcoes: OpcoesExotica } | null;
+  saltos: { modelo: ModeloSaltos; parametros: ParametrosSaltos } | null;
+  passos: number;
+};
+
+// Entradas que so algumas variantes usam (cambio, exoticos, saltos, arvore).
+function lerEntradasDaVariante(
+  variant: Variant,
+  form: FormState,
+): EntradasVariante | Erro {
+  const rf = Number(form.rf);
+  const nocional = Number(form.nocional || '1');
+  if (
+    variant === 'fx' &&
+    (Number.isNaN(rf) || Number.isNaN(nocional) || nocional <= 0)
+  ) {
+    return { ok: false, error: 'Informe taxa estrangeira e nocional validos.' };
+  }
+
+  const exotica = variant === 'exotico' ? parseOpcoesExotica(form) : null;
+  if (exotica && !exotica.ok) {
+    return exotica;
+  }
+
+  const saltos = variant === 'saltos' ? parseParametrosSaltos(form) : null;
+  if (saltos && !saltos.ok) {
+    return saltos;
+  }
+
+  const passos = Number(form.passosBinomial);
+  if (
+    variant === 'americano' &&
+    form.metodoAmericano !== 'bjerksundStensland' &&
+    (!Number.isInteger(passos) || passos < 1 || passos > 5000)
+  ) {
+    return {
+      ok: false,
+      error: 'Passos da arvore devem ser um inteiro entre 1 e 5000.',
+    };
+  }
+
+  return {
+    ok: true,
+    rf,
+    nocional,
+    exotica: exotica && { produto: exotica.produto, opcoes: exotica.opcoes },
+    saltos: saltos && { modelo: saltos.modelo, parametros: saltos.parametros },
+    passos,
+  };
+}
+
+function resolverSigmaImplicita(
+  parsed: ParsedInputs,
+  tipo: TipoOpcao,
+  modelo: 'classico' | 'modificado' | 'black76',
+): { ok: true; sigma: number; volImplicita?: VolImplicitaState } | Erro {
+  if (parsed.premio === undefined) {
+    return { ok: true, sigma: parsed.sigma };
+  }
+
+  const {
+    S,
+    K,
+    r,
+    q,
+    taxaAluguel,
+    p,
+    premio,
+    dataAtual,
+    dataVencimento,
+    calendario,
+  } = parsed;
+  const solved =
+    modelo === 'black76'
+      ? volatilidadeImplicitaBlack76(
+        premio,
+        tipo,
+        S,
+        K,
+        r,
+        dataAtual,
+        dataVencimento,
+        {},
+        calendario,
+      )
+      : modelo === 'modificado'
+        ? volatilidadeImplicitaModificada(
+          premio,
+          tipo,
+          S,
+          K,
+          r,
+          p,
+          dataAtual,
+          dataVencimento,
+          q,
+          taxaAluguel,
+          {},
+          calendario,
+        )
+        : volatilidadeImplicita(
+          premio,
+          tipo,
+          S,
+          K,
+          r,
+          dataAtual,
+          dataVencimento,
+          q,
+          taxaAluguel,
+          {},
+          calendario,
+        );
+  if (!solved.ok) {
+    return solved;
+  }
+
+  return {
+    ok: true,
+    sigma: solved.sigma,
+    volImplicita: {
+      sigma: solved.sigma,
+      premio,
+      tipo,
+      iteracoes: solved.iteracoes,
+      ...(solved.naoUnica ? { naoUnica: true } : {}),
+    },
+  };
+}
+
+/**
+ * Executa o calculo completo de uma variante a partir do formulario.
+ */
+export function calcularCenario(
+  variant: Variant,
+  form: FormState,
+  modo: Modo,
+): { ok: true; state: ResultState } | Erro {
+  if ((variant === 'americano' || variant === 'exotico') && modo === 'volImplicita') {
+    return {
+      ok: false,
+      error: 'Volatilidade implicita disponivel apenas para vanillas europeias.',
+    };
+  }
+  if (variant === 'saltos' && modo === 'volImplicita') {
+    return {
+      ok: false,
+      error: 'No modelo com saltos informe a vol da difusao; a implicita nao se aplica.',
+    };
+  }
+
+  const parsed = parseInputs(form, {
+    requireP: variant === 'modificado',
+    requirePremio: modo === 'volImplicita',
+  });
+  if (!parsed.ok) {
+    return parsed;
+  }
+
+  const entradas = lerEntradasDaVariante(variant, form);
+  if (!entradas.ok) {
+    return entradas;
+  }
+  const { rf, nocional, exotica, saltos, passos } = entradas;
+
+  // Futuro e cambio nao usam dividendos discretos.
+  const usaEscrow = variant !== 'black76' && variant !== 'fx';
+  const escrow = usaEscrow
+    ? aplicarDividendos(parsed)
+    : { ok: true as const, parsed, resumo: undefined };
+  if (!escrow.ok) {
+    return escrow;
+  }
+
+  // A taxa estrangeira entra como rendimento continuo (q = rf) no solver.
+  const resolved = resolverSigmaImplicita(
+    variant === 'fx' ? { ...escrow.parsed, q: rf, taxaAluguel: 0 } : escrow.parsed,
+    form.tipoOpcao,
+    variant === 'black76' || variant === 'modificado' ? variant : 'classico',
+  );
+  if (!resolved.ok) {
+    return resolved;
+  }
+
+  const { S, K, r, q, taxaAluguel, p, dataAtual, dataVencimento, calendario } =
+    escrow.parsed;
+  const { sigma, volImplicita } = resolved;
+  const base = {
+    T: calcularTempoEmAnos(dataAtual, dataVencimento, calendario),
+    diasUteis: calcularDiasUteis(dataAtual, dataVencimento, calendario),
+    feriados: listarFeriadosNoPeriodo(dataAtual, dataVencimento, calendario),
+    volImplicita,
+  };
+  const inputs = { ...form, sigma: String(sigma) };
+  const inputsSemRendimento = { ...inputs, q: undefined, taxaAluguel: undefined };
+
+  switch (variant) {
+    case 'modificado':
+      return {
+        ok: true,
+        state: {
+          variant,
+          p,
+          result: {
+            ...base,
+            call: blackScholesCallModified(
+              S,
+              K,
+              r,
+              sigma,
+              p,
+              dataAtual,
+              dataVencimento,
+              q,
+              taxaAluguel,
+              calendario,
+            ),
+            put: blackScholesPutModified(
+              S,
+              K,
+              r,
+              sigma,
+              p,
+              dataAtual,
+              dataVencimento,
+              q,
+              taxaAluguel,
+              calendario,
+            ),
+            ...calculaGregasModificado(
+              S,
+              K,
+              r,
+              sigma,
+              p,
+              dataAtual,
+              dataVencimento,
+              q,
+              taxaAluguel,
+              calendario,
+            ),
+            dividendos: escrow.resumo,
+            inputs,
+          },
+        },
+      };
+    case 'black76':
+      // No modo futuro o campo S carrega o preco do futuro F.
+      return {
+        ok: true,
+        state: {
+          variant,
+          result: {
+            ...base,
+            call: black76Call(S, K, r, sigma, dataAtual, dataVencimento, calendario),
+            put: black76Put(S, K, r, sigma, dataAtual, dataVencimento, calendario),
+            ...calculaGregasBlack76(
+              S,
+              K,
+              r,
+              sigma,
+              dataAtual,
+              dataVencimento,
+              calendario,
+            ),
+            inputs: inputsSemRendimento,
+          },
+        },
+      };
+    case 'fx': {
+      const call = garmanKohlhagenCall(
+        S,
+        K,
+        r,
+        rf,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        calendario,
+      );
+      const put = garmanKohlhagenPut(
+        S,
+        K,
+        r,
+        rf,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        calendario,
+      );
+      return {
+        ok: true,
+        state: {
+          variant,
+          result: {
+            ...base,
+            call,
+            put,
+            ...calculaGregasGarmanKohlhagen(
+              S,
+              K,
+              r,
+              rf,
+              sigma,
+              dataAtual,
+              dataVencimento,
+              calendario,
+            ),
+            fx: {
+              nocional,
+              call: cotarPremioFx(call, S, nocional),
+              put: cotarPremioFx(put, S, nocional),
+            },
+            inputs: inputsSemRendimento,
+          },
+        },
+      };
+    }
+    case 'americano': {
+      const opcoes = { metodo: form.metodoAmericano, passos };
+      const call = calcularAmericana(
+        'call',
+        S,
+        K,
+        r,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+        opcoes,
+        calendario,
+      );
+      const put = calcularAmericana(
+        'put',
+        S,
+        K,
+        r,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+        opcoes,
+        calendario,
+      );
+      return {
+        ok: true,
+        state: {
+          variant,
+          result: {
+            ...base,
+            call: call.americana,
+            put: put.americana,
+            americana: {
+              metodo: form.metodoAmericano,
+              passos:
+                form.metodoAmericano === 'bjerksundStensland' ? undefined : passos,
+              callEuropeia: call.europeia,
+              putEuropeia: put.europeia,
+              premioCall: call.premioExercicio,
+              premioPut: put.premioExercicio,
+            },
+            dividendos: escrow.resumo,
+            inputs,
+          },
+        },
+      };
+    }
+    case 'exotico': {
+      if (!exotica) {
+        return { ok: false, error: 'Escolha um produto exotico.' };
+      }
+      const { produto, opcoes } = exotica;
+      const argumentos = [
+        S,
+        K,
+        r,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+      ] as const;
+      return {
+        ok: true,
+        state: {
+          variant,
+          result: {
+            ...base,
+            call: precoExotico(produto, 'call', ...argumentos, opcoes, calendario),
+            put: precoExotico(produto, 'put', ...argumentos, opcoes, calendario),
+            ...calculaGregasExotica(produto, ...argumentos, opcoes, calendario),
+            exotica: {
+              produto,
+              ...(produto === 'barreira'
+                ? {
+                  tipoBarreira: form.tipoBarreira,
+                  barreiraAjustada: barreiraAjustada(
+                    opcoes.barreira ?? NaN,
+                    form.tipoBarreira,
+                    sigma,
+                    opcoes.monitoramentoDias,
+                  ),
+                }
+                : {}),
+              callVanilla: blackScholesCall(...argumentos, calendario),
+              putVanilla: blackScholesPut(...argumentos, calendario),
+            },
+            dividendos: escrow.resumo,
+            inputs,
+          },
+        },
+      };
+    }
+    case 'saltos': {
+      if (!saltos) {
+        return { ok: false, error: 'Informe os parametros de saltos.' };
+      }
+      const { modelo, parametros } = saltos;
+      const argumentos = [
+        S,
+        K,
+        r,
+        sigma,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+      ] as const;
+      const call = precoSaltos(modelo, 'call', ...argumentos, parametros, calendario);
+      const put = precoSaltos(modelo, 'put', ...argumentos, parametros, calendario);
+      // A opcao fora do dinheiro carrega a cauda que o lognormal subprecifica.
+      const tipoFora: TipoOpcao = K >= S ? 'call' : 'put';
+      const volEquivalente = volatilidadeImplicita(
+        tipoFora === 'call' ? call : put,
+        tipoFora,
+        S,
+        K,
+        r,
+        dataAtual,
+        dataVencimento,
+        q,
+        taxaAluguel,
+        {},
+        calendario,
+      );
+      const kou = modelo === 'kou' ? parametrosKou(parametros) : null;
+      return {
+        ok: true,
+        state: {
+          variant,
+          result: {
+            ...base,
+            call,
+            put,
+            ...calculaGregasSaltos(modelo, ...argumentos, parametros, calendario),
+            saltos: {
+              modelo,
+              parametros,
+              ...(kou?.ok
+                ? { eta1: kou.parametros.eta1, eta2: kou.parametros.eta2 }
+                : {}),
+              callSemSaltos: blackScholesCall(...argumentos, calendario),
+              putSemSaltos: blackScholesPut(...argumentos, calendario),
+              volEquivalente: volEquivalente.ok
+                ? { tipo: tipoFora, sigma: volEquivalente.sigma }
+                : undefined,
+            },
+            dividendos: escrow.resumo,
+            inputs,
+          },
+        },
+      };
+    }
+    default:
+      return {
+        ok: true,
+        state: {
+          variant,
+          result: {
+            ...base,
+            call: blackScholesCall(
+              S,
+              K,
+              r,
+              sigma,
+              dataAtual,
+              dataVencimento,
+              q,
+              taxaAluguel,
+              calendario,
+            ),
+            put: blackScholesPut(
+              S,
+              K,
+              r,
+              sigma,
+              dataAtual,
+              dataVencimento,
+              q,
+              taxaAluguel,
+              calendario,
+            ),
+            ...calculaGregas(
+              S,
+              K,
+              r,
+              sigma,
+              dataAtual,
+              dataVencimento,
+              q,
+              taxaAluguel,
+              calendario,
+            ),
+            dividendos: escrow.resumo,
+            inputs,
+          },
+        },
+      };
+  }
+}
+
+/** Teto de passos da arvore americana nas curvas por spot (um preco por ponto). */
+export const MAX_PASSOS_CURVA_SPOT = 100;
+
+export type PrecosPorSpot = { call: number; put: number };
+
+/**
+ * Precificador de call e put em funcao do spot, com as demais entradas da
+ * variante fixas: le e valida o formulario uma vez e chama os precificadores
+ * direto, sem gregas nem vol implicita. Devolve null nos spots em que os
+ * dividendos discretos superam o spot.
+ */
+export function criarPrecificadorPorSpot(
+  variant: Variant,
+  form: FormState,
+): { ok: true; precificar: (S: number) => PrecosPorSpot | null } | Erro {
+  const parsed = parseInputs(form, { requireP: variant === 'modificado' });
+  if (!parsed.ok) {
+    return parsed;
+  }
+  const entradas = lerEntradasDaVariante(variant, form);
+  if (!entradas.ok) {
+    return entradas;
+  }
+
+  const { rf, exotica, saltos, passos } = entradas;
+  const {
+    K,
+    r,
+    sigma,
+    q,
+    taxaAluguel,
+    p,
+    dividendos,
+    dataAtual,
+    dataVencimento,
+    calendario,
+  } = parsed;
+  const usaEscrow = variant !== 'black76' && variant !== 'fx';
+  const valorPresente =
+    usaEscrow && dividendos.length > 0
+      ? valorPresenteDividendos(dividendos, r, dataAtual, dataVencimento, calendario)
+      : 0;
+
+  const precos = (S: number): PrecosPorSpot => {
+    const argumentos = [
+      S,
+      K,
+      r,
+      sigma,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+    ] as const;
+    switch (variant) {
+      case 'modificado':
+        return {
+          call: blackScholesCallModified(
+            S,
+            K,
+            r,
+            sigma,
+            p,
+            dataAtual,
+            dataVencimento,
+            q,
+            taxaAluguel,
+            calendario,
+          ),
+          put: blackScholesPutModified(
+            S,
+            K,
+            r,
+            sigma,
+            p,
+            dataAtual,
+            dataVencimento,
+            q,
+            taxaAluguel,
+            calendario,
+          ),
+        };
+      case 'black76':
+        return {
+          call: black76Call(S, K, r, sigma, dataAtual, dataVencimento, calendario),
+          put: black76Put(S, K, r, sigma, dataAtual, dataVencimento, calendario),
+        };
+      case 'fx':
+        return {
+          call: garmanKohlhagenCall(
+            S,
+            K,
+            r,
+            rf,
+            sigma,
+            dataAtual,
+            dataVencimento,
+            calendario,
+          ),
+          put: garmanKohlhagenPut(
+            S,
+            K,
+            r,
+            rf,
+            sigma,
+            dataAtual,
+            dataVencimento,
+            calendario,
+          ),
+        };
+      case 'americano': {
+        const opcoes = {
+          metodo: form.metodoAmericano,
+          passos: Math.min(passos, MAX_PASSOS_CURVA_SPOT),
+        };
+        return {
+          call: calcularAmericana('call', ...argumentos, opcoes, calendario).americana,
+          put: calcularAmericana('put', ...argumentos, opcoes, calendario).americana,
+        };
+      }
+      case 'exotico': {
+        if (!exotica) {
+          return { call: NaN, put: NaN };
+        }
+        const { produto, opcoes } = exotica;
+        return {
+          call: precoExotico(produto, 'call', ...argumentos, opcoes, calendario),
+          put: precoExotico(produto, 'put', ...argumentos, opcoes, calendario),
+        };
+      }
+      case 'saltos': {
+        if (!saltos) {
+          return { call: NaN, put: NaN };
+        }
+        const { modelo, parametros } = saltos;
+        return {
+          call: precoSaltos(modelo, 'call', ...argumentos, parametros, calendario),
+          put: precoSaltos(modelo, 'put', ...argumentos, parametros, calendario),
+        };
+      }
+      default:
+        return {
+          call: blackScholesCall(...argumentos, calendario),
+          put: blackScholesPut(...argumentos, calendario),
+        };
+    }
+  };
+
+  return {
+    ok: true,
+    precificar: (S) => (S - valorPresente > 0 ? precos(S - valorPresente) : null),
+  };
+}
+
+/**
+ * Campos do formulario relevantes para cada variante; so eles vao para a URL.
+ */
+function camposDaVariante(variant: Variant, modo: Modo): (keyof FormState)[] {
+  const campos: (keyof FormState)[] = ['S', 'K', 'r'];
+  if (modo === 'volImplicita') {
+    campos.push('tipoOpcao', 'premio');
+  } else {
+    campos.push('sigma');
+  }
+  if (variant === 'fx') {
+    campos.push('rf', 'nocional');
+  } else if (variant !== 'black76') {
+    campos.push('q', 'taxaAluguel', 'dividendos');
+  }
+  if (variant === 'modificado') {
+    campos.push('p');
+  }
+  if (variant === 'americano') {
+    campos.push('metodoAmericano', 'passosBinomial');
+  }
+  if (variant === 'exotico') {
+    campos.push(
+      'produto',
+      'pagamento',
+      'tipoBarreira',
+      'barreira',
+      'rebate',
+      'monitoramento',
+    );
+  }
+  if (variant === 'saltos') {
+    campos.push(
+      'modeloSaltos',
+      'lambdaSaltos',
+      'mediaSalto',
+      'volSalto',
+      'probSaltoAlta',
+    );
+  }
+  campos.push('dataAtual', 'dataVencimento', 'calendario', 'feriadosExtras');
+  return campos;
+}
+
+/**
+ * Serializa o cenario na query string (?modelo=...&S=...), para links
+ * compartilhaveis que sobrevivem a um refresh da pagina de resultado.
+ */
+export function cenarioParaQuery(
+  variant: Variant,
+  form: FormState,
+  modo: Modo,
+): string {
+  const params = new URLSearchParams({ modelo: variant });
+  if (modo === 'volImplicita') {
+    params.set('modo', modo);
+  }
+  camposDaVariante(variant, modo).forEach((campo) => {
+    const valor = form[campo].trim();
+    if (valor) {
+      params.set(campo, valor);
+    }
+  });
+  return params.toString();
+}
+
+/**
+ * Reconstroi o formulario a partir da query string gerada por cenarioParaQuery.
+ * Retorna null quando a URL nao traz um cenario.
+ */
+export function cenarioDeQuery(
+  search: string,
+): { variant: Variant | null; form: FormState; modo: Modo } | null {
+  const params = new URLSearchParams(search);
+  if (!params.has('S') || !params.has('K')) {
+    return null;
+  }
+
+  const texto = (campo: keyof FormState, padrao = '') =>
+    params.get(campo) ?? padrao;
+  const modelo = params.get('modelo');
+  const variant =
+    modelo && Object.hasOwn(ROTAS_RESULTADO, modelo) ? (modelo as Variant) : null;
+  const metodo = texto('metodoAmericano', 'bjerksundStensland');
+  const produto = texto('produto', 'vanilla');
+  const tipoBarreira = texto('tipoBarreira', 'downOut');
+  const modeloSaltos = texto('modeloSaltos', 'merton');
+
+  return {
+    variant,
+    modo: params.get('modo') === 'volImplicita' ? 'volImplicita' : 'preco',
+    form: {
+      S: texto('S'),
+      K: texto('K'),
+      r: texto('r'),
+      sigma: texto('sigma'),
+      q: texto('q'),
+      taxaAluguel: texto('taxaAluguel'),
+      rf: texto('rf'),
+      nocional: texto('nocional'),
+      p: texto('p'),
+      premio: texto('premio'),
+      tipoOpcao: texto('tipoOpcao') === 'put' ? 'put' : 'call',
+      calendario: texto('calendario', 'b3'),
+      feriadosExtras: texto('feriadosExtras'),
+      dividendos: texto('dividendos'),
+      metodoAmericano:
+        metodo === 'crr' || metodo === 'leisenReimer'
+          ? metodo
+          : 'bjerksundStensland',
+      passosBinomial: texto('passosBinomial'),
+      produto: Object.hasOwn(PRODUTOS_EXOTICOS, produto)
+        ? (produto as ProdutoExotico)
+        : 'vanilla',
+      tipoBarreira: Object.hasOwn(TIPOS_BARREIRA, tipoBarreira)
+        ? (tipoBarreira as TipoBarreira)
+        : 'downOut',
+      barreira: texto('barreira'),
+      rebate: texto('rebate'),
+      monitoramento: texto('monitoramento'),
+      pagamento: texto('pagamento'),
+      modeloSaltos: Object.hasOwn(MODELOS_SALTOS, modeloSaltos)
+        ? (modeloSaltos as ModeloSaltos)
+        : 'merton',
+      lambdaSaltos: texto('lambdaSaltos'),
+      mediaSalto: texto('mediaSalto'),
+      volSalto: texto('volSalto'),
+      probSaltoAlta: texto('probSaltoAlta'),
+      dataAtual: texto('dataAtual'),
+      dataVencimento: texto('dataVencimento'),
+    },
+  };
+}
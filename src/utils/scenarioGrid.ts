@@ -0,0 +1,160 @@
+import { blackScholesCall, blackScholesPut, calculaGregas } from './blackScholes';
+import type { CalendarioFeriados } from './holidayCalendar';
+import type { TipoOpcao } from './impliedVolatility';
+
+export const MAX_CHOQUES_GRADE = 41;
+
+export type PosicaoCenario = {
+  tipo: TipoOpcao;
+  /** Quantidade com sinal: negativa para posicao vendida. */
+  quantidade: number;
+  S: number;
+  K: number;
+  r: number;
+  sigma: number;
+  q: number;
+  taxaAluguel: number;
+  dataAtual: Date;
+  dataVencimento: Date;
+  calendario: CalendarioFeriados;
+};
+
+export type CelulaCenario = {
+  valor: number;
+  /** Valor menos o valor da posicao no cenario base. */
+  resultado: number;
+  delta: number;
+};
+
+export type GradeCenario = {
+  /** Choques de spot em fracao (0.1 = +10%), nas colunas. */
+  choquesSpot: number[];
+  /** Choques das linhas: pontos de vol em fracao ou dias corridos a frente. */
+  linhas: number[];
+  celulas: CelulaCenario[][];
+  valorBase: number;
+};
+
+type Erro = { ok: false; error: string };
+
+/**
+ * Lista de numeros separados por espaco ou ";" (virgula decimal aceita).
+ */
+export function parseListaNumeros(
+  texto: string,
+  rotulo: string,
+): { ok: true; valores: number[] } | Erro {
+  const partes = texto.split(/[\s;]+/).filter(Boolean);
+  if (partes.length === 0) {
+    return { ok: false, error: `Informe ao menos um valor em ${rotulo}.` };
+  }
+  if (partes.length > MAX_CHOQUES_GRADE) {
+    return {
+      ok: false,
+      error: `${rotulo}: no maximo ${MAX_CHOQUES_GRADE} valores.`,
+    };
+  }
+
+  const valores: number[] = [];
+  for (const parte of partes) {
+    const valor = Number(parte.replace(',', '.'));
+    if (!Number.isFinite(valor)) {
+      return { ok: false, error: `${rotulo}: valor invalido ${parte}.` };
+    }
+    valores.push(valor);
+  }
+  return {
+    ok: true,
+    valores: Array.from(new Set(valores)).sort((a, b) => a - b),
+  };
+}
+
+function avaliarCelula(
+  posicao: PosicaoCenario,
+  S: number,
+  sigma: number,
+  dataAtual: Date,
+): Omit<CelulaCenario, 'resultado'> {
+  const { tipo, quantidade, K, r, q, taxaAluguel, dataVencimento, calendario } = posicao;
+  const precificar = tipo === 'call' ? blackScholesCall : blackScholesPut;
+  const valor = precificar(
+    S,
+    K,
+    r,
+    sigma,
+    dataAtual,
+    dataVencimento,
+    q,
+    taxaAluguel,
+    calendario,
+  );
+  const gregas = calculaGregas(
+    S,
+    K,
+    r,
+    sigma,
+    dataAtual,
+    dataVencimento,
+    q,
+    taxaAluguel,
+    calendario,
+  );
+  return {
+    valor: quantidade * valor,
+    delta: quantidade * (tipo === 'call' ? gregas.deltaCall : gregas.deltaPut),
+  };
+}
+
+function montarGrade(
+  posicao: PosicaoCenario,
+  choquesSpot: number[],
+  linhas: number[],
+  cenario: (linha: number) => { sigma: number; dataAtual: Date },
+): GradeCenario {
+  const { S, sigma, dataAtual } = posicao;
+  const valorBase = avaliarCelula(posicao, S, sigma, dataAtual).valor;
+  const celulas = linhas.map((linha) => {
+    const ajustado = cenario(linha);
+    return choquesSpot.map((choque) => {
+      const celula = avaliarCelula(
+        posicao,
+        S * (1 + choque),
+        ajustado.sigma,
+        ajustado.dataAtual,
+      );
+      return { ...celula, resultado: celula.valor - valorBase };
+    });
+  });
+  return { choquesSpot, linhas, celulas, valorBase };
+}
+
+/**
+ * Grade spot x vol: choques de vol somados a sigma (0.05 = +5 pontos),
+ * com piso de 0,1% de volatilidade.
+ */
+export function calcularGradeSpotVol(
+  posicao: PosicaoCenario,
+  choquesSpot: number[],
+  choquesVol: number[],
+): GradeCenario {
+  return montarGrade(posicao, choquesSpot, choquesVol, (choque) => ({
+    sigma: Math.max(posicao.sigma + choque, 0.001),
+    dataAtual: posicao.dataAtual,
+  }));
+}
+
+/**
+ * Grade spot x tempo: a data de avaliacao avanca N dias corridos; apos o
+ * vencimento a posicao vale o payoff.
+ */
+export function calcularGradeSpotDias(
+  posicao: PosicaoCenario,
+  choquesSpot: number[],
+  diasAFrente: number[],
+): GradeCenario {
+  return montarGrade(posicao, choquesSpot, diasAFrente, (dias) => {
+    const data = new Date(posicao.dataAtual);
+    data.setDate(data.getDate() + dias);
+    return { sigma: posicao.sigma, dataAtual: data };
+  });
+}
@@ -0,0 +1,289 @@
+import {
+  blackScholesCall,
+  blackScholesPut,
+  calculaGregas,
+} from './blackScholes';
+import type { CalendarioFeriados } from './holidayCalendar';
+import { type PontoCurva, encontrarBreakevens } from './payoff';
+
+export type TipoPerna = 'call' | 'put' | 'ativo';
+
+export type LadoPerna = 'compra' | 'venda';
+
+export type Perna = {
+  tipo: TipoPerna;
+  lado: LadoPerna;
+  quantidade: number;
+  /** Ignorado para pernas de ativo. */
+  K: number;
+  /** Ignorado para pernas de ativo. */
+  dataVencimento: Date;
+};
+
+export type MercadoEstrategia = {
+  S: number;
+  r: number;
+  sigma: number;
+  q: number;
+  taxaAluguel: number;
+  dataAtual: Date;
+  calendario: CalendarioFeriados;
+};
+
+export type TemplateEstrategia =
+  | 'travaAlta'
+  | 'travaBaixa'
+  | 'straddle'
+  | 'strangle'
+  | 'borboleta'
+  | 'condor'
+  | 'collar';
+
+export const TEMPLATES_ESTRATEGIA: Record<TemplateEstrategia, string> = {
+  travaAlta: 'Trava de alta com calls',
+  travaBaixa: 'Trava de baixa com puts',
+  straddle: 'Straddle comprado',
+  strangle: 'Strangle comprado',
+  borboleta: 'Borboleta com calls',
+  condor: 'Iron condor vendido',
+  collar: 'Collar (ativo + put comprada + call vendida)',
+};
+
+/** Gregas agregadas da posicao, ja multiplicadas por lado e quantidade. */
+export type GregasEstrategia = {
+  delta: number;
+  gama: number;
+  vega: number;
+  theta: number;
+  rho: number;
+};
+
+export type ResultadoPerna = {
+  perna: Perna;
+  /** Preco unitario hoje. */
+  preco: number;
+  gregas: GregasEstrategia;
+};
+
+export type ResultadoEstrategia = {
+  pernas: ResultadoPerna[];
+  /** Soma de lado x quantidade x preco: positivo = debito (paga), negativo = credito. */
+  premioLiquido: number;
+  gregas: GregasEstrategia;
+  /** Data em que o resultado e medido: o vencimento mais proximo. */
+  horizonte: Date;
+  /** Infinity quando o lucro e ilimitado. */
+  lucroMaximo: number;
+  /** -Infinity quando a perda e ilimitada. */
+  perdaMaxima: number;
+  breakevens: number[];
+  curvaHorizonte: PontoCurva[];
+  curvaHoje: PontoCurva[];
+};
+
+function sinal(lado: LadoPerna): number {
+  return lado === 'compra' ? 1 : -1;
+}
+
+/**
+ * Monta as pernas de um modelo em torno do strike mais proximo de S,
+ * com strikes espacados por `largura`.
+ */
+export function criarPernasTemplate(
+  template: TemplateEstrategia,
+  S: number,
+  largura: number,
+  dataVencimento: Date,
+): Perna[] {
+  const K = Math.max(Math.round(S / largura) * largura, largura);
+  const opcao = (
+    tipo: 'call' | 'put',
+    lado: LadoPerna,
+    strike: number,
+    quantidade = 1,
+  ): Perna => ({ tipo, lado, quantidade, K: strike, dataVencimento });
+
+  switch (template) {
+    case 'travaAlta':
+      return [opcao('call', 'compra', K), opcao('call', 'venda', K + largura)];
+    case 'travaBaixa':
+      return [opcao('put', 'compra', K), opcao('put', 'venda', K - largura)];
+    case 'straddle':
+      return [opcao('call', 'compra', K), opcao('put', 'compra', K)];
+    case 'strangle':
+      return [
+        opcao('put', 'compra', K - largura),
+        opcao('call', 'compra', K + largura),
+      ];
+    case 'borboleta':
+      return [
+        opcao('call', 'compra', K - largura),
+        opcao('call', 'venda', K, 2),
+        opcao('call', 'compra', K + largura),
+      ];
+    case 'condor':
+      return [
+        opcao('put', 'compra', K - 2 * largura),
+        opcao('put', 'venda', K - largura),
+        opcao('call', 'venda', K + largura),
+        opcao('call', 'compra', K + 2 * largura),
+      ];
+    default:
+      return [
+        { tipo: 'ativo', lado: 'compra', quantidade: 1, K: 0, dataVencimento },
+        opcao('put', 'compra', K - largura),
+        opcao('call', 'venda', K + largura),
+      ];
+  }
+}
+
+function precoPerna(
+  perna: Perna,
+  spot: number,
+  mercado: MercadoEstrategia,
+  data: Date,
+): number {
+  if (perna.tipo === 'ativo') {
+    return spot;
+  }
+  const { r, sigma, q, taxaAluguel, calendario } = mercado;
+  const precificar = perna.tipo === 'call' ? blackScholesCall : blackScholesPut;
+  return precificar(
+    spot,
+    perna.K,
+    r,
+    sigma,
+    data,
+    perna.dataVencimento,
+    q,
+    taxaAluguel,
+    calendario,
+  );
+}
+
+function gregasPerna(perna: Perna, mercado: MercadoEstrategia): GregasEstrategia {
+  const fator = sinal(perna.lado) * perna.quantidade;
+  if (perna.tipo === 'ativo') {
+    return { delta: fator, gama: 0, vega: 0, theta: 0, rho: 0 };
+  }
+
+  const { S, r, sigma, q, taxaAluguel, dataAtual, calendario } = mercado;
+  const gregas = calculaGregas(
+    S,
+    perna.K,
+    r,
+    sigma,
+    dataAtual,
+    perna.dataVencimento,
+    q,
+    taxaAluguel,
+    calendario,
+  );
+  const call = perna.tipo === 'call';
+  return {
+    delta: fator * (call ? gregas.deltaCall : gregas.deltaPut),
+    gama: fator * gregas.gama,
+    vega: fator * gregas.vega,
+    theta: fator * (call ? gregas.thetaCall : gregas.thetaPut),
+    rho: fator * (call ? gregas.rhoCall : gregas.rhoPut),
+  };
+}
+
+/**
+ * Valor da posicao para um spot em uma data; pernas ja vencidas valem o payoff
+ * (os precificadores devolvem o intrinseco quando T <= 0).
+ */
+function valorPosicao(
+  pernas: Perna[],
+  spot: number,
+  mercado: MercadoEstrategia,
+  data: Date,
+): number {
+  return pernas.reduce(
+    (total, perna) =>
+      total +
+      sinal(perna.lado) * perna.quantidade * precoPerna(perna, spot, mercado, data),
+    0,
+  );
+}
+
+/**
+ * Grade de spots de perto de zero a 3x o maior strike/spot, incluindo os
+ * strikes para que os vertices do payoff sejam pontos exatos da curva. O spot
+ * zero fica de fora: pernas ainda vivas nao tem preco definido ali.
+ */
+function gradeEstrategia(pernas: Perna[], S: number, pontos = 241): number[] {
+  const strikes = pernas.filter((p) => p.tipo !== 'ativo').map((p) => p.K);
+  const fim = 3 * Math.max(S, ...strikes);
+  const inicio = fim * 1e-4;
+  const base = Array.from(
+    { length: pontos },
+    (_, i) => inicio + ((fim - inicio) * i) / (pontos - 1),
+  );
+  return Array.from(new Set([...base, ...strikes, S])).sort((a, b) => a - b);
+}
+
+/**
+ * Precifica e agrega as pernas. Lucro/perda e breakevens sao medidos no
+ * vencimento mais proximo; pernas mais longas entram pelo valor teorico nessa data.
+ */
+export function avaliarEstrategia(
+  pernas: Perna[],
+  mercado: MercadoEstrategia,
+): ResultadoEstrategia {
+  const { S, dataAtual } = mercado;
+  const resultados = pernas.map((perna) => ({
+    perna,
+    preco: precoPerna(perna, S, mercado, dataAtual),
+    gregas: gregasPerna(perna, mercado),
+  }));
+  const premioLiquido = resultados.reduce(
+    (total, { perna, preco }) =>
+      total + sinal(perna.lado) * perna.quantidade * preco,
+    0,
+  );
+  const gregas = resultados.reduce<GregasEstrategia>(
+    (total, { gregas: g }) => ({
+      delta: total.delta + g.delta,
+      gama: total.gama + g.gama,
+      vega: total.vega + g.vega,
+      theta: total.theta + g.theta,
+      rho: total.rho + g.rho,
+    }),
+    { delta: 0, gama: 0, vega: 0, theta: 0, rho: 0 },
+  );
+
+  const opcoes = pernas.filter((p) => p.tipo !== 'ativo');
+  const horizonte = opcoes.reduce(
+    (menor, p) => (p.dataVencimento < menor ? p.dataVencimento : menor),
+    opcoes[0]?.dataVencimento ?? dataAtual,
+  );
+  const spots = gradeEstrategia(pernas, S);
+  const curvaHorizonte = spots.map((x) => ({
+    x,
+    y: valorPosicao(pernas, x, mercado, horizonte) - premioLiquido,
+  }));
+  const curvaHoje = spots.map((x) => ({
+    x,
+    y: valorPosicao(pernas, x, mercado, dataAtual) - premioLiquido,
+  }));
+
+  // Alem do ultimo ponto o resultado segue a inclinacao final da curva.
+  const [penultimo, ultimo] = curvaHorizonte.slice(-2);
+  const inclinacaoFinal =
+    (ultimo.y - penultimo.y) / (ultimo.x - penultimo.x);
+  const ys = curvaHorizonte.map((p) => p.y);
+  const tolerancia = 1e-9 * Math.max(1, ...pernas.map((p) => p.quantidade));
+
+  return {
+    pernas: resultados,
+    premioLiquido,
+    gregas,
+    horizonte,
+    lucroMaximo: inclinacaoFinal > tolerancia ? Infinity : Math.max(...ys),
+    perdaMaxima: inclinacaoFinal < -tolerancia ? -Infinity : Math.min(...ys),
+    breakevens: encontrarBreakevens(curvaHorizonte),
+    curvaHorizonte,
+    curvaHoje,
+  };
+}
@@ -0,0 +1,263 @@
+import { calcularTempoEmAnos } from './blackScholes';
+import type { CalendarioFeriados } from './holidayCalendar';
+import { type TipoOpcao, volatilidadeImplicita } from './impliedVolatility';
+import { parseDate } from './dateHelpers';
+
+export type CotacaoOpcao = {
+  dataVencimento: Date;
+  K: number;
+  premio: number;
+  tipo: TipoOpcao;
+};
+
+export type MercadoSuperficie = {
+  S: number;
+  r: number;
+  q: number;
+  taxaAluguel: number;
+  dataAtual: Date;
+  calendario: CalendarioFeriados;
+};
+
+export type PontoSmile = {
+  K: number;
+  /** ln(K/F) */
+  k: number;
+  vol: number;
+};
+
+type Spline = {
+  x: number[];
+  y: number[];
+  /** Segundas derivadas nos nos (spline natural). */
+  m: number[];
+};
+
+export type FatiaSmile = {
+  dataVencimento: Date;
+  T: number;
+  forward: number;
+  pontos: PontoSmile[];
+  spline: Spline;
+};
+
+export type CotacaoRejeitada = {
+  cotacao: CotacaoOpcao;
+  motivo: string;
+};
+
+export type SuperficieVolatilidade = {
+  mercado: MercadoSuperficie;
+  fatias: FatiaSmile[];
+  rejeitadas: CotacaoRejeitada[];
+};
+
+type Erro = { ok: false; error: string };
+
+/**
+ * Cotacoes, uma por linha: "DD/MM/AAAA;strike;premio[;call|put]" (call por padrao).
+ */
+export function parseCotacoes(
+  texto: string,
+): { ok: true; cotacoes: CotacaoOpcao[] } | Erro {
+  const cotacoes: CotacaoOpcao[] = [];
+  const linhas = texto.split(/\r?\n/);
+
+  for (let i = 0; i < linhas.length; i += 1) {
+    const linha = linhas[i].trim();
+    if (!linha) continue;
+
+    const [dataStr = '', strikeStr = '', premioStr = '', tipoStr = 'call'] = linha
+      .split(';')
+      .map((parte) => parte.trim());
+    const dataVencimento = parseDate(dataStr);
+    const K = Number(strikeStr.replace(',', '.'));
+    const premio = Number(premioStr.replace(',', '.'));
+    const tipo = tipoStr.toLowerCase();
+    if (
+      !dataVencimento ||
+      !(K > 0) ||
+      !(premio > 0) ||
+      (tipo !== 'call' && tipo !== 'put')
+    ) {
+      return {
+        ok: false,
+        error: `Cotacao invalida na linha ${i + 1}: use DD/MM/AAAA;strike;premio;call|put.`,
+      };
+    }
+    cotacoes.push({ dataVencimento, K, premio, tipo });
+  }
+
+  if (cotacoes.length === 0) {
+    return { ok: false, error: 'Informe ao menos uma cotacao.' };
+  }
+  return { ok: true, cotacoes };
+}
+
+function criarSpline(x: number[], y: number[]): Spline {
+  const n = x.length;
+  const m = new Array<number>(n).fill(0);
+  if (n < 3) {
+    return { x, y, m };
+  }
+
+  // Sistema tridiagonal para as segundas derivadas (Thomas), m[0] = m[n-1] = 0.
+  const c = new Array<number>(n).fill(0);
+  const d = new Array<number>(n).fill(0);
+  for (let i = 1; i < n - 1; i += 1) {
+    const h0 = x[i] - x[i - 1];
+    const h1 = x[i + 1] - x[i];
+    const diagonal = 2 * (h0 + h1) - h0 * c[i - 1];
+    const rhs = 6 * ((y[i + 1] - y[i]) / h1 - (y[i] - y[i - 1]) / h0);
+    c[i] = h1 / diagonal;
+    d[i] = (rhs - h0 * d[i - 1]) / diagonal;
+  }
+  for (let i = n - 2; i >= 1; i -= 1) {
+    m[i] = d[i] - c[i] * m[i + 1];
+  }
+  return { x, y, m };
+}
+
+/**
+ * Avalia a spline; fora dos nos extremos a vol e mantida constante.
+ */
+function avaliarSpline({ x, y, m }: Spline, valor: number): number {
+  const n = x.length;
+  if (n === 1 || valor <= x[0]) return y[0];
+  if (valor >= x[n - 1]) return y[n - 1];
+
+  let i = 1;
+  while (x[i] < valor) i += 1;
+  const h = x[i] - x[i - 1];
+  const a = (x[i] - valor) / h;
+  const b = (valor - x[i - 1]) / h;
+  return (
+    a * y[i - 1] +
+    b * y[i] +
+    (((a * a * a - a) * m[i - 1] + (b * b * b - b) * m[i]) * h * h) / 6
+  );
+}
+
+function calcularForward(mercado: MercadoSuperficie, T: number): number {
+  const { S, r, q, taxaAluguel } = mercado;
+  return S * Math.exp((r - q - taxaAluguel) * T);
+}
+
+/**
+ * Calcula a vol implicita de cada cotacao e ajusta uma spline cubica natural
+ * da vol em log-moneyness ln(K/F) para cada vencimento.
+ */
+export function construirSuperficie(
+  cotacoes: CotacaoOpcao[],
+  mercado: MercadoSuperficie,
+): SuperficieVolatilidade {
+  const { S, r, q, taxaAluguel, dataAtual, calendario } = mercado;
+  const rejeitadas: CotacaoRejeitada[] = [];
+  const porVencimento = new Map<number, { K: number; vol: number }[]>();
+
+  cotacoes.forEach((cotacao) => {
+    const { dataVencimento, K, premio, tipo } = cotacao;
+    if (calcularTempoEmAnos(dataAtual, dataVencimento, calendario) <= 0) {
+      rejeitadas.push({ cotacao, motivo: 'Vencimento nao posterior a data atual.' });
+      return;
+    }
+    const resolvido = volatilidadeImplicita(
+      premio,
+      tipo,
+      S,
+      K,
+      r,
+      dataAtual,
+      dataVencimento,
+      q,
+      taxaAluguel,
+      {},
+      calendario,
+    );
+    if (!resolvido.ok) {
+      rejeitadas.push({ cotacao, motivo: resolvido.error });
+      return;
+    }
+    const chave = dataVencimento.getTime();
+    porVencimento.set(chave, [
+      ...(porVencimento.get(chave) ?? []),
+      { K, vol: resolvido.sigma },
+    ]);
+  });
+
+  const fatias = Array.from(porVencimento.entries())
+    .sort(([a], [b]) => a - b)
+    .map(([chave, vols]) => {
+      const dataVencimento = new Date(chave);
+      const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+      const forward = calcularForward(mercado, T);
+      // Call e put no mesmo strike: usa a media das duas vols.
+      const porStrike = new Map<number, number[]>();
+      vols.forEach(({ K, vol }) =>
+        porStrike.set(K, [...(porStrike.get(K) ?? []), vol]),
+      );
+      const pontos = Array.from(porStrike.entries())
+        .map(([K, lista]) => ({
+          K,
+          k: Math.log(K / forward),
+          vol: lista.reduce((soma, v) => soma + v, 0) / lista.length,
+        }))
+        .sort((a, b) => a.k - b.k);
+      return {
+        dataVencimento,
+        T,
+        forward,
+        pontos,
+        spline: criarSpline(
+          pontos.map((p) => p.k),
+          pontos.map((p) => p.vol),
+        ),
+      };
+    });
+
+  return { mercado, fatias, rejeitadas };
+}
+
+/**
+ * Vol de uma fatia para o strike K (log-moneyness contra o forward da fatia).
+ */
+export function volatilidadeDoSmile(fatia: FatiaSmile, K: number): number {
+  return avaliarSpline(fatia.spline, Math.log(K / fatia.forward));
+}
+
+/**
+ * Vol da superficie para (K, vencimento). Entre vencimentos interpola a
+ * variancia total sigma^2 * T linearmente em T, no mesmo ln(K/F);
+ * antes do primeiro e depois do ultimo usa o smile mais proximo.
+ */
+export function volatilidadeDaSuperficie(
+  superficie: SuperficieVolatilidade,
+  K: number,
+  dataVencimento: Date,
+): number | null {
+  const { fatias, mercado } = superficie;
+  if (fatias.length === 0) {
+    return null;
+  }
+
+  const T = calcularTempoEmAnos(
+    mercado.dataAtual,
+    dataVencimento,
+    mercado.calendario,
+  );
+  const k = Math.log(K / calcularForward(mercado, T));
+  const volNaFatia = (fatia: FatiaSmile) => avaliarSpline(fatia.spline, k);
+
+  if (T <= fatias[0].T) return volNaFatia(fatias[0]);
+  const ultima = fatias[fatias.length - 1];
+  if (T >= ultima.T) return volNaFatia(ultima);
+
+  const i = fatias.findIndex((fatia) => fatia.T >= T);
+  const antes = fatias[i - 1];
+  const depois = fatias[i];
+  const w0 = volNaFatia(antes) ** 2 * antes.T;
+  const w1 = volNaFatia(depois) ** 2 * depois.T;
+  const peso = (T - antes.T) / (depois.T - antes.T);
+  const variancia = w0 + peso * (w1 - w0);
+  return Math.sqrt(Math.max(variancia, 0) / T);
+}
@@ -0,0 +1,203 @@
+import { BUSINESS_DAYS_IN_YEAR, calcularDiasUteis } from './blackScholes';
+import { type CalendarioFeriados, CALENDARIO_B3 } from './holidayCalendar';
+import { parseDate } from './dateHelpers';
+
+/** Taxa zero do vertice: anual, exponencial base 252 (convencao DI), em decimal. */
+export type VerticeCurva = {
+  diasUteis: number;
+  taxa: number;
+  fatorDesconto: number;
+};
+
+export type CurvaJuros = {
+  dataBase: Date;
+  /** Calendario usado para contar os dias uteis dos vertices. */
+  calendario: CalendarioFeriados;
+  vertices: VerticeCurva[];
+};
+
+export type TaxaNoVencimento = {
+  diasUteis: number;
+  taxaExp252: number;
+  /** Taxa continua equivalente, como os precificadores usam em exp(-r * T). */
+  taxaContinua: number;
+};
+
+type Erro = { ok: false; error: string };
+
+// Codigos de vencimento dos contratos futuros da B3 (F = janeiro ... Z = dezembro).
+const MESES_DI1 = 'FGHJKMNQUVXZ';
+
+/** (1 + taxa)^(du/252) = exp(r * du/252) */
+export function exp252ParaContinua(taxa: number): number {
+  return Math.log1p(taxa);
+}
+
+export function continuaParaExp252(r: number): number {
+  return Math.expm1(r);
+}
+
+function ehDiaUtil(data: Date, calendario: CalendarioFeriados): boolean {
+  const seguinte = new Date(data);
+  seguinte.setDate(seguinte.getDate() + 1);
+  return calcularDiasUteis(data, seguinte, calendario) === 1;
+}
+
+/**
+ * Vencimento de um DI1 (ex.: DI1F27 ou F27): primeiro dia util do mes do contrato.
+ */
+export function vencimentoDi1(
+  codigo: string,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): Date | null {
+  const match = /^(?:DI1)?([FGHJKMNQUVXZ])(\d{2})$/i.exec(codigo.trim());
+  if (!match) {
+    return null;
+  }
+  const mes = MESES_DI1.indexOf(match[1].toUpperCase());
+  const data = new Date(2000 + Number(match[2]), mes, 1);
+  while (!ehDiaUtil(data, calendario)) {
+    data.setDate(data.getDate() + 1);
+  }
+  return data;
+}
+
+/**
+ * Vertices, um por linha: "prazo;taxa" com taxa em % a.a. (base 252) e prazo em
+ * dias uteis, data DD/MM/AAAA ou codigo de DI1 (DI1F27). Datas e contratos viram
+ * dias uteis a partir de `dataBase`.
+ */
+export function parseVerticesCurva(
+  texto: string,
+  dataBase: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): { ok: true; vertices: { diasUteis: number; taxa: number }[] } | Erro {
+  const vertices: { diasUteis: number; taxa: number }[] = [];
+  const linhas = texto.split(/\r?\n/);
+
+  for (let i = 0; i < linhas.length; i += 1) {
+    const linha = linhas[i].trim();
+    if (!linha) continue;
+
+    const [prazoStr = '', taxaStr = ''] = linha.split(';').map((parte) => parte.trim());
+    const taxa = Number(taxaStr.replace(',', '.')) / 100;
+    const vencimento = prazoStr.includes('/')
+      ? parseDate(prazoStr)
+      : vencimentoDi1(prazoStr, calendario);
+    const diasUteis = /^\d+$/.test(prazoStr)
+      ? Number(prazoStr)
+      : vencimento
+        ? calcularDiasUteis(dataBase, vencimento, calendario)
+        : NaN;
+    if (!(diasUteis > 0) || !taxaStr || !Number.isFinite(taxa) || taxa <= -1) {
+      return {
+        ok: false,
+        error: `Vertice invalido na linha ${i + 1}: use du|DD/MM/AAAA|DI1F27;taxa % a.a.`,
+      };
+    }
+    vertices.push({ diasUteis, taxa });
+  }
+
+  if (vertices.length === 0) {
+    return { ok: false, error: 'Informe ao menos um vertice.' };
+  }
+  return { ok: true, vertices };
+}
+
+/**
+ * Monta a curva zero a partir de taxas de DI1/pre-DI. Cada contrato e um zero
+ * cupom, entao o fator de desconto sai direto da taxa; vertices repetidos sao
+ * consolidados pela media dos fatores.
+ */
+export function construirCurva(
+  vertices: { diasUteis: number; taxa: number }[],
+  dataBase: Date,
+  calendario: CalendarioFeriados = CALENDARIO_B3,
+): CurvaJuros {
+  const fatores = new Map<number, number[]>();
+  vertices.forEach(({ diasUteis, taxa }) => {
+    const fator = (1 + taxa) ** (-diasUteis / BUSINESS_DAYS_IN_YEAR);
+    fatores.set(diasUteis, [...(fatores.get(diasUteis) ?? []), fator]);
+  });
+
+  return {
+    dataBase,
+    calendario,
+    vertices: Array.from(fatores.entries())
+      .sort(([a], [b]) => a - b)
+      .map(([diasUteis, lista]) => {
+        const fatorDesconto = lista.reduce((soma, f) => soma + f, 0) / lista.length;
+        return {
+          diasUteis,
+          fatorDesconto,
+          taxa: fatorDesconto ** (-BUSINESS_DAYS_IN_YEAR / diasUteis) - 1,
+        };
+      }),
+  };
+}
+
+/**
+ * Fator de desconto com interpolacao flat-forward (log do fator linear em du).
+ * Antes do primeiro vertice vale a taxa dele; depois do ultimo, a ultima termo.
+ */
+export function fatorDescontoNoPrazo(curva: CurvaJuros, diasUteis: number): number {
+  const { vertices } = curva;
+  if (diasUteis <= 0 || vertices.length === 0) {
+    return 1;
+  }
+
+  let i = vertices.findIndex((v) => v.diasUteis >= diasUteis);
+  if (i === 0 || vertices.length === 1) {
+    const primeiro = vertices[0];
+    return primeiro.fatorDesconto ** (diasUteis / primeiro.diasUteis);
+  }
+  if (i === -1) {
+    i = vertices.length - 1;
+  }
+  const a = vertices[i - 1];
+  const b = vertices[i];
+  const peso = (diasUteis - a.diasUteis) / (b.diasUteis - a.diasUteis);
+  return Math.exp(
+    Math.log(a.fatorDesconto) +
+      peso * (Math.log(b.fatorDesconto) - Math.log(a.fatorDesconto)),
+  );
+}
+
+/** Taxa zero exponencial 252 no prazo informado. */
+export function taxaNoPrazo(curva: CurvaJuros, diasUteis: number): number {
+  if (curva.vertices.length === 0) {
+    return 0;
+  }
+  if (diasUteis <= 0) {
+    return curva.vertices[0].taxa;
+  }
+  const fator = fatorDescontoNoPrazo(curva, diasUteis);
+  return fator ** (-BUSINESS_DAYS_IN_YEAR / diasUteis) - 1;
+}
+
+/** Taxa a termo exponencial 252 entre dois prazos. */
+export function taxaTermo(curva: CurvaJuros, inicio: number, fim: number): number {
+  if (fim <= inicio) {
+    return taxaNoPrazo(curva, inicio);
+  }
+  const razao = fatorDescontoNoPrazo(curva, inicio) / fatorDescontoNoPrazo(curva, fim);
+  return razao ** (BUSINESS_DAYS_IN_YEAR / (fim - inicio)) - 1;
+}
+
+/**
+ * Taxa para precificar uma opcao: termo da curva entre a data atual e o
+ * vencimento (prazos contados da data base no calendario da curva), convertida
+ * para capitalizacao continua.
+ */
+export function taxaParaVencimento(
+  curva: CurvaJuros,
+  dataAtual: Date,
+  dataVencimento: Date,
+): TaxaNoVencimento {
+  const { dataBase, calendario } = curva;
+  const inicio = Math.max(calcularDiasUteis(dataBase, dataAtual, calendario), 0);
+  const fim = calcularDiasUteis(dataBase, dataVencimento, calendario);
+  const diasUteis = calcularDiasUteis(dataAtual, dataVencimento, calendario);
+  const taxaExp252 = taxaTermo(curva, inicio, fim);
+  return { diasUteis, taxaExp252, taxaContinua: exp252ParaContinua(taxaExp252) };
+}
@@ -0,0 +1,77 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import {
+  blackScholesCall,
+  blackScholesCallModified,
+  blackScholesPutModified,
+} from '../src/utils/blackScholes';
+import {
+  volatilidadeImplicita,
+  volatilidadeImplicitaModificada,
+} from '../src/utils/impliedVolatility';
+
+const dataAtual = new Date(2025, 0, 6);
+const dataVencimento = new Date(2025, 6, 6);
+const datas = [dataAtual, dataVencimento] as const;
+
+test('classico recupera o sigma do proprio preco', () => {
+  const premio = blackScholesCall(100, 110, 0.1, 0.35, ...datas);
+  const solved = volatilidadeImplicita(premio, 'call', 100, 110, 0.1, ...datas);
+  assert.ok(solved.ok);
+  assert.ok(Math.abs(solved.sigma - 0.35) < 1e-8, `${solved.sigma}`);
+  assert.equal(solved.naoUnica, undefined);
+});
+
+test('modificado com p < 1 resolve no trecho crescente e avisa da outra raiz', () => {
+  // Preco sobe ate sigma ~1.68 e depois cai: 14.03 tambem e atingido acima do pico.
+  const p = 0.5;
+  const premio = blackScholesCallModified(100, 110, 0.1, 1.5, p, ...datas);
+  assert.ok(blackScholesCallModified(100, 110, 0.1, 2, p, ...datas) < premio);
+  const solved = volatilidadeImplicitaModificada(
+    premio,
+    'call',
+    100,
+    110,
+    0.1,
+    p,
+    dataAtual,
+    dataVencimento,
+  );
+  assert.ok(solved.ok, solved.ok ? '' : solved.error);
+  assert.ok(Math.abs(solved.sigma - 1.5) < 1e-8, `${solved.sigma}`);
+  assert.equal(solved.naoUnica, true);
+});
+
+test('modificado rejeita premio acima do maximo do modelo informando o maximo', () => {
+  const solved = volatilidadeImplicitaModificada(
+    15,
+    'call',
+    100,
+    110,
+    0.1,
+    0.5,
+    dataAtual,
+    dataVencimento,
+  );
+  assert.ok(!solved.ok);
+  assert.match(solved.error, /acima do maximo do modelo \(14\.18\d+\)/);
+});
+
+test('modificado com p >= 1 continua com solucao unica', () => {
+  for (const p of [1, 1.2]) {
+    const premio = blackScholesPutModified(100, 95, 0.1, 0.3, p, ...datas);
+    const solved = volatilidadeImplicitaModificada(
+      premio,
+      'put',
+      100,
+      95,
+      0.1,
+      p,
+      dataAtual,
+      dataVencimento,
+    );
+    assert.ok(solved.ok, solved.ok ? '' : solved.error);
+    assert.ok(Math.abs(solved.sigma - 0.3) < 1e-8, `p=${p}: ${solved.sigma}`);
+    assert.equal(solved.naoUnica, undefined);
+  }
+});
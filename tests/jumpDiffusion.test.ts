@@ -0,0 +1,138 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import {
+  blackScholesCall,
+  blackScholesPut,
+  calcularTempoEmAnos,
+} from '../src/utils/blackScholes';
+import {
+  type CalendarioFeriados,
+  CALENDARIO_ANBIMA,
+  CALENDARIO_SEM_FERIADOS,
+} from '../src/utils/holidayCalendar';
+import {
+  type ParametrosSaltos,
+  parametrosKou,
+  precoKou,
+  precoMerton,
+} from '../src/utils/jumpDiffusion';
+
+type C = { re: number; im: number };
+const mul = (a: C, b: C): C => ({
+  re: a.re * b.re - a.im * b.im,
+  im: a.re * b.im + a.im * b.re,
+});
+const div = (a: C, b: C): C => {
+  const d = b.re * b.re + b.im * b.im;
+  return { re: (a.re * b.re + a.im * b.im) / d, im: (a.im * b.re - a.re * b.im) / d };
+};
+const exp = (a: C): C => ({
+  re: Math.exp(a.re) * Math.cos(a.im),
+  im: Math.exp(a.re) * Math.sin(a.im),
+});
+
+/**
+ * Call de referencia pela formula de Lewis, integrada por Simpson, com o
+ * expoente de Levy do salto dado em z = iu (independente do integrador do app).
+ */
+function callLewis(
+  S: number,
+  K: number,
+  r: number,
+  q: number,
+  sigma: number,
+  T: number,
+  lambda: number,
+  saltoMgf: (z: C) => C,
+): number {
+  const omega = -0.5 * sigma * sigma - lambda * (saltoMgf({ re: 1, im: 0 }).re - 1);
+  const k = Math.log(S / K) + (r - q) * T;
+  const integrando = (u: number) => {
+    const z = { re: 0.5, im: u };
+    const mgf = saltoMgf(z);
+    const z2 = mul(z, z);
+    const expoente = {
+      re: T * (omega * z.re + 0.5 * sigma * sigma * z2.re + lambda * (mgf.re - 1)),
+      im: T * (omega * z.im + 0.5 * sigma * sigma * z2.im + lambda * mgf.im),
+    };
+    const phi = exp(expoente);
+    const valor = mul({ re: Math.cos(u * k), im: Math.sin(u * k) }, phi);
+    return valor.re / (u * u + 0.25);
+  };
+  const n = 40_000;
+  const fim = 400;
+  const h = fim / n;
+  let soma = integrando(0) + integrando(fim);
+  for (let i = 1; i < n; i += 1) soma += (i % 2 ? 4 : 2) * integrando(i * h);
+  const integral = (soma * h) / 3;
+  return (
+    S * Math.exp(-q * T) -
+    (Math.sqrt(S * K) * Math.exp(-0.5 * (r + q) * T) * integral) / Math.PI
+  );
+}
+
+const S = 100;
+const r = 0.1;
+const sigma = 0.2;
+const q = 0.02;
+const dataAtual = new Date(2025, 0, 2);
+const dataVencimento = new Date(2026, 0, 2);
+const calendarios: CalendarioFeriados[] = [CALENDARIO_SEM_FERIADOS, CALENDARIO_ANBIMA];
+
+test('Merton segue a referencia de Lewis no calendario escolhido', () => {
+  const saltos: ParametrosSaltos = { lambda: 1, media: -0.1, vol: 0.15 };
+  const mgf = (z: C) =>
+    exp({
+      re: saltos.media * z.re + 0.5 * saltos.vol ** 2 * mul(z, z).re,
+      im: saltos.media * z.im + 0.5 * saltos.vol ** 2 * mul(z, z).im,
+    });
+  for (const calendario of calendarios) {
+    const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+    for (const K of [80, 100, 120]) {
+      const args = [S, K, r, sigma, dataAtual, dataVencimento, q, 0] as const;
+      const call = precoMerton('call', ...args, saltos, calendario);
+      const put = precoMerton('put', ...args, saltos, calendario);
+      const referencia = callLewis(S, K, r, q, sigma, T, saltos.lambda, mgf);
+      const paridade = S * Math.exp(-q * T) - K * Math.exp(-r * T);
+      const rotulo = `${calendario.id} K=${K}`;
+      const erro = Math.abs(call - referencia);
+      assert.ok(erro < 1e-6, `${rotulo}: ${call} vs ${referencia}`);
+      assert.ok(Math.abs(call - put - paridade) < 1e-9, rotulo);
+    }
+  }
+});
+
+test('Kou segue a referencia de Lewis no calendario escolhido', () => {
+  const saltos: ParametrosSaltos = { lambda: 1, media: -0.05, vol: 0.12, probAlta: 0.4 };
+  const kou = parametrosKou(saltos);
+  assert.ok(kou.ok);
+  const { probAlta, eta1, eta2 } = kou.parametros;
+  const mgf = (z: C): C => {
+    const alta = div({ re: eta1, im: 0 }, { re: eta1 - z.re, im: -z.im });
+    const baixa = div({ re: eta2, im: 0 }, { re: eta2 + z.re, im: z.im });
+    return {
+      re: probAlta * alta.re + (1 - probAlta) * baixa.re,
+      im: probAlta * alta.im + (1 - probAlta) * baixa.im,
+    };
+  };
+  for (const calendario of calendarios) {
+    const T = calcularTempoEmAnos(dataAtual, dataVencimento, calendario);
+    for (const K of [80, 100, 120]) {
+      const args = [S, K, r, sigma, dataAtual, dataVencimento, q, 0] as const;
+      const call = precoKou('call', ...args, saltos, calendario);
+      const referencia = callLewis(S, K, r, q, sigma, T, saltos.lambda, mgf);
+      const rotulo = `${calendario.id} K=${K}`;
+      const erro = Math.abs(call - referencia);
+      assert.ok(erro < 1e-5, `${rotulo}: ${call} vs ${referencia}`);
+    }
+  }
+});
+
+test('sem saltos Merton e Kou caem no Black-Scholes do mesmo calendario', () => {
+  const semSaltos: ParametrosSaltos = { lambda: 0, media: -0.1, vol: 0.15 };
+  const args = [S, 100, r, sigma, dataAtual, dataVencimento, q, 0] as const;
+  const call = blackScholesCall(...args, CALENDARIO_SEM_FERIADOS);
+  const put = blackScholesPut(...args, CALENDARIO_SEM_FERIADOS);
+  assert.equal(precoMerton('call', ...args, semSaltos, CALENDARIO_SEM_FERIADOS), call);
+  assert.equal(precoKou('put', ...args, semSaltos, CALENDARIO_SEM_FERIADOS), put);
+});
@@ -0,0 +1,37 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import {
+  FIXTURE_PROVIDER,
+  getProvider,
+  listProviders,
+} from '../src/services/marketDataProvider';
+
+test('provedor fixture busca, cota e devolve historico sem rede', async () => {
+  const busca = await FIXTURE_PROVIDER.search('petr4');
+  assert.deepEqual(
+    busca.map((item) => item.symbol),
+    ['PETR4.SA'],
+  );
+  assert.equal((await FIXTURE_PROVIDER.search('itau'))[0]?.symbol, 'ITUB4.SA');
+
+  const cotacao = await FIXTURE_PROVIDER.quote('vale3.sa');
+  assert.equal(cotacao.price, 58.2);
+  assert.ok(!('history' in cotacao));
+
+  const historico = await FIXTURE_PROVIDER.history('PETR4.SA');
+  assert.ok(historico.length > 200);
+  assert.ok(historico.every((barra, i) => i === 0 || barra.data > historico[i - 1].data));
+
+  const curva = await FIXTURE_PROVIDER.riskFreeCurve();
+  assert.ok(curva.length > 0 && curva.every((v) => v.businessDays > 0));
+
+  await assert.rejects(FIXTURE_PROVIDER.quote('XXXX3'), /nao encontrado/);
+});
+
+test('Yahoo fica fora dos provedores fora do servidor de desenvolvimento', () => {
+  assert.equal(getProvider('yahoo'), undefined);
+  assert.deepEqual(
+    listProviders().map((provider) => provider.id),
+    ['github', 'fixture'],
+  );
+});
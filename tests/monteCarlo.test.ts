@@ -0,0 +1,83 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import { blackScholesCall, blackScholesPut } from '../src/utils/blackScholes';
+import {
+  type ConfigMonteCarlo,
+  precificarEuropeiaMonteCarlo,
+  simularMonteCarlo,
+} from '../src/utils/monteCarlo';
+
+const dataAtual = new Date(2025, 0, 2);
+const dataVencimento = new Date(2026, 0, 2);
+const argumentos = [100, 105, 0.1, 0.25, dataAtual, dataVencimento, 0.02, 0.01] as const;
+const fechados = {
+  call: blackScholesCall(...argumentos),
+  put: blackScholesPut(...argumentos),
+};
+
+function conferir(rotulo: string, preco: number, erroPadrao: number, fechado: number) {
+  assert.ok(erroPadrao > 1e-4 && erroPadrao < 0.1, `${rotulo} EP ${erroPadrao}`);
+  const desvio = Math.abs(preco - fechado) / erroPadrao;
+  assert.ok(desvio < 4, `${rotulo}: ${preco} vs ${fechado}`);
+}
+
+test('Monte Carlo europeu fica a poucos erros padrao da formula fechada', () => {
+  const configs: ConfigMonteCarlo[] = [
+    { caminhos: 100_000 },
+    { caminhos: 100_000, antitetico: true, controle: true, variavelControle: 'spot' },
+    { caminhos: 65_536, sequencia: 'sobol', controle: true, variavelControle: 'spot' },
+  ];
+  for (const config of configs) {
+    for (const tipo of ['call', 'put'] as const) {
+      const mc = precificarEuropeiaMonteCarlo(tipo, ...argumentos, config);
+      assert.ok(mc.ok);
+      const rotulo = `${tipo} ${JSON.stringify(config)}`;
+      conferir(rotulo, mc.preco, mc.erroPadrao, fechados[tipo]);
+    }
+  }
+});
+
+test('controle pela call Black-Scholes confere a put e calls de outro strike', () => {
+  const config: ConfigMonteCarlo = {
+    caminhos: 100_000,
+    antitetico: true,
+    controle: true,
+  };
+  const put = precificarEuropeiaMonteCarlo('put', ...argumentos, config);
+  assert.ok(put.ok);
+  conferir('put', put.preco, put.erroPadrao, fechados.put);
+
+  const [S, , r, sigma, , , q, taxaAluguel] = argumentos;
+  const mercado = { S, r, sigma, q, taxaAluguel, dataAtual, dataVencimento };
+  const call = simularMonteCarlo(
+    mercado,
+    (caminho) => Math.max(caminho[caminho.length - 1] - 105, 0),
+    config,
+    100,
+  );
+  assert.ok(call.ok);
+  conferir('call K=105 controle K=100', call.preco, call.erroPadrao, fechados.call);
+});
+
+test('as duas variaveis de controle reduzem o erro padrao de uma asiatica', () => {
+  const [S, , r, sigma, , , q, taxaAluguel] = argumentos;
+  const mercado = { S, r, sigma, q, taxaAluguel, dataAtual, dataVencimento };
+  const asiatica = (caminho: Float64Array) =>
+    Math.max(caminho.reduce((soma, x) => soma + x, 0) / caminho.length - S, 0);
+  const base: ConfigMonteCarlo = { caminhos: 50_000, passos: 12 };
+  const sem = simularMonteCarlo(mercado, asiatica, base);
+  assert.ok(sem.ok);
+  for (const variavelControle of ['call', 'spot'] as const) {
+    const com = simularMonteCarlo(mercado, asiatica, {
+      ...base,
+      controle: true,
+      variavelControle,
+    });
+    assert.ok(com.ok);
+    assert.ok(
+      com.erroPadrao < 0.7 * sem.erroPadrao,
+      `${variavelControle}: ${com.erroPadrao} vs ${sem.erroPadrao}`,
+    );
+    assert.ok(Math.abs(com.preco - sem.preco) < 4 * sem.erroPadrao);
+  }
+});
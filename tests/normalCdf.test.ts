@@ -0,0 +1,35 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import {
+  blackScholesCall,
+  blackScholesPut,
+  normalCdf,
+} from '../src/utils/blackScholes';
+import { compararComReferencia } from '../src/utils/normalReference';
+
+const dataAtual = new Date(2025, 0, 2);
+const dataVencimento = new Date(2026, 0, 2);
+
+test('normalCdf e normalCdfInversa ficam abaixo de 1e-13 contra a tabela', () => {
+  const { cdf, inversa, erroMaximo } = compararComReferencia();
+  const pior = [...cdf, ...inversa].find((linha) => linha.erroRelativo === erroMaximo);
+  assert.ok(erroMaximo < 1e-13, `erro relativo ${erroMaximo} em ${pior?.entrada}`);
+});
+
+test('normalCdf vale 0 e 1 nas caudas e nos infinitos', () => {
+  assert.equal(normalCdf(-Infinity), 0);
+  assert.equal(normalCdf(Infinity), 1);
+  assert.equal(normalCdf(-40), 0);
+  assert.equal(normalCdf(40), 1);
+  assert.ok(Number.isNaN(normalCdf(NaN)));
+});
+
+test('spot ou strike zero dao precos finitos', () => {
+  const r = 0.1;
+  const sigma = 0.2;
+  assert.equal(blackScholesCall(0, 100, r, sigma, dataAtual, dataVencimento), 0);
+  assert.equal(blackScholesPut(100, 0, r, sigma, dataAtual, dataVencimento), 0);
+  assert.equal(blackScholesCall(100, 0, r, sigma, dataAtual, dataVencimento), 100);
+  const put = blackScholesPut(0, 100, r, sigma, dataAtual, dataVencimento);
+  assert.ok(Number.isFinite(put) && put > 0 && put < 100);
+});
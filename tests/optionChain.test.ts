@@ -0,0 +1,22 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import { calcularCadeia } from '../src/utils/optionChain';
+
+const dataAtual = new Date(2025, 0, 3);
+
+test('calcularCadeia rejeita vencimento na data atual ou antes', () => {
+  for (const vencimento of [new Date(2025, 0, 3), new Date(2024, 11, 20)]) {
+    const resultado = calcularCadeia(100, [90, 100], 0.1, 0.2, dataAtual, [vencimento]);
+    assert.equal(resultado.ok, false);
+  }
+});
+
+test('calcularCadeia devolve gregas finitas para vencimentos futuros', () => {
+  const resultado = calcularCadeia(100, [90, 100, 110], 0.1, 0.2, dataAtual, [
+    new Date(2025, 1, 3),
+  ]);
+  assert.ok(resultado.ok);
+  resultado.cadeia[0].linhas.forEach(({ gregas }) => {
+    Object.values(gregas).forEach((valor) => assert.ok(Number.isFinite(valor)));
+  });
+});
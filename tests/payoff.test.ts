@@ -0,0 +1,81 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import { curvasValorHoje, gerarGradeSpot } from '../src/utils/payoff';
+import {
+  type FormState,
+  type Variant,
+  MAX_PASSOS_CURVA_SPOT,
+  calcularCenario,
+} from '../src/utils/scenario';
+
+const form: FormState = {
+  S: '100',
+  K: '100',
+  r: '0.1',
+  sigma: '0.2',
+  q: '0.01',
+  taxaAluguel: '0',
+  rf: '0.03',
+  nocional: '1',
+  p: '1.2',
+  premio: '',
+  tipoOpcao: 'call',
+  calendario: 'b3',
+  feriadosExtras: '',
+  dividendos: '',
+  metodoAmericano: 'crr',
+  passosBinomial: '5000',
+  produto: 'barreira',
+  tipoBarreira: 'downOut',
+  barreira: '80',
+  rebate: '0',
+  monitoramento: '0',
+  pagamento: '1',
+  modeloSaltos: 'kou',
+  lambdaSaltos: '0.5',
+  mediaSalto: '-0.1',
+  volSalto: '0.15',
+  probSaltoAlta: '0.3',
+  dataAtual: '02/01/2025',
+  dataVencimento: '02/01/2026',
+};
+
+const variantes: Variant[] = [
+  'classico',
+  'modificado',
+  'black76',
+  'fx',
+  'americano',
+  'exotico',
+  'saltos',
+];
+
+test('curvasValorHoje reproduz calcularCenario em cada spot', () => {
+  const spots = gerarGradeSpot(100, 100);
+  for (const variant of variantes) {
+    const curvas = curvasValorHoje(variant, form, spots);
+    assert.equal(curvas.call.length, spots.length, variant);
+    for (const i of [10, 50, 90]) {
+      // A arvore da curva usa no maximo MAX_PASSOS_CURVA_SPOT passos.
+      const calculado = calcularCenario(
+        variant,
+        { ...form, S: String(spots[i]), passosBinomial: String(MAX_PASSOS_CURVA_SPOT) },
+        'preco',
+      );
+      assert.ok(calculado.ok, variant);
+      const { call, put } = calculado.state.result;
+      assert.ok(Math.abs(curvas.call[i].y - call) < 1e-10, `${variant} call ${i}`);
+      assert.ok(Math.abs(curvas.put[i].y - put) < 1e-10, `${variant} put ${i}`);
+    }
+  }
+});
+
+test('curvasValorHoje omite spots abaixo do valor presente dos dividendos', () => {
+  const spots = [1, 5, 50, 100];
+  const comDividendo = { ...form, dividendos: '01/06/2025;10' };
+  const curvas = curvasValorHoje('classico', comDividendo, spots);
+  assert.deepEqual(
+    curvas.call.map((p) => p.x),
+    [50, 100],
+  );
+});
@@ -0,0 +1,21 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import { cenarioDeQuery } from '../src/utils/scenario';
+
+test('cenarioDeQuery ignora chaves herdadas do prototipo', () => {
+  const cenario = cenarioDeQuery(
+    '?S=100&K=100&modelo=toString&produto=constructor' +
+      '&tipoBarreira=__proto__&modeloSaltos=hasOwnProperty',
+  );
+  assert.ok(cenario);
+  assert.equal(cenario.variant, null);
+  assert.equal(cenario.form.produto, 'vanilla');
+  assert.equal(cenario.form.tipoBarreira, 'downOut');
+  assert.equal(cenario.form.modeloSaltos, 'merton');
+});
+
+test('cenarioDeQuery aceita modelo e produto validos', () => {
+  const cenario = cenarioDeQuery('?S=100&K=100&modelo=exotico&produto=barreira');
+  assert.equal(cenario?.variant, 'exotico');
+  assert.equal(cenario?.form.produto, 'barreira');
+});
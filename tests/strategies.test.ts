@@ -0,0 +1,34 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import { CALENDARIO_B3 } from '../src/utils/holidayCalendar';
+import { type Perna, avaliarEstrategia } from '../src/utils/strategies';
+
+const mercado = {
+  S: 100,
+  r: 0.1,
+  sigma: 0.2,
+  q: 0,
+  taxaAluguel: 0,
+  dataAtual: new Date(2025, 0, 2),
+  calendario: CALENDARIO_B3,
+};
+
+test('calendario com calls tem lucro e perda maximos finitos', () => {
+  const call = (lado: Perna['lado'], dataVencimento: Date): Perna => ({
+    tipo: 'call',
+    lado,
+    quantidade: 1,
+    K: 100,
+    dataVencimento,
+  });
+  const pernas = [
+    call('venda', new Date(2025, 2, 3)),
+    call('compra', new Date(2025, 5, 2)),
+  ];
+  const resultado = avaliarEstrategia(pernas, mercado);
+  assert.ok(Number.isFinite(resultado.lucroMaximo), `lucro ${resultado.lucroMaximo}`);
+  assert.ok(Number.isFinite(resultado.perdaMaxima), `perda ${resultado.perdaMaxima}`);
+  assert.ok(resultado.lucroMaximo > 0 && resultado.perdaMaxima < 0);
+  assert.ok(resultado.curvaHoje.every((p) => p.x > 0 && Number.isFinite(p.y)));
+  assert.equal(resultado.breakevens.length, 2);
+});
@@ -0,0 +1,42 @@
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+import { calcularDiasUteis } from '../src/utils/blackScholes';
+import { CALENDARIO_B3 } from '../src/utils/holidayCalendar';
+import {
+  construirCurva,
+  fatorDescontoNoPrazo,
+  taxaNoPrazo,
+  taxaParaVencimento,
+} from '../src/utils/yieldCurve';
+
+const dataBase = new Date(2025, 0, 2);
+const curva = construirCurva(
+  [
+    { diasUteis: 21, taxa: 0.12 },
+    { diasUteis: 126, taxa: 0.13 },
+    { diasUteis: 252, taxa: 0.14 },
+  ],
+  dataBase,
+  CALENDARIO_B3,
+);
+const dataVencimento = new Date(2025, 11, 1);
+
+test('taxaParaVencimento na data base e a taxa zero do prazo', () => {
+  const taxa = taxaParaVencimento(curva, dataBase, dataVencimento);
+  const diasUteis = calcularDiasUteis(dataBase, dataVencimento, CALENDARIO_B3);
+  assert.equal(taxa.diasUteis, diasUteis);
+  assert.equal(taxa.taxaExp252, taxaNoPrazo(curva, taxa.diasUteis));
+});
+
+test('taxaParaVencimento depois da data base usa a taxa a termo', () => {
+  const dataAtual = new Date(2025, 5, 2);
+  const taxa = taxaParaVencimento(curva, dataAtual, dataVencimento);
+  const inicio = calcularDiasUteis(dataBase, dataAtual, CALENDARIO_B3);
+  const fim = calcularDiasUteis(dataBase, dataVencimento, CALENDARIO_B3);
+  assert.equal(taxa.diasUteis, fim - inicio);
+  // Descontar pela taxa devolvida reproduz a razao dos fatores da curva.
+  const fator = (1 + taxa.taxaExp252) ** (-taxa.diasUteis / 252);
+  const esperado = fatorDescontoNoPrazo(curva, fim) / fatorDescontoNoPrazo(curva, inicio);
+  assert.ok(Math.abs(fator - esperado) < 1e-14, `${fator} vs ${esperado}`);
+  assert.notEqual(taxa.taxaExp252, taxaNoPrazo(curva, taxa.diasUteis));
+});
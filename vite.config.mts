@@ -15,6 +15,11 @@ export default defineConfig({
         changeOrigin: true,
         rewrite: (path) => path.replace(/^\/yahoo\/quote/, '/v7/finance/quote'),
       },
+      '/yahoo/chart': {
+        target: 'https://query1.finance.yahoo.com',
+        changeOrigin: true,
+        rewrite: (path) => path.replace(/^\/yahoo\/chart/, '/v8/finance/chart'),
+      },
     },
   },
 });